@@ -0,0 +1,40 @@
+//! Measures how the search speed scales with the number of threads sharing the transposition table.
+//!
+//! Run with `cargo bench --bench search_scaling`.
+
+use timecat::*;
+
+const BENCH_FENS: [&str; 4] = [
+    STARTING_POSITION_FEN,
+    "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+    "r1bq1rk1/ppp2ppp/2np1n2/2b1p3/2B1P3/2NP1N2/PPP2PPP/R1BQ1RK1 w - - 0 7",
+    "8/3R4/p5kp/P1p3p1/6P1/8/6P1/Q6K w - - 0 47",
+];
+const NUM_THREADS: [usize; 6] = [1, 2, 4, 8, 16, 32];
+const SEARCH_TIME: Duration = Duration::from_secs(2);
+
+fn get_nodes_per_second(num_threads: usize) -> u128 {
+    let mut num_nodes_searched = 0;
+    let mut time_taken = Duration::ZERO;
+    for fen in BENCH_FENS {
+        let mut engine = Engine::from_fen(fen).unwrap();
+        engine.set_num_threads(NonZeroUsize::new(num_threads).unwrap());
+        let clock = Instant::now();
+        let search_info = engine.go_quiet(&SearchConfig::new_movetime(SEARCH_TIME));
+        time_taken += clock.elapsed();
+        num_nodes_searched += search_info.get_num_nodes_searched().unwrap_or_default() as u128;
+    }
+    num_nodes_searched * 10u128.pow(9) / time_taken.as_nanos()
+}
+
+fn main() {
+    let mut single_thread_nps = None;
+    for num_threads in NUM_THREADS {
+        let nps = get_nodes_per_second(num_threads);
+        let single_thread_nps = *single_thread_nps.get_or_insert(nps);
+        println!(
+            "{num_threads:>2} threads: {nps:>10} Nodes/sec, {:.2}x",
+            nps as f64 / single_thread_nps as f64,
+        );
+    }
+}
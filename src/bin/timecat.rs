@@ -24,6 +24,13 @@ fn main() {
     if !std::io::stdout().is_terminal() || cfg!(target_os = "windows") {
         GLOBAL_TIMECAT_STATE.set_colored_output(false, false);
     }
+    #[cfg(not(feature = "wasm"))]
+    if CliArgs::parse(&args).is_ok_and(|cli_args| cli_args.get_subcommand() == Some("serve")) {
+        if let Err(error) = TimecatServer::serve_from_args(&args) {
+            println_wasm!("{}", error.stringify().colorize(ERROR_MESSAGE_STYLE));
+        }
+        return;
+    }
     TimecatBuilder::<Engine>::default()
         .parse_args(&args)
         .build()
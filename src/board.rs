@@ -31,6 +31,17 @@ impl GameResult {
     }
 }
 
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameResult::Win(White) => write!(f, "1-0"),
+            GameResult::Win(Black) => write!(f, "0-1"),
+            GameResult::Draw => write!(f, "1/2-1/2"),
+            GameResult::InProgress => write!(f, "*"),
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 pub struct Board {
@@ -69,6 +80,12 @@ impl Board {
         Ok(board)
     }
 
+    /// Like [`Self::from_fen`], but drops the castle rights and the en passant square inconsistent with the pieces
+    /// instead of failing on them.
+    pub fn from_fen_lenient(fen: &str) -> Result<Self> {
+        Ok(BoardPosition::from_fen_lenient(fen)?.into())
+    }
+
     pub fn get_position(&self) -> &BoardPosition {
         &self.position
     }
@@ -83,8 +100,18 @@ impl Board {
         &mut self.evaluator
     }
 
+    /// Switches to the variant, setting up its starting position.
+    pub fn set_variant(&mut self, variant: Variant) -> Result<()> {
+        let mut position_builder = BoardPositionBuilder::from_str(variant.get_starting_fen())?;
+        position_builder.variant(variant);
+        self.position = position_builder.try_into()?;
+        self.stack.clear();
+        self.update_repetition_table();
+        Ok(())
+    }
+
     pub fn reset(&mut self) {
-        self.set_fen(STARTING_POSITION_FEN).unwrap();
+        self.set_fen(self.get_variant().get_starting_fen()).unwrap();
     }
 
     pub fn clear(&mut self) {
@@ -129,7 +156,8 @@ impl Board {
         }
         match self.status() {
             BoardStatus::Checkmate => GameResult::Win(!self.turn()),
-            BoardStatus::Stalemate => GameResult::Draw,
+            BoardStatus::VariantWin(color) => GameResult::Win(color),
+            BoardStatus::Stalemate | BoardStatus::VariantDraw => GameResult::Draw,
             BoardStatus::Ongoing => GameResult::InProgress,
         }
     }
@@ -331,14 +359,91 @@ impl Board {
         }
     }
 
+    fn get_starting_board(&self) -> Board {
+        self.stack
+            .first()
+            .map_or_else(|| self.position.clone(), |(position, _)| position.clone())
+            .into()
+    }
+
+    /// Removes the comments, variations and annotations of PGN move text, leaving the move numbers and moves.
+    fn strip_pgn_move_text(move_text: &str) -> String {
+        let mut stripped = String::with_capacity(move_text.len());
+        let mut variation_depth = 0_usize;
+        let mut chars = move_text.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' => {
+                    chars.by_ref().find(|&c| c == '}');
+                    stripped.push(' ');
+                }
+                ';' => {
+                    chars.by_ref().find(|&c| c == '\n');
+                    stripped.push(' ');
+                }
+                '(' => variation_depth += 1,
+                ')' => {
+                    variation_depth = variation_depth.saturating_sub(1);
+                    stripped.push(' ');
+                }
+                _ if variation_depth > 0 => {}
+                _ => stripped.push(c),
+            }
+        }
+        stripped
+    }
+
+    /// Parses the main line of a PGN game, starting from the position of the FEN tag if there is one.
+    pub fn from_pgn(pgn: &str) -> Result<Self> {
+        let mut optional_fen = None;
+        let mut move_text = String::new();
+        for line in pgn.lines() {
+            let line = line.trim();
+            if let Some(tag) = line.strip_prefix('[') {
+                if let Some(fen) = tag
+                    .strip_prefix("FEN")
+                    .and_then(|tag| tag.trim().strip_prefix('"'))
+                    .and_then(|tag| tag.split('"').next())
+                {
+                    optional_fen = Some(fen.to_string());
+                }
+            } else {
+                move_text += line;
+                move_text.push('\n');
+            }
+        }
+        let mut board = match optional_fen {
+            Some(fen) => Self::from_fen(&fen)?,
+            None => Self::default(),
+        };
+        for token in Self::strip_pgn_move_text(&move_text).split_whitespace() {
+            if ["1-0", "0-1", "1/2-1/2", "*"].contains(&token) {
+                break;
+            }
+            // Move numbers can be attached to the move, like 1.e4 or 12...Nf6.
+            let san = match token.split_once('.') {
+                Some((number, san)) if number.chars().all(|c| c.is_ascii_digit()) => {
+                    san.trim_start_matches('.')
+                }
+                _ => token,
+            }
+            .trim_end_matches(['!', '?']);
+            if san.is_empty() || san.starts_with('$') {
+                continue;
+            }
+            board.push_san(san)?;
+        }
+        Ok(board)
+    }
+
     pub fn get_pgn(&self) -> String {
         let mut pgn = String::new();
         let starting_fen = &self.get_starting_board_fen();
-        if starting_fen != STARTING_POSITION_FEN {
+        if starting_fen != self.get_variant().get_starting_fen() {
             pgn += &format!("[FEN \"{}\"]\n", starting_fen);
         }
         pgn += &Self::variation_san(
-            &Self::from_fen(starting_fen).unwrap(),
+            &self.get_starting_board(),
             self.stack
                 .clone()
                 .into_iter()
@@ -348,6 +453,34 @@ impl Board {
         pgn
     }
 
+    /// The PGN of the game with the tags in order, followed by the result token. The `FEN` and `SetUp` tags are added
+    /// when the game does not start from the starting position.
+    pub fn get_pgn_with_tags(&self, tags: &[(&str, String)], result: GameResult) -> String {
+        let mut pgn = String::new();
+        for (name, value) in tags {
+            pgn += &format!(
+                "[{name} \"{}\"]\n",
+                value.replace('\\', "\\\\").replace('"', "\\\"")
+            );
+        }
+        pgn += &format!("[Result \"{result}\"]\n");
+        if self.get_variant() != Variant::Standard {
+            pgn += &format!("[Variant \"{}\"]\n", self.get_variant().get_pgn_name());
+        }
+        let starting_fen = self.get_starting_board_fen();
+        if starting_fen != self.get_variant().get_starting_fen() {
+            pgn += &format!("[SetUp \"1\"]\n[FEN \"{starting_fen}\"]\n");
+        }
+        let move_text = Self::variation_san(&self.get_starting_board(), self.get_all_stack_moves());
+        pgn += "\n";
+        if !move_text.is_empty() {
+            pgn += &move_text;
+            pgn += " ";
+        }
+        pgn += &result.to_string();
+        pgn
+    }
+
     fn perft_helper(&mut self, depth: Depth, print_move: bool) -> usize {
         let moves = self.generate_legal_moves();
         if depth == 1 {
@@ -2,6 +2,10 @@ pub mod castle;
 pub mod move_generator;
 pub mod position;
 pub mod position_builder;
+pub mod symmetry;
+pub mod tactics;
+pub mod validation;
+pub mod variant;
 pub mod zobrist;
 
 use super::*;
@@ -9,4 +13,8 @@ pub use castle::*;
 pub use move_generator::*;
 pub use position::*;
 pub use position_builder::*;
+pub use symmetry::*;
+pub use tactics::*;
+pub use validation::*;
+pub use variant::*;
 pub use zobrist::*;
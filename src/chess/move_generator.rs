@@ -10,7 +10,7 @@ trait PieceMoves {
     {
         let occupied = position.occupied();
         let color = position.turn();
-        let ksq = position.get_king_square(color);
+        let ksq = position.get_king_square_unchecked(color);
 
         let pieces = position.get_colored_piece_mask(Self::into_piece(), color);
         let pinned = position.pinned();
@@ -52,6 +52,25 @@ trait PieceMoves {
             }
         }
     }
+
+    /// Pushes the moves of the pieces ignoring pins and checks, which are left to
+    /// [`MoveGenerator::is_legal_after_pseudo`].
+    fn pseudo_legal_moves(move_list: &mut MoveList, position: &BoardPosition, mask: BitBoard) {
+        let occupied = position.occupied();
+        let color = position.turn();
+        for src in position.get_colored_piece_mask(Self::into_piece(), color) {
+            let square_and_bitboard_array = Self::pseudo_legals(src, color, occupied, mask);
+            if !square_and_bitboard_array.is_empty() {
+                unsafe {
+                    move_list.push_unchecked(SquareAndBitBoard::new(
+                        src,
+                        square_and_bitboard_array,
+                        Self::is(Pawn) && src.get_rank() == color.to_seventh_rank(),
+                    ));
+                }
+            }
+        }
+    }
 }
 
 struct PawnMoves;
@@ -77,6 +96,19 @@ impl CheckMoves for NotInCheckMoves {
 }
 
 impl PawnMoves {
+    /// The pawns which can capture en passant, whether it leaves the king in check or not.
+    fn get_ep_sources(position: &BoardPosition, dest: Square) -> BitBoard {
+        let dest_rank = dest.get_rank();
+        let rank_bb = if dest_rank.to_int() > 3 {
+            dest_rank.wrapping_down().to_bitboard()
+        } else {
+            dest_rank.wrapping_up().to_bitboard()
+        };
+        rank_bb
+            & dest.get_file().get_adjacent_files_bb()
+            & position.get_colored_piece_mask(Pawn, position.turn())
+    }
+
     fn legal_ep_move(position: &BoardPosition, source: Square, dest: Square) -> bool {
         let occupied = position.occupied()
             ^ position
@@ -132,7 +164,7 @@ impl PieceMoves for PawnMoves {
     {
         let occupied = position.occupied();
         let color = position.turn();
-        let ksq = position.get_king_square(color);
+        let ksq = position.get_king_square_unchecked(color);
 
         let pieces = position.get_colored_piece_mask(Self::into_piece(), color);
         let pinned = position.pinned();
@@ -175,14 +207,7 @@ impl PieceMoves for PawnMoves {
         }
 
         if let Some(dest) = position.ep_square() {
-            let dest_rank = dest.get_rank();
-            let rank_bb = if dest_rank.to_int() > 3 {
-                dest_rank.wrapping_down().to_bitboard()
-            } else {
-                dest_rank.wrapping_up().to_bitboard()
-            };
-            let files_bb = dest.get_file().get_adjacent_files_bb();
-            for src in rank_bb & files_bb & pieces {
+            for src in Self::get_ep_sources(position, dest) {
                 if PawnMoves::legal_ep_move(position, src, dest) {
                     unsafe {
                         move_list.push_unchecked(SquareAndBitBoard::new(
@@ -197,6 +222,41 @@ impl PieceMoves for PawnMoves {
     }
 }
 
+impl PawnMoves {
+    /// The two-square move of a pawn of the horde from the first rank, which the pawn tables do not cover.
+    fn get_horde_double_move(position: &BoardPosition, source: Square) -> BitBoard {
+        let color = position.turn();
+        if position.get_variant() != Variant::Horde || source.get_rank() != color.to_my_backrank() {
+            return BitBoard::EMPTY;
+        }
+        let middle = source.wrapping_forward(color);
+        let dest = middle.wrapping_forward(color);
+        if position.occupied().contains(middle) || position.occupied().contains(dest) {
+            return BitBoard::EMPTY;
+        }
+        dest.to_bitboard()
+    }
+
+    fn pseudo_legal_moves_with_ep(
+        move_list: &mut MoveList,
+        position: &BoardPosition,
+        mask: BitBoard,
+    ) {
+        Self::pseudo_legal_moves(move_list, position, mask);
+        if let Some(dest) = position.ep_square() {
+            for src in Self::get_ep_sources(position, dest) {
+                unsafe {
+                    move_list.push_unchecked(SquareAndBitBoard::new(
+                        src,
+                        dest.to_bitboard(),
+                        false,
+                    ));
+                }
+            }
+        }
+    }
+}
+
 impl PieceMoves for BishopMoves {
     fn is(piece: PieceType) -> bool {
         piece == Bishop
@@ -233,7 +293,7 @@ impl PieceMoves for KnightMoves {
     {
         let occupied = position.occupied();
         let color = position.turn();
-        let ksq = position.get_king_square(color);
+        let ksq = position.get_king_square_unchecked(color);
 
         let pieces = position.get_colored_piece_mask(Self::into_piece(), color);
         let pinned = position.pinned();
@@ -331,6 +391,63 @@ impl KingMoves {
 
         attackers.is_empty()
     }
+
+    /// The destinations of the castling moves for which the rights are there, the squares in between are empty and
+    /// neither the king nor the square it passes are attacked. Whether the destination is attacked is not checked.
+    fn get_pseudo_legal_castle_dests(position: &BoardPosition) -> BitBoard {
+        let mut castle_dests = BitBoard::EMPTY;
+        let castle_rights = position.my_castle_rights();
+        if castle_rights == CastleRights::None || !position.get_checkers().is_empty() {
+            return castle_dests;
+        }
+        let occupied = position.occupied();
+        let color = position.turn();
+        let ksq = position.get_king_square_unchecked(color);
+        // In Atomic, the king may pass next to the other king, which cannot capture it.
+        let is_safe = |square: Square| {
+            KingMoves::legal_king_move(position, square)
+                || position.get_variant() == Variant::Atomic
+                    && !(square.get_king_moves() & position.get_colored_piece_mask(King, !color))
+                        .is_empty()
+        };
+        if castle_rights.has_kingside()
+            && (occupied & castle_rights.kingside_squares(color)).is_empty()
+        {
+            let middle = ksq.wrapping_right();
+            if is_safe(middle) {
+                castle_dests ^= middle.wrapping_right().to_bitboard();
+            }
+        }
+        if castle_rights.has_queenside()
+            && (occupied & castle_rights.queenside_squares(color)).is_empty()
+        {
+            let middle = ksq.wrapping_left();
+            if is_safe(middle) {
+                castle_dests ^= middle.wrapping_left().to_bitboard();
+            }
+        }
+        castle_dests
+    }
+
+    fn pseudo_legal_moves_with_castles(
+        move_list: &mut MoveList,
+        position: &BoardPosition,
+        mask: BitBoard,
+    ) {
+        let color = position.turn();
+        let ksq = position.get_king_square_unchecked(color);
+        let square_and_bitboard_array = Self::pseudo_legals(ksq, color, position.occupied(), mask)
+            | Self::get_pseudo_legal_castle_dests(position);
+        if !square_and_bitboard_array.is_empty() {
+            unsafe {
+                move_list.push_unchecked(SquareAndBitBoard::new(
+                    ksq,
+                    square_and_bitboard_array,
+                    false,
+                ));
+            }
+        }
+    }
 }
 
 impl PieceMoves for KingMoves {
@@ -354,7 +471,7 @@ impl PieceMoves for KingMoves {
     {
         let occupied = position.occupied();
         let color = position.turn();
-        let ksq = position.get_king_square(color);
+        let ksq = position.get_king_square_unchecked(color);
 
         let mut square_and_bitboard_array = Self::pseudo_legals(ksq, color, occupied, mask);
 
@@ -365,36 +482,12 @@ impl PieceMoves for KingMoves {
             }
         }
 
-        // If we are not in check, we may be able to castle.
-        // We can do so iff:
-        //  * the `BoardPosition` structure says we can.
-        //  * the squares between my king and my rook are empty.
-        //  * no enemy pieces are attacking the squares between the king, and the kings
-        //    destination square.
-        //  ** This is determined by going to the left or right, and calling
-        //     'legal_king_move' for that square.
+        // If we are not in check, we may be able to castle, as long as the destination square of the king is not
+        // attacked either.
         if !T::IN_CHECK {
-            if position.my_castle_rights().has_kingside()
-                && (occupied & position.my_castle_rights().kingside_squares(color)).is_empty()
-            {
-                let middle = ksq.wrapping_right();
-                let right = middle.wrapping_right();
-                if KingMoves::legal_king_move(position, middle)
-                    && KingMoves::legal_king_move(position, right)
-                {
-                    square_and_bitboard_array ^= right.to_bitboard();
-                }
-            }
-
-            if position.my_castle_rights().has_queenside()
-                && (occupied & position.my_castle_rights().queenside_squares(color)).is_empty()
-            {
-                let middle = ksq.wrapping_left();
-                let left = middle.wrapping_left();
-                if KingMoves::legal_king_move(position, middle)
-                    && KingMoves::legal_king_move(position, left)
-                {
-                    square_and_bitboard_array ^= left.to_bitboard();
+            for dest in KingMoves::get_pseudo_legal_castle_dests(position) {
+                if KingMoves::legal_king_move(position, dest) {
+                    square_and_bitboard_array ^= dest.to_bitboard();
                 }
             }
         }
@@ -416,6 +509,7 @@ struct SquareAndBitBoard {
     square: Square,
     bitboard: BitBoard,
     promotion: bool,
+    drop: Option<PieceType>,
 }
 
 impl SquareAndBitBoard {
@@ -424,11 +518,53 @@ impl SquareAndBitBoard {
             square,
             bitboard: bb,
             promotion,
+            drop: None,
+        }
+    }
+
+    /// The drops of the piece from the pocket to the squares of the bitboard, which have no source square.
+    fn new_drop(piece_type: PieceType, bb: BitBoard) -> SquareAndBitBoard {
+        SquareAndBitBoard {
+            square: Square::A1,
+            bitboard: bb,
+            promotion: false,
+            drop: Some(piece_type),
+        }
+    }
+
+    /// Whether the moves pass the mask of the source squares. The drops only pass the mask allowing every square.
+    #[inline]
+    fn is_from(&self, mask: BitBoard) -> bool {
+        if self.drop.is_some() {
+            mask == BB_ALL
+        } else {
+            mask.contains(self.square)
+        }
+    }
+
+    #[inline]
+    fn get_piece_type(&self, position: &BoardPosition) -> PieceType {
+        self.drop
+            .unwrap_or_else(|| position.get_piece_type_at(self.square).unwrap())
+    }
+
+    #[inline]
+    fn get_source(&self) -> Option<Square> {
+        self.drop.is_none().then_some(self.square)
+    }
+
+    #[inline]
+    fn get_move(&self, dest: Square, promotion: Option<PieceType>) -> Move {
+        match self.drop {
+            Some(piece_type) => Move::new_drop(piece_type, dest),
+            None => Move::new_unchecked(self.square, dest, promotion),
         }
     }
 }
 
-type MoveList = ArrayVec<SquareAndBitBoard, 18>;
+/// The horde can have up to 36 pieces, with eight more entries for the pawns moving two squares from the first rank and
+/// two for en passant, which is more than the 31 pieces, two en passant entries and five drops of Crazyhouse.
+type MoveList = ArrayVec<SquareAndBitBoard, 46>;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
@@ -439,13 +575,22 @@ pub struct MoveGenerator {
     to_bitboard_iterator_mask: BitBoard,
     index: usize,
     last_index: usize,
+    king_promotion: bool,
 }
 
 impl MoveGenerator {
     #[inline]
     fn enumerate_moves(position: &BoardPosition) -> MoveList {
+        Self::enumerate_masked_moves(position, !position.self_occupied())
+    }
+
+    /// Enumerates the legal moves to the squares of the mask, except for en passant captures, which are added
+    /// regardless of it.
+    fn enumerate_masked_moves(position: &BoardPosition, mask: BitBoard) -> MoveList {
+        if !position.get_variant().has_standard_kings() {
+            return Self::enumerate_variant_moves(position, mask);
+        }
         let checkers = position.get_checkers();
-        let mask = !position.self_occupied();
         let mut move_list = ArrayVec::new();
 
         if checkers.is_empty() {
@@ -465,11 +610,330 @@ impl MoveGenerator {
         } else {
             KingMoves::legals::<InCheckMoves>(&mut move_list, position, mask);
         }
+        Self::push_drops(
+            &mut move_list,
+            position,
+            Self::get_drop_targets(position) & mask,
+        );
+
+        if !position.get_variant().allows_checks() {
+            Self::remove_checking_moves(position, &mut move_list);
+        }
+
+        move_list
+    }
+
+    #[inline]
+    fn enumerate_pseudo_legal_moves(position: &BoardPosition) -> MoveList {
+        let mask = !position.self_occupied();
+        if !position.get_variant().has_standard_kings() {
+            return Self::enumerate_variant_pseudo_legal_moves(position, mask);
+        }
+        let mut move_list = ArrayVec::new();
+        PawnMoves::pseudo_legal_moves_with_ep(&mut move_list, position, mask);
+        KnightMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        BishopMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        RookMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        QueenMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        KingMoves::pseudo_legal_moves_with_castles(&mut move_list, position, mask);
+        Self::push_drops(&mut move_list, position, !position.occupied());
+        move_list
+    }
+
+    /// The pseudo-legal moves to the squares of the mask in the variants without standard kings, where a side may have
+    /// any number of kings. En passant captures are added regardless of the mask.
+    fn enumerate_variant_pseudo_legal_moves(position: &BoardPosition, mask: BitBoard) -> MoveList {
+        let mut move_list = ArrayVec::new();
+        PawnMoves::pseudo_legal_moves_with_ep(&mut move_list, position, mask);
+        KnightMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        BishopMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        RookMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        QueenMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        KingMoves::pseudo_legal_moves(&mut move_list, position, mask);
+        for source in position.get_colored_piece_mask(Pawn, position.turn()) & BB_BACKRANKS {
+            let dests = PawnMoves::get_horde_double_move(position, source) & mask;
+            if !dests.is_empty() {
+                unsafe {
+                    move_list.push_unchecked(SquareAndBitBoard::new(source, dests, false));
+                }
+            }
+        }
+        let castle_dests = KingMoves::get_pseudo_legal_castle_dests(position) & mask;
+        if !castle_dests.is_empty() {
+            unsafe {
+                move_list.push_unchecked(SquareAndBitBoard::new(
+                    position.get_king_square_unchecked(position.turn()),
+                    castle_dests,
+                    false,
+                ));
+            }
+        }
+        move_list
+    }
+
+    /// The legal moves to the squares of the mask in the variants without standard kings, filtered out of the
+    /// pseudo-legal ones by the rules of the variant.
+    fn enumerate_variant_moves(position: &BoardPosition, mask: BitBoard) -> MoveList {
+        let mut move_list = Self::enumerate_variant_pseudo_legal_moves(position, mask);
+        match position.get_variant() {
+            Variant::Antichess => {
+                if Self::is_capture_compulsory(position) {
+                    let ep_bitboard = position
+                        .ep_square()
+                        .map_or(BitBoard::EMPTY, |ep_square| ep_square.to_bitboard());
+                    move_list.retain(|square_and_bitboard| {
+                        let mut targets = position.opponent_occupied();
+                        if square_and_bitboard.get_piece_type(position) == Pawn {
+                            targets |= ep_bitboard;
+                        }
+                        square_and_bitboard.bitboard &= targets;
+                        !square_and_bitboard.bitboard.is_empty()
+                    });
+                }
+            }
+            _ => move_list.retain(|square_and_bitboard| {
+                // The promotion piece makes no difference to the safety of the king.
+                let promotion = square_and_bitboard.promotion.then_some(Queen);
+                for dest in square_and_bitboard.bitboard {
+                    if !Self::is_legal_in_variant(
+                        position,
+                        &square_and_bitboard.get_move(dest, promotion),
+                    ) {
+                        square_and_bitboard.bitboard ^= dest.to_bitboard();
+                    }
+                }
+                !square_and_bitboard.bitboard.is_empty()
+            }),
+        }
+        move_list
+    }
+
+    /// Whether the side to move can capture, which makes capturing compulsory in Antichess.
+    fn is_capture_compulsory(position: &BoardPosition) -> bool {
+        !Self::enumerate_variant_pseudo_legal_moves(position, position.opponent_occupied())
+            .is_empty()
+    }
+
+    /// Whether a pseudo-legal move follows the rules of a variant without standard kings.
+    fn is_legal_in_variant(position: &BoardPosition, move_: &Move) -> bool {
+        let color = position.turn();
+        match position.get_variant() {
+            Variant::Antichess => {
+                position.is_capture(*move_) || !Self::is_capture_compulsory(position)
+            }
+            Variant::Atomic => {
+                if !position.has_king(color) || !position.has_king(!color) {
+                    return false;
+                }
+                let next_position = position.make_move_new(*move_);
+                // Exploding the other king wins, even if the own king is left in check.
+                next_position.has_king(color)
+                    && (!next_position.has_king(!color)
+                        || next_position
+                            .null_move_unchecked()
+                            .get_checkers()
+                            .is_empty())
+            }
+            // Only black has a king to keep out of check.
+            Variant::Horde => {
+                !position.has_king(color)
+                    || position
+                        .make_move_new(*move_)
+                        .null_move_unchecked()
+                        .get_checkers()
+                        .is_empty()
+            }
+            _ => true,
+        }
+    }
+
+    /// The empty squares a piece can be dropped on without leaving the king in check.
+    fn get_drop_targets(position: &BoardPosition) -> BitBoard {
+        let checkers = position.get_checkers();
+        match checkers.popcnt() {
+            0 => !position.occupied(),
+            1 => checkers
+                .to_square_unchecked()
+                .between(position.get_king_square_unchecked(position.turn())),
+            _ => BitBoard::EMPTY,
+        }
+    }
+
+    /// Pushes the drops of the pieces in the pocket to the targets, leaving out pawns on the back ranks.
+    fn push_drops(move_list: &mut MoveList, position: &BoardPosition, targets: BitBoard) {
+        if !position.has_pocket_pieces(position.turn()) {
+            return;
+        }
+        for piece_type in const { [Pawn, Knight, Bishop, Rook, Queen] } {
+            if position.get_pocket_count(position.turn(), piece_type) == 0 {
+                continue;
+            }
+            let dests = if piece_type == Pawn {
+                targets & !BB_BACKRANKS
+            } else {
+                targets
+            };
+            if !dests.is_empty() {
+                unsafe {
+                    move_list.push_unchecked(SquareAndBitBoard::new_drop(piece_type, dests));
+                }
+            }
+        }
+    }
 
+    /// The squares from which a piece of the side to move attacks the king of the opponent directly.
+    fn get_direct_check_squares(position: &BoardPosition, piece_type: PieceType) -> BitBoard {
+        let their_ksq = position.get_king_square_unchecked(!position.turn());
+        let occupied = position.occupied();
+        match piece_type {
+            Pawn => their_ksq.get_pawn_attacks(!position.turn(), BB_ALL),
+            Knight => their_ksq.get_knight_moves(),
+            Bishop => get_bishop_moves(their_ksq, occupied),
+            Rook => get_rook_moves(their_ksq, occupied),
+            Queen => get_queen_moves(their_ksq, occupied),
+            King => BitBoard::EMPTY,
+        }
+    }
+
+    /// The own pieces which are the only piece between an own slider and the king of the opponent, so that moving
+    /// them off the line gives a discovered check.
+    fn get_discovered_check_candidates(position: &BoardPosition) -> BitBoard {
+        let color = position.turn();
+        let their_ksq = position.get_king_square_unchecked(!color);
+        let occupied = position.occupied();
+        let queens = position.get_colored_piece_mask(Queen, color);
+        let sliders = (their_ksq.get_bishop_rays_bb()
+            & (position.get_colored_piece_mask(Bishop, color) | queens))
+            | (their_ksq.get_rook_rays_bb()
+                & (position.get_colored_piece_mask(Rook, color) | queens));
+        let mut candidates = BitBoard::EMPTY;
+        for slider in sliders {
+            let blockers = slider.between(their_ksq) & occupied;
+            if blockers.popcnt() == 1 {
+                candidates |= blockers & position.self_occupied();
+            }
+        }
+        candidates
+    }
+
+    /// The destinations from which the piece moving from the source gives check, directly or by discovery, except for
+    /// castling with a rook check. Promotions are treated as pawn moves and drops have no source.
+    fn get_checking_dests(
+        position: &BoardPosition,
+        piece_type: PieceType,
+        optional_source: Option<Square>,
+        discovered_check_candidates: BitBoard,
+    ) -> BitBoard {
+        let mut checking_dests = Self::get_direct_check_squares(position, piece_type);
+        if let Some(source) = optional_source {
+            if discovered_check_candidates.contains(source) {
+                checking_dests |= !position
+                    .get_king_square_unchecked(!position.turn())
+                    .line(source);
+            }
+        }
+        checking_dests
+    }
+
+    fn remove_checking_moves(position: &BoardPosition, move_list: &mut MoveList) {
+        let discovered_check_candidates = Self::get_discovered_check_candidates(position);
+        move_list.retain(|square_and_bitboard| {
+            square_and_bitboard.bitboard &= !Self::get_checking_dests(
+                position,
+                square_and_bitboard.get_piece_type(position),
+                square_and_bitboard.get_source(),
+                discovered_check_candidates,
+            );
+            !square_and_bitboard.bitboard.is_empty()
+        });
+    }
+
+    /// Whether castling to the destination gives check with the rook.
+    fn is_castling_check(position: &BoardPosition, ksq: Square, dest: Square) -> bool {
+        let (rook_source, rook_dest) = if dest.get_file() > ksq.get_file() {
+            (dest.wrapping_right(), dest.wrapping_left())
+        } else {
+            (dest.wrapping_left().wrapping_left(), dest.wrapping_right())
+        };
+        let occupied = position.occupied()
+            ^ ksq.to_bitboard()
+            ^ dest.to_bitboard()
+            ^ rook_source.to_bitboard()
+            ^ rook_dest.to_bitboard();
+        get_rook_moves(rook_dest, occupied)
+            .contains(position.get_king_square_unchecked(!position.turn()))
+    }
+
+    fn enumerate_quiet_checks(position: &BoardPosition) -> MoveList {
+        let mut mask = !position.occupied();
+        if let Some(ep_square) = position.ep_square() {
+            mask &= !ep_square.to_bitboard();
+        }
+        if !position.get_variant().has_standard_kings() {
+            // Without standard kings, the checks are found by making the moves.
+            let mut move_list = Self::enumerate_variant_moves(position, mask);
+            move_list.retain(|square_and_bitboard| {
+                if square_and_bitboard.promotion {
+                    return false;
+                }
+                for dest in square_and_bitboard.bitboard {
+                    if !mask.contains(dest)
+                        || !position.gives_check(square_and_bitboard.get_move(dest, None))
+                    {
+                        square_and_bitboard.bitboard ^= dest.to_bitboard();
+                    }
+                }
+                !square_and_bitboard.bitboard.is_empty()
+            });
+            return move_list;
+        }
+        let discovered_check_candidates = Self::get_discovered_check_candidates(position);
+        let mut move_list = Self::enumerate_masked_moves(position, mask);
+        move_list.retain(|square_and_bitboard| {
+            // Promotions are left to the promotion generator, whether they give check or not.
+            if square_and_bitboard.promotion {
+                return false;
+            }
+            let source = square_and_bitboard.square;
+            let piece_type = square_and_bitboard.get_piece_type(position);
+            let mut check_squares = Self::get_checking_dests(
+                position,
+                piece_type,
+                square_and_bitboard.get_source(),
+                discovered_check_candidates,
+            );
+            if piece_type == King {
+                for dest in square_and_bitboard.bitboard & !source.get_king_moves() {
+                    if Self::is_castling_check(position, source, dest) {
+                        check_squares |= dest.to_bitboard();
+                    }
+                }
+            }
+            square_and_bitboard.bitboard &= check_squares & mask;
+            !square_and_bitboard.bitboard.is_empty()
+        });
+        move_list
+    }
+
+    fn enumerate_quiet_promotions(position: &BoardPosition) -> MoveList {
+        let mask = !position.occupied() & position.turn().to_their_backrank().to_bitboard();
+        let mut move_list = Self::enumerate_masked_moves(position, mask);
+        move_list.retain(|square_and_bitboard| square_and_bitboard.promotion);
         move_list
     }
 
+    fn enumerate_evasions(position: &BoardPosition) -> MoveList {
+        if position.get_checkers().is_empty() {
+            ArrayVec::new()
+        } else {
+            Self::enumerate_moves(position)
+        }
+    }
+
     pub fn has_legal_moves(position: &BoardPosition) -> bool {
+        if position.get_variant().restricts_moves() {
+            return !Self::enumerate_moves(position).is_empty();
+        }
         let checkers = position.get_checkers();
         let mask = !position.occupied_color(position.turn());
         let mut move_list = ArrayVec::new();
@@ -505,7 +969,8 @@ impl MoveGenerator {
             move_list.clear();
         }
 
-        false
+        Self::push_drops(&mut move_list, position, Self::get_drop_targets(position));
+        !move_list.is_empty()
     }
 
     #[inline]
@@ -517,6 +982,65 @@ impl MoveGenerator {
             to_bitboard_iterator_mask: BB_ALL,
             index: 0,
             last_index: usize::MAX,
+            king_promotion: position.get_variant().allows_king_promotion(),
+        }
+    }
+
+    /// Generates the legal non-capturing moves giving check, directly or by discovery, including castling with a rook
+    /// check but excluding promotions.
+    #[inline]
+    pub fn new_legal_quiet_checks(position: &BoardPosition) -> MoveGenerator {
+        MoveGenerator {
+            square_and_bitboard_array: MoveGenerator::enumerate_quiet_checks(position),
+            promotion_index: 0,
+            from_bitboard_iterator_mask: BB_ALL,
+            to_bitboard_iterator_mask: BB_ALL,
+            index: 0,
+            last_index: usize::MAX,
+            king_promotion: position.get_variant().allows_king_promotion(),
+        }
+    }
+
+    /// Generates the legal promotions which capture nothing.
+    #[inline]
+    pub fn new_legal_quiet_promotions(position: &BoardPosition) -> MoveGenerator {
+        MoveGenerator {
+            square_and_bitboard_array: MoveGenerator::enumerate_quiet_promotions(position),
+            promotion_index: 0,
+            from_bitboard_iterator_mask: BB_ALL,
+            to_bitboard_iterator_mask: BB_ALL,
+            index: 0,
+            last_index: usize::MAX,
+            king_promotion: position.get_variant().allows_king_promotion(),
+        }
+    }
+
+    /// Generates the legal moves getting out of check, which are none when the side to move is not in check.
+    #[inline]
+    pub fn new_legal_evasions(position: &BoardPosition) -> MoveGenerator {
+        MoveGenerator {
+            square_and_bitboard_array: MoveGenerator::enumerate_evasions(position),
+            promotion_index: 0,
+            from_bitboard_iterator_mask: BB_ALL,
+            to_bitboard_iterator_mask: BB_ALL,
+            index: 0,
+            last_index: usize::MAX,
+            king_promotion: position.get_variant().allows_king_promotion(),
+        }
+    }
+
+    /// Generates the moves without checking whether they leave the king in check, which is much cheaper in nodes where
+    /// most moves are never played. Every move has to pass [`Self::is_legal_after_pseudo`] before it is made.
+    #[inline]
+    pub fn new_pseudo_legal(position: &BoardPosition) -> MoveGenerator {
+        MoveGenerator {
+            square_and_bitboard_array: MoveGenerator::enumerate_pseudo_legal_moves(position),
+            promotion_index: 0,
+            from_bitboard_iterator_mask: BB_ALL,
+            to_bitboard_iterator_mask: BB_ALL,
+            index: 0,
+            last_index: usize::MAX,
+            king_promotion: position.get_variant().allows_king_promotion(),
         }
     }
 
@@ -559,7 +1083,9 @@ impl MoveGenerator {
     pub fn remove_move(&mut self, move_: Move) -> bool {
         let mut square_removed = false;
         for square_and_bitboard in self.square_and_bitboard_array.iter_mut() {
-            if square_and_bitboard.square == move_.get_source() {
+            if square_and_bitboard.drop == move_.get_drop()
+                && (move_.is_drop() || square_and_bitboard.square == move_.get_source())
+            {
                 square_and_bitboard.bitboard &= !move_.get_dest().to_bitboard();
                 square_removed = true;
             }
@@ -615,6 +1141,50 @@ impl MoveGenerator {
         }
     }
 
+    /// Counts the leaf nodes like [`Self::perft_test`], but with the pseudo-legal generator and
+    /// [`Self::is_legal_after_pseudo`].
+    pub fn perft_test_pseudo_legal(position: &BoardPosition, depth: usize) -> usize {
+        let legal_moves = position
+            .generate_pseudo_legal_moves()
+            .filter(|move_| Self::is_legal_after_pseudo(position, move_));
+        if depth == 1 {
+            return legal_moves.count();
+        }
+        legal_moves
+            .map(|move_| Self::perft_test_pseudo_legal(&position.make_move_new(move_), depth - 1))
+            .sum()
+    }
+
+    /// Counts the leaf nodes while checking in every node that both generators agree on the legal moves and that
+    /// [`Self::is_pseudo_legal`] accepts every pseudo-legal move.
+    pub fn perft_cross_check(position: &BoardPosition, depth: usize) -> Result<usize> {
+        let legal_moves = position.generate_legal_moves().collect_vec();
+        let pseudo_legal_moves = position.generate_pseudo_legal_moves().collect_vec();
+        let mut mismatched_moves = pseudo_legal_moves
+            .iter()
+            .filter(|move_| !Self::is_pseudo_legal(position, move_))
+            .copied()
+            .collect_vec();
+        let filtered_moves: HashSet<Move> = pseudo_legal_moves
+            .into_iter()
+            .filter(|move_| Self::is_legal_after_pseudo(position, move_))
+            .collect();
+        let legal_move_set: HashSet<Move> = legal_moves.iter().copied().collect();
+        mismatched_moves.extend(filtered_moves.symmetric_difference(&legal_move_set));
+        if !mismatched_moves.is_empty() {
+            return Err(TimecatError::MoveGenerationMismatch {
+                fen: position.get_fen(),
+                mismatched_moves,
+            });
+        }
+        if depth <= 1 {
+            return Ok(legal_moves.len());
+        }
+        legal_moves.into_iter().try_fold(0, |count, move_| {
+            Ok(count + Self::perft_cross_check(&position.make_move_new(move_), depth - 1)?)
+        })
+    }
+
     pub fn perft_test_piecewise(position: &BoardPosition, depth: usize) -> usize {
         let mut iterable = position.generate_legal_moves();
 
@@ -646,80 +1216,162 @@ impl MoveGenerator {
         self.square_and_bitboard_array
             .iter()
             .filter(|square_and_bitboard| {
-                self.from_bitboard_iterator_mask
-                    .contains(square_and_bitboard.square)
+                square_and_bitboard.is_from(self.from_bitboard_iterator_mask)
             })
             .take_while(|square_and_bitboard| {
                 !(square_and_bitboard.bitboard & self.to_bitboard_iterator_mask).is_empty()
             })
             .flat_map(move |square_and_bitboard| {
-                let promotion_pieces: &[Option<PieceType>] = if square_and_bitboard.promotion {
-                    const { &[Some(Queen), Some(Knight), Some(Rook), Some(Bishop)] }
-                } else {
-                    const { &[None] }
-                };
+                let promotion_pieces: &[Option<PieceType>] =
+                    match (square_and_bitboard.promotion, self.king_promotion) {
+                        (false, _) => const { &[None] },
+                        (true, false) => {
+                            const { &[Some(Queen), Some(Knight), Some(Rook), Some(Bishop)] }
+                        }
+                        (true, true) => {
+                            const {
+                                &[
+                                    Some(Queen),
+                                    Some(Knight),
+                                    Some(Rook),
+                                    Some(Bishop),
+                                    Some(King),
+                                ]
+                            }
+                        }
+                    };
                 promotion_pieces.iter().flat_map(move |&promotion| {
-                    (square_and_bitboard.bitboard & self.to_bitboard_iterator_mask).map(
-                        move |dest| {
-                            Move::new_unchecked(square_and_bitboard.square, dest, promotion)
-                        },
-                    )
+                    (square_and_bitboard.bitboard & self.to_bitboard_iterator_mask)
+                        .map(move |dest| square_and_bitboard.get_move(dest, promotion))
                 })
             })
     }
 
+    /// The pieces a pawn can promote to, which include the king in Antichess.
+    #[inline]
+    fn get_promotion_pieces(&self) -> &'static [PieceType] {
+        if self.king_promotion {
+            &ANTICHESS_PROMOTION_PIECES
+        } else {
+            &PROMOTION_PIECES
+        }
+    }
+
     #[inline]
     pub fn contains(&self, move_: &Move) -> bool {
         self.square_and_bitboard_array
             .iter()
             .any(|square_and_bitboard| {
-                square_and_bitboard.square == move_.get_source()
+                square_and_bitboard.drop == move_.get_drop()
+                    && (move_.is_drop() || square_and_bitboard.square == move_.get_source())
                     && square_and_bitboard.bitboard.contains(move_.get_dest())
                     && if square_and_bitboard.promotion {
-                        const { [Some(Knight), Some(Bishop), Some(Rook), Some(Queen)] }
-                            .contains(&move_.get_promotion())
+                        move_.get_promotion().is_some_and(|promotion| {
+                            self.get_promotion_pieces().contains(&promotion)
+                        })
                     } else {
                         move_.get_promotion().is_none()
                     }
             })
     }
 
-    #[inline]
-    pub fn is_legal(position: &BoardPosition, move_: &Move) -> bool {
-        // TODO: Scope of improvement
-        let Some(piece_type) = position.get_piece_type_at(move_.get_source()) else {
+    /// Whether the move could be generated by [`Self::new_pseudo_legal`], which is cheap enough to validate moves from
+    /// the transposition table or the killer moves, which may belong to another position.
+    pub fn is_pseudo_legal(position: &BoardPosition, move_: &Move) -> bool {
+        let source = move_.get_source();
+        let dest = move_.get_dest();
+        let color = position.turn();
+        if let Some(piece_type) = move_.get_drop() {
+            return piece_type != King
+                && position.get_pocket_count(color, piece_type) > 0
+                && !position.occupied().contains(dest)
+                && (piece_type != Pawn || !BB_BACKRANKS.contains(dest));
+        }
+        let self_occupied = position.self_occupied();
+        if !self_occupied.contains(source) || self_occupied.contains(dest) {
+            return false;
+        }
+        let Some(piece_type) = position.get_piece_type_at(source) else {
             return false;
         };
-        let possibly_legal = match piece_type {
+        let is_promotion = piece_type == Pawn && source.get_rank() == color.to_seventh_rank();
+        match move_.get_promotion() {
+            Some(Pawn) => return false,
+            Some(King) if !position.get_variant().allows_king_promotion() => return false,
+            Some(_) if !is_promotion => return false,
+            None if is_promotion => return false,
+            _ => (),
+        }
+        let occupied = position.occupied();
+        let targets = match piece_type {
             Pawn => {
-                if move_.get_source().get_file() != move_.get_dest().get_file()
-                    && position.get_piece_type_at(move_.get_dest()).is_none()
-                {
-                    // en-passant
-                    PawnMoves::legal_ep_move(position, move_.get_source(), move_.get_dest())
-                } else {
-                    true
-                }
-            }
-            King => {
-                let bb = move_.get_source().between(move_.get_dest());
-                if bb.popcnt() == 1 {
-                    // castles
-                    if !KingMoves::legal_king_move(position, bb.to_square_unchecked()) {
-                        false
-                    } else {
-                        KingMoves::legal_king_move(position, move_.get_dest())
-                    }
-                } else {
-                    KingMoves::legal_king_move(position, move_.get_dest())
+                let mut targets = source.get_pawn_moves(color, occupied);
+                if let Some(ep_square) = position.ep_square() {
+                    targets |= source.get_pawn_attacks(color, ep_square.to_bitboard());
                 }
+                targets | PawnMoves::get_horde_double_move(position, source)
             }
-            _ => true,
+            Knight => source.get_knight_moves(),
+            Bishop => get_bishop_moves(source, occupied),
+            Rook => get_rook_moves(source, occupied),
+            Queen => get_queen_moves(source, occupied),
+            King => source.get_king_moves() | KingMoves::get_pseudo_legal_castle_dests(position),
         };
-        if !possibly_legal {
+        targets.contains(dest)
+    }
+
+    /// Whether a pseudo-legal move leaves the own king safe, without generating any moves.
+    pub fn is_legal_after_pseudo(position: &BoardPosition, move_: &Move) -> bool {
+        if !position.get_variant().has_standard_kings() {
+            return Self::is_legal_in_variant(position, move_);
+        }
+        let source = move_.get_source();
+        let dest = move_.get_dest();
+        let optional_source = (!move_.is_drop()).then_some(source);
+        if !position.get_variant().allows_checks()
+            && Self::get_checking_dests(
+                position,
+                move_
+                    .get_drop()
+                    .unwrap_or_else(|| position.get_piece_type_at(source).unwrap()),
+                optional_source,
+                Self::get_discovered_check_candidates(position),
+            )
+            .contains(dest)
+        {
             return false;
         }
-        position.generate_legal_moves().contains(move_)
+        if move_.is_drop() {
+            return Self::get_drop_targets(position).contains(dest);
+        }
+        let ksq = position.get_king_square_unchecked(position.turn());
+        if source == ksq {
+            // The squares the king passes while castling were already checked.
+            return KingMoves::legal_king_move(position, dest);
+        }
+        if position.ep_square() == Some(dest)
+            && source.get_file() != dest.get_file()
+            && position.get_piece_type_at(source) == Some(Pawn)
+        {
+            return PawnMoves::legal_ep_move(position, source, dest);
+        }
+        let checkers = position.get_checkers();
+        match checkers.popcnt() {
+            0 => (),
+            1 => {
+                let check_mask = checkers.to_square_unchecked().between(ksq) ^ checkers;
+                if !check_mask.contains(dest) || position.pinned().contains(source) {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+        !position.pinned().contains(source) || ksq.line(source).contains(dest)
+    }
+
+    #[inline]
+    pub fn is_legal(position: &BoardPosition, move_: &Move) -> bool {
+        Self::is_pseudo_legal(position, move_) && Self::is_legal_after_pseudo(position, move_)
     }
 }
 
@@ -729,16 +1381,14 @@ impl ExactSizeIterator for MoveGenerator {
         for square_and_bitboard in &self.square_and_bitboard_array {
             let bitboard_and_to_bitboard_iterator_mask =
                 square_and_bitboard.bitboard & self.to_bitboard_iterator_mask;
-            if !self
-                .from_bitboard_iterator_mask
-                .contains(square_and_bitboard.square)
+            if !square_and_bitboard.is_from(self.from_bitboard_iterator_mask)
                 || bitboard_and_to_bitboard_iterator_mask.is_empty()
             {
                 break;
             }
             if square_and_bitboard.promotion {
                 result += (bitboard_and_to_bitboard_iterator_mask.popcnt() as usize)
-                    * NUM_PROMOTION_PIECES;
+                    * self.get_promotion_pieces().len();
             } else {
                 result += bitboard_and_to_bitboard_iterator_mask.popcnt() as usize;
             }
@@ -763,9 +1413,9 @@ impl Iterator for MoveGenerator {
             return None;
         }
         if self.index != self.last_index {
-            while !self.from_bitboard_iterator_mask.contains(
-                get_item_unchecked_mut!(self.square_and_bitboard_array, self.index).square,
-            ) {
+            while !get_item_unchecked!(self.square_and_bitboard_array, self.index)
+                .is_from(self.from_bitboard_iterator_mask)
+            {
                 self.index += 1;
                 if self.index >= square_and_bitboard_array_len {
                     return None;
@@ -773,12 +1423,11 @@ impl Iterator for MoveGenerator {
             }
             self.last_index = self.index;
         }
+        let promotion_pieces = self.get_promotion_pieces();
         let square_and_bitboard =
             get_item_unchecked_mut!(self.square_and_bitboard_array, self.index);
 
-        if !self
-            .from_bitboard_iterator_mask
-            .contains(square_and_bitboard.square)
+        if !square_and_bitboard.is_from(self.from_bitboard_iterator_mask)
             || (square_and_bitboard.bitboard & self.to_bitboard_iterator_mask).is_empty()
         {
             // are we done?
@@ -791,10 +1440,10 @@ impl Iterator for MoveGenerator {
             let result = Move::new_unchecked(
                 square_and_bitboard.square,
                 dest,
-                Some(*get_item_unchecked!(PROMOTION_PIECES, self.promotion_index)),
+                Some(*get_item_unchecked!(promotion_pieces, self.promotion_index)),
             );
             self.promotion_index += 1;
-            if self.promotion_index >= NUM_PROMOTION_PIECES {
+            if self.promotion_index >= promotion_pieces.len() {
                 square_and_bitboard.bitboard ^= dest.to_bitboard();
                 self.promotion_index = 0;
                 if (square_and_bitboard.bitboard & self.to_bitboard_iterator_mask).is_empty() {
@@ -811,7 +1460,7 @@ impl Iterator for MoveGenerator {
             if (square_and_bitboard.bitboard & self.to_bitboard_iterator_mask).is_empty() {
                 self.index += 1;
             }
-            Some(Move::new_unchecked(square_and_bitboard.square, dest, None))
+            Some(square_and_bitboard.get_move(dest, None))
         }
     }
 }
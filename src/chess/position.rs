@@ -6,6 +6,10 @@ pub enum BoardStatus {
     Ongoing,
     Stalemate,
     Checkmate,
+    /// The game was won under the rules of the variant.
+    VariantWin(Color),
+    /// The game was drawn under the rules of the variant.
+    VariantDraw,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -25,6 +29,10 @@ pub struct BoardPosition {
     _halfmove_clock: u8,
     _fullmove_number: NumMoves,
     _material_scores: [Score; 2],
+    _variant: Variant,
+    _remaining_checks: [u8; NUM_COLORS],
+    _pockets: [[u8; NUM_PIECE_TYPES]; NUM_COLORS],
+    _promoted: BitBoard,
 }
 
 impl UniqueIdentifier for BoardPosition {
@@ -36,6 +44,10 @@ impl UniqueIdentifier for BoardPosition {
             &self._castle_rights,
             &self._turn,
             &self._ep_square,
+            &self._variant,
+            &self._remaining_checks,
+            &self._pockets,
+            &self._promoted,
         )
     }
 }
@@ -68,6 +80,10 @@ impl BoardPosition {
             _halfmove_clock: 0,
             _fullmove_number: 1,
             _material_scores: [0; 2],
+            _variant: Variant::default(),
+            _remaining_checks: [THREE_CHECK_NUM_CHECKS; NUM_COLORS],
+            _pockets: [[0; NUM_PIECE_TYPES]; NUM_COLORS],
+            _promoted: BitBoard::EMPTY,
         }
     }
 
@@ -76,9 +92,51 @@ impl BoardPosition {
         MoveGenerator::has_legal_moves(self)
     }
 
+    #[inline]
+    pub fn get_variant(&self) -> Variant {
+        self._variant
+    }
+
+    /// The number of checks the color still has to give to win a game of Three-check.
+    #[inline]
+    pub fn get_remaining_checks(&self, color: Color) -> u8 {
+        *get_item_unchecked!(self._remaining_checks, color.to_index())
+    }
+
+    /// The number of pieces of the kind the color has in its pocket in Crazyhouse.
+    #[inline]
+    pub fn get_pocket_count(&self, color: Color, piece_type: PieceType) -> u8 {
+        *get_item_unchecked!(self._pockets, color.to_index(), piece_type.to_index())
+    }
+
+    #[inline]
+    pub fn has_pocket_pieces(&self, color: Color) -> bool {
+        get_item_unchecked!(self._pockets, color.to_index())
+            .iter()
+            .any(|&count| count > 0)
+    }
+
+    /// The pieces promoted from pawns in Crazyhouse, which go back to the pocket as pawns when captured.
+    #[inline]
+    pub fn get_promoted_mask(&self) -> BitBoard {
+        self._promoted
+    }
+
+    #[inline]
+    fn add_to_pocket(&mut self, color: Color, piece_type: PieceType) {
+        *get_item_unchecked_mut!(self._pockets, color.to_index(), piece_type.to_index()) += 1;
+    }
+
+    #[inline]
+    fn remove_from_pocket(&mut self, color: Color, piece_type: PieceType) {
+        *get_item_unchecked_mut!(self._pockets, color.to_index(), piece_type.to_index()) -= 1;
+    }
+
     #[inline]
     pub fn status(&self) -> BoardStatus {
-        if self.has_legal_moves() {
+        if let Some(status) = self.get_variant().get_status(self) {
+            status
+        } else if self.has_legal_moves() {
             BoardStatus::Ongoing
         } else if self.get_checkers() == BitBoard::EMPTY {
             BoardStatus::Stalemate
@@ -122,12 +180,26 @@ impl BoardPosition {
         self.occupied_color(White)
     }
 
+    /// The square of the king of the color, or [`None`] if the color has no king, which some variants allow.
+    #[inline]
+    pub fn get_king_square(&self, color: Color) -> Option<Square> {
+        self.get_colored_piece_mask(King, color).to_square()
+    }
+
+    /// Like [`Self::get_king_square`], for a color known to have exactly one king, as in every variant with standard
+    /// kings.
     #[inline]
-    pub fn get_king_square(&self, color: Color) -> Square {
+    pub fn get_king_square_unchecked(&self, color: Color) -> Square {
         self.get_colored_piece_mask(King, color)
             .to_square_unchecked()
     }
 
+    /// Whether the side has a king, which is always the case but in the variants without standard kings.
+    #[inline]
+    pub fn has_king(&self, color: Color) -> bool {
+        !self.get_colored_piece_mask(King, color).is_empty()
+    }
+
     #[inline]
     pub fn get_all_piece_masks(&self) -> &[BitBoard] {
         &self._piece_masks
@@ -144,10 +216,18 @@ impl BoardPosition {
     }
 
     pub fn has_insufficient_material(&self, color: Color) -> bool {
+        if self.has_pocket_pieces(color) {
+            return false;
+        }
+        // A side without a king loses once all of its pieces are captured, which any piece can set out to do.
+        if !self.has_king(!color) {
+            return false;
+        }
         let occupied = self.occupied_color(color);
-        match occupied.popcnt() {
-            1 => true,
-            2 => ((self.get_piece_mask(Rook)
+        let num_kings = self.get_colored_piece_mask(King, color).popcnt();
+        match occupied.popcnt() - num_kings {
+            0 => true,
+            1 => ((self.get_piece_mask(Rook)
                 ^ self.get_piece_mask(Queen)
                 ^ self.get_piece_mask(Pawn))
                 & occupied)
@@ -181,6 +261,27 @@ impl BoardPosition {
 
     #[inline]
     pub fn is_insufficient_material(&self) -> bool {
+        match self.get_variant() {
+            Variant::Standard => (),
+            // Any piece can still give check.
+            Variant::ThreeCheck => return self.occupied().popcnt() == 2,
+            // The kings can still race for their goal squares.
+            Variant::KingOfTheHill | Variant::RacingKings => return false,
+            // Any piece can still explode the other king, and a lone king has already won.
+            Variant::Atomic => {
+                return self.occupied().popcnt() == 2
+                    && self.occupied() == self.get_piece_mask(King)
+            }
+            // Losing the pieces is the goal, and the horde can always be captured.
+            Variant::Antichess | Variant::Horde => return false,
+            // The captured pieces are never gone for good.
+            Variant::Crazyhouse => {
+                return self.occupied().popcnt() == 2
+                    && ALL_COLORS
+                        .into_iter()
+                        .all(|color| !self.has_pocket_pieces(color))
+            }
+        }
         match self.occupied().popcnt() {
             2 => true,
             3 => const { [Pawn, Rook, Queen] }
@@ -279,6 +380,27 @@ impl BoardPosition {
     //     }
     // }
 
+    /// Removes the piece on the square and the pieces around it but the pawns, as a capture does in Atomic.
+    fn explode(&mut self, square: Square) {
+        let exploded = (square.get_king_moves() & !self.get_piece_mask(Pawn)
+            | square.to_bitboard())
+            & self.occupied();
+        for square in exploded {
+            let piece = self.get_piece_at(square).unwrap();
+            self.xor(
+                piece.get_piece_type(),
+                square.to_bitboard(),
+                piece.get_color(),
+            );
+            for color in ALL_COLORS {
+                self.remove_castle_rights(
+                    color,
+                    CastleRights::square_to_castle_rights(color, square),
+                );
+            }
+        }
+    }
+
     fn xor(&mut self, piece_type: PieceType, bb: BitBoard, color: Color) {
         *get_item_unchecked_mut!(self._piece_masks, piece_type.to_index()) ^= bb;
         let colored_piece_mask = get_item_unchecked_mut!(self._occupied_color, color.to_index());
@@ -322,6 +444,30 @@ impl BoardPosition {
                 .ep_square()
                 .map_or(0, |ep| Zobrist::en_passant(ep.get_file()))
             ^ Zobrist::color(self.turn())
+            ^ self.get_remaining_checks_hash()
+            ^ self.get_pockets_hash()
+    }
+
+    #[inline]
+    fn get_remaining_checks_hash(&self) -> u64 {
+        if self.get_variant() != Variant::ThreeCheck {
+            return 0;
+        }
+        Zobrist::remaining_checks(White, self.get_remaining_checks(White))
+            ^ Zobrist::remaining_checks(Black, self.get_remaining_checks(Black))
+    }
+
+    #[inline]
+    fn get_pockets_hash(&self) -> u64 {
+        if !self.get_variant().has_pockets() {
+            return 0;
+        }
+        ALL_COLORS
+            .into_iter()
+            .cartesian_product(ALL_PIECE_TYPES)
+            .fold(0, |hash, (color, piece_type)| {
+                hash ^ Zobrist::pocket(color, piece_type, self.get_pocket_count(color, piece_type))
+            })
     }
 
     #[inline]
@@ -391,71 +537,158 @@ impl BoardPosition {
             return false;
         }
 
-        // make sure there is exactly one white king
-        if self.get_colored_piece_mask(King, White).popcnt() != 1 {
-            return false;
+        self.validate().is_empty()
+    }
+
+    /// Lists everything which makes the position illegal, like the kings, the checks, the castling rights or the en
+    /// passant square being inconsistent with the pieces, so that a position set up by hand can be explained.
+    pub fn validate(&self) -> Vec<PositionIssue> {
+        let mut issues = Vec::new();
+        let variant = self.get_variant();
+
+        for color in ALL_COLORS {
+            if variant.has_standard_kings() {
+                match self.get_colored_piece_mask(King, color).popcnt() {
+                    0 => issues.push(PositionIssue::MissingKing { color }),
+                    1 => (),
+                    _ => issues.push(PositionIssue::TooManyKings { color }),
+                }
+            }
+            if self.get_colored_piece_mask(Pawn, color).popcnt() > variant.get_max_num_pawns(color)
+            {
+                issues.push(PositionIssue::TooManyPawns { color });
+            }
+            if self.occupied_color(color).popcnt() > variant.get_max_num_pieces(color) {
+                issues.push(PositionIssue::TooManyPieces { color });
+            }
         }
 
-        // make sure there is exactly one black king
-        if self.get_colored_piece_mask(King, Black).popcnt() != 1 {
-            return false;
+        // A pawn on a back rank would have promoted, unless it is a pawn of the horde still on its first rank.
+        let mut backrank_pawns = self.get_piece_mask(Pawn) & BB_BACKRANKS;
+        if variant == Variant::Horde {
+            backrank_pawns &= !(self.get_colored_piece_mask(Pawn, White) & BB_RANK_1);
+        }
+        if !backrank_pawns.is_empty() {
+            issues.push(PositionIssue::PawnsOnBackRank);
         }
 
-        // make sure the en passant square has a pawn on it of the right color
-        if let Some(x) = self.ep_square() {
-            let mut square_bb = x.to_bitboard();
-            if self.turn() == White {
-                square_bb >>= 8;
-            } else {
-                square_bb <<= 8;
+        // The checks only make sense with the kings of both sides in place.
+        if !issues.iter().any(|issue| {
+            matches!(
+                issue,
+                PositionIssue::MissingKing { .. } | PositionIssue::TooManyKings { .. }
+            )
+        }) {
+            // my opponent must not be in check
+            let mut board_copy = self.to_owned();
+            board_copy.flip_turn_unchecked();
+            board_copy.update_pin_and_checkers_info();
+            if !board_copy.get_checkers().is_empty() {
+                issues.push(PositionIssue::OpponentInCheck);
             }
-            if (self.get_colored_piece_mask(Pawn, !self.turn()) & square_bb).is_empty() {
-                return false;
+
+            // and the kings must not touch
+            if variant.has_standard_kings()
+                && !(self.get_king_square_unchecked(White).get_king_moves()
+                    & self.get_piece_mask(King))
+                .is_empty()
+            {
+                issues.push(PositionIssue::KingsAdjacent);
             }
-        }
 
-        // make sure my opponent is not currently in check (because that would be illegal)
-        let mut board_copy = self.to_owned();
-        board_copy.flip_turn_unchecked();
-        board_copy.update_pin_and_checkers_info();
-        if !board_copy.get_checkers().is_empty() {
-            return false;
+            // a move can give at most two checks, one of which is discovered along the line of a slider
+            let checkers = self.get_checkers();
+            match checkers.popcnt() {
+                0 | 1 => (),
+                2 => {
+                    if !self.is_possible_double_check(checkers) {
+                        issues.push(PositionIssue::ImpossibleCheck);
+                    }
+                }
+                _ => issues.push(PositionIssue::TooManyCheckers),
+            }
         }
 
         // for each color, verify that, if they have castle rights, that they haven't moved their
         // rooks or king
         for color in ALL_COLORS {
-            // get the castle rights
-            let castle_rights = self.castle_rights(color);
-
-            // the castle rights object will tell us which rooks shouldn't have moved yet.
-            // verify there are rooks on all those squares
-            if castle_rights.unmoved_rooks(color)
-                & self.get_piece_mask(Rook)
-                & self.occupied_color(color)
-                != castle_rights.unmoved_rooks(color)
-            {
-                return false;
+            if self.castle_rights(color) != self.get_valid_castle_rights(color) {
+                issues.push(PositionIssue::InvalidCastlingRights { color });
             }
-            // if we have castle rights, make sure we have a king on the (E, {1,8}) square,
-            // depending on the color
-            if castle_rights != CastleRights::None
-                && self.get_colored_piece_mask(King, color)
-                    != BB_FILE_E & color.to_my_backrank().to_bitboard()
-            {
-                return false;
+        }
+
+        if let Some(ep_square) = self.ep_square() {
+            if !self.is_valid_ep_square(ep_square) {
+                issues.push(PositionIssue::InvalidEnPassant);
             }
         }
 
-        // we must make sure the kings aren't touching
-        if !(self.get_king_square(White).get_king_moves() & self.get_piece_mask(King)).is_empty() {
-            return false;
+        // and the position must follow the rules of the variant
+        if !variant.is_sane(self) {
+            issues.push(PositionIssue::VariantRules { variant });
         }
 
-        // it checks out
+        issues
+    }
+
+    /// Whether a single move can have given the two checks, one of them discovered by moving a piece off the line of a
+    /// slider.
+    fn is_possible_double_check(&self, checkers: BitBoard) -> bool {
+        let sliders =
+            self.get_piece_mask(Bishop) ^ self.get_piece_mask(Rook) ^ self.get_piece_mask(Queen);
+        if (checkers & sliders).is_empty() {
+            return false;
+        }
+        // the piece which moved off the line cannot check along the same line
+        let first_checker = checkers.to_square_unchecked();
+        let second_checker = (checkers ^ first_checker.to_bitboard()).to_square_unchecked();
+        if first_checker
+            .line(second_checker)
+            .contains(self.get_king_square_unchecked(self.turn()))
+        {
+            return false;
+        }
+        // two bishops can never check together, and two rooks only when a pawn capturing into a rook on the last rank
+        // uncovers the other one
+        if checkers & self.get_piece_mask(Bishop) == checkers {
+            return false;
+        }
+        if checkers & self.get_piece_mask(Rook) == checkers {
+            return !(checkers & self.turn().to_my_backrank().to_bitboard()).is_empty();
+        }
         true
     }
 
+    /// The castle rights of the color kept only where its king and rooks are still on their starting squares.
+    fn get_valid_castle_rights(&self, color: Color) -> CastleRights {
+        let castle_rights = self.castle_rights(color);
+        if self.get_colored_piece_mask(King, color)
+            != BB_FILE_E & color.to_my_backrank().to_bitboard()
+        {
+            return CastleRights::None;
+        }
+        let unmoved_rooks =
+            castle_rights.unmoved_rooks(color) & self.get_colored_piece_mask(Rook, color);
+        [CastleRights::KingSide, CastleRights::QueenSide]
+            .into_iter()
+            .filter(|&side| !(side.unmoved_rooks(color) & unmoved_rooks).is_empty())
+            .fold(CastleRights::None, CastleRights::add)
+    }
+
+    /// Whether the pawn of the side which just moved can have skipped the en passant square with a double move.
+    fn is_valid_ep_square(&self, ep_square: Square) -> bool {
+        let color = !self.turn();
+        let (Some(pawn_square), Some(source)) =
+            (ep_square.forward(color), ep_square.backward(color))
+        else {
+            return false;
+        };
+        self.get_colored_piece_mask(Pawn, color)
+            .contains(pawn_square)
+            && !self.occupied().contains(ep_square)
+            && !self.occupied().contains(source)
+    }
+
     #[inline]
     pub fn get_piece_type_at(&self, square: Square) -> Option<PieceType> {
         // TODO: check speed on Naive Algorithm
@@ -541,9 +774,17 @@ impl BoardPosition {
         Self::from_str(fen)
     }
 
+    /// Like [`Self::from_fen`], but drops the castle rights and the en passant square inconsistent with the pieces
+    /// instead of failing on them.
+    pub fn from_fen_lenient(fen: &str) -> Result<Self> {
+        BoardPositionBuilder::from_str(&simplify_fen(fen))?
+            .repair()
+            .try_into()
+    }
+
     pub fn is_good_fen(fen: &str) -> bool {
         let fen = simplify_fen(fen);
-        let mut splitted_fen = fen.split(' ');
+        let mut splitted_fen = fen.split(' ').filter(|token| !token.contains('+'));
         if splitted_fen.nth(4).unwrap_or("0").parse().unwrap_or(-1) < 0
             || splitted_fen.next().unwrap_or("1").parse().unwrap_or(-1) < 0
             || splitted_fen.next().is_some()
@@ -553,12 +794,18 @@ impl BoardPosition {
         Self::from_str(&fen).is_ok()
     }
 
+    /// Sets the position from the FEN, keeping the current variant unless it is standard chess and the FEN is of
+    /// another variant.
     pub fn set_fen(&mut self, fen: &str) -> Result<()> {
         let fen = simplify_fen(fen);
         if fen == self.get_fen() {
             return Ok(());
         }
-        *self = Self::from_str(&fen)?;
+        let mut position_builder = BoardPositionBuilder::from_str(&fen)?;
+        if self.get_variant() != Variant::Standard {
+            position_builder.variant(self.get_variant());
+        }
+        *self = position_builder.try_into()?;
         Ok(())
     }
 
@@ -618,6 +865,14 @@ impl BoardPosition {
         !(touched & self.opponent_occupied()).is_empty() || self.is_en_passant(move_)
     }
 
+    /// The piece type of the piece moved, or dropped, by the move.
+    #[inline]
+    pub fn get_moving_piece_type(&self, move_: Move) -> Option<PieceType> {
+        move_
+            .get_drop()
+            .or_else(|| self.get_piece_type_at(move_.get_source()))
+    }
+
     fn set_ep(&mut self, square: Square) {
         // Only set self._ep_square if the pawn can actually be captured next move.
         let mut rank = square.get_rank();
@@ -641,6 +896,11 @@ impl BoardPosition {
         MoveGenerator::new_legal(self)
     }
 
+    #[inline]
+    pub fn generate_pseudo_legal_moves(&self) -> MoveGenerator {
+        MoveGenerator::new_pseudo_legal(self)
+    }
+
     pub fn generate_masked_legal_moves(
         &self,
         from_bitboard: BitBoard,
@@ -662,11 +922,36 @@ impl BoardPosition {
         moves
     }
 
+    #[inline]
+    pub fn generate_legal_quiet_checks(&self) -> MoveGenerator {
+        MoveGenerator::new_legal_quiet_checks(self)
+    }
+
+    #[inline]
+    pub fn generate_legal_quiet_promotions(&self) -> MoveGenerator {
+        MoveGenerator::new_legal_quiet_promotions(self)
+    }
+
+    #[inline]
+    pub fn generate_legal_evasions(&self) -> MoveGenerator {
+        MoveGenerator::new_legal_evasions(self)
+    }
+
     #[inline]
     pub fn is_legal(&self, move_: &Move) -> bool {
         MoveGenerator::is_legal(self, move_)
     }
 
+    #[inline]
+    pub fn is_pseudo_legal(&self, move_: &Move) -> bool {
+        MoveGenerator::is_pseudo_legal(self, move_)
+    }
+
+    #[inline]
+    pub fn is_legal_after_pseudo(&self, move_: &Move) -> bool {
+        MoveGenerator::is_legal_after_pseudo(self, move_)
+    }
+
     pub fn is_castling(&self, move_: Move) -> bool {
         if !self.get_piece_mask(King).contains(move_.get_source()) {
             return false;
@@ -683,6 +968,9 @@ impl BoardPosition {
     }
 
     pub fn is_zeroing(&self, move_: Move) -> bool {
+        if let Some(piece_type) = move_.get_drop() {
+            return piece_type == Pawn;
+        }
         let touched = move_.get_source().to_bitboard() ^ move_.get_dest().to_bitboard();
         !(touched & self.get_piece_mask(Pawn)).is_empty()
             || !(touched & self.opponent_occupied()).is_empty()
@@ -695,6 +983,7 @@ impl BoardPosition {
             .chain(self._occupied_color.iter_mut())
             .for_each(|bb| *bb = bb.flip_vertical());
         self._occupied = self._occupied.flip_vertical();
+        self._promoted = self._promoted.flip_vertical();
         self._castle_rights = [CastleRights::None; NUM_COLORS];
         self.update_pin_and_checkers_info();
         // self._non_pawn_transposition_hash = self._non_pawn_transposition_hash;
@@ -709,6 +998,7 @@ impl BoardPosition {
             .chain(self._occupied_color.iter_mut())
             .for_each(|bb| *bb = bb.flip_horizontal());
         self._occupied = self._occupied.flip_horizontal();
+        self._promoted = self._promoted.flip_horizontal();
         self._castle_rights = [CastleRights::None; NUM_COLORS];
         self.update_pin_and_checkers_info();
         // self._non_pawn_transposition_hash = self._non_pawn_transposition_hash;
@@ -716,6 +1006,123 @@ impl BoardPosition {
         self._ep_square = self._ep_square.map(|square| square.vertical_mirror());
     }
 
+    /// Whether the symmetry maps the position to an equivalent one. Only flipping the files keeps the pawns and the
+    /// rules of some variants in place, and the castling allows no symmetry at all.
+    pub fn is_symmetry_valid(&self, symmetry: Symmetry) -> bool {
+        if symmetry == Symmetry::Identity {
+            return true;
+        }
+        if ALL_COLORS
+            .into_iter()
+            .any(|color| self.castle_rights(color) != CastleRights::None)
+        {
+            return false;
+        }
+        symmetry.preserves_ranks()
+            || self.get_variant().allows_all_symmetries()
+                && self.get_piece_mask(Pawn).is_empty()
+                && ALL_COLORS
+                    .into_iter()
+                    .all(|color| self.get_pocket_count(color, Pawn) == 0)
+    }
+
+    pub fn get_valid_symmetries(&self) -> impl Iterator<Item = Symmetry> + '_ {
+        ALL_SYMMETRIES
+            .into_iter()
+            .filter(|&symmetry| self.is_symmetry_valid(symmetry))
+    }
+
+    /// The position with the symmetry applied to the board, or [`None`] if the symmetry is not valid for the position.
+    pub fn transform(&self, symmetry: Symmetry) -> Option<Self> {
+        if !self.is_symmetry_valid(symmetry) {
+            return None;
+        }
+        let mut position_builder = BoardPositionBuilder::from(self);
+        for square in ALL_SQUARES {
+            position_builder[symmetry.transform_square(square)] = self.get_piece_at(square);
+        }
+        position_builder
+            .ep_file(
+                self.ep_square()
+                    .map(|square| symmetry.transform_square(square).get_file()),
+            )
+            .promoted_mask(symmetry.transform_bitboard(self.get_promoted_mask()));
+        position_builder.try_into().ok()
+    }
+
+    /// The same position seen from the side of the other color, with the board flipped vertically and the colors of
+    /// the pieces, the turn, the castle rights and the state of the variant swapped. Returns [`None`] in the variants
+    /// where the colors play by different rules.
+    pub fn mirror(&self) -> Option<Self> {
+        if !self.get_variant().is_color_symmetric() {
+            return None;
+        }
+        let mut position_builder = BoardPositionBuilder::from(self);
+        for square in ALL_SQUARES {
+            position_builder[square.horizontal_mirror()] = self
+                .get_piece_at(square)
+                .map(|piece| Piece::new(piece.get_piece_type(), !piece.get_color()));
+        }
+        position_builder
+            .turn(!self.turn())
+            .promoted_mask(self.get_promoted_mask().flip_vertical());
+        for color in ALL_COLORS {
+            position_builder
+                .castle_rights(color, self.castle_rights(!color))
+                .remaining_checks(color, self.get_remaining_checks(!color));
+            for piece_type in ALL_PIECE_TYPES {
+                position_builder.pocket_count(
+                    color,
+                    piece_type,
+                    self.get_pocket_count(!color, piece_type),
+                );
+            }
+        }
+        position_builder.try_into().ok()
+    }
+
+    /// The one position all the positions equivalent to this one under the valid symmetries map to, which also covers
+    /// the colors swapped by [`Self::mirror`] if asked for. Deduplicating the canonical forms deduplicates the
+    /// positions up to symmetry.
+    pub fn get_canonical_form(&self, include_mirror: bool) -> Self {
+        let optional_mirror = if include_mirror { self.mirror() } else { None };
+        std::iter::once(self)
+            .chain(&optional_mirror)
+            .flat_map(|position| {
+                position
+                    .get_valid_symmetries()
+                    .filter_map(|symmetry| position.transform(symmetry))
+            })
+            .min_by_key(|position| position.get_canonical_key())
+            .unwrap_or_else(|| self.to_owned())
+    }
+
+    /// The key ordering the positions to pick the canonical form from, which covers everything but the move counters.
+    #[expect(clippy::type_complexity)]
+    fn get_canonical_key(
+        &self,
+    ) -> (
+        [BitBoard; NUM_COLORS],
+        [BitBoard; NUM_PIECE_TYPES],
+        Color,
+        [usize; NUM_COLORS],
+        Option<usize>,
+        BitBoard,
+        [[u8; NUM_PIECE_TYPES]; NUM_COLORS],
+        [u8; NUM_COLORS],
+    ) {
+        (
+            self._occupied_color,
+            self._piece_masks,
+            self.turn(),
+            self._castle_rights.map(CastleRights::to_index),
+            self.ep_square().map(Square::to_index),
+            self._promoted,
+            self._pockets,
+            self._remaining_checks,
+        )
+    }
+
     #[inline]
     pub fn set_turn_unchecked(&mut self, turn: Color) {
         self._turn = turn;
@@ -735,7 +1142,27 @@ impl BoardPosition {
         self._pinned = BitBoard::EMPTY;
         self._checkers = BitBoard::EMPTY;
 
-        let ksq = self.get_king_square(self.turn());
+        // There is no check without a king, nor in Atomic with the kings side by side, as capturing one of them would
+        // explode the other.
+        match self.get_variant() {
+            Variant::Antichess => return,
+            Variant::Atomic
+                if !self.has_king(White)
+                    || !self.has_king(Black)
+                    || self
+                        .get_king_square_unchecked(White)
+                        .get_king_moves()
+                        .contains(self.get_king_square_unchecked(Black)) =>
+            {
+                return
+            }
+            _ => (),
+        }
+
+        // A side without a king, like the horde or a position still to be checked for sanity, is never in check.
+        let Some(ksq) = self.get_king_square(self.turn()) else {
+            return;
+        };
         let pinners = self.opponent_occupied()
             & ((ksq.get_bishop_rays_bb()
                 & (self.get_piece_mask(Bishop) | self.get_piece_mask(Queen)))
@@ -871,6 +1298,27 @@ impl BoardPosition {
             .next()
     }
 
+    /// The tactical motifs of the color, or of both sides if no color is given, as found by [`TacticalMotif::find`].
+    pub fn get_tactical_motifs(&self, color: impl Into<Option<Color>>) -> Vec<TacticalMotif> {
+        match color.into() {
+            Some(color) => TacticalMotif::find(self, color),
+            None => ALL_COLORS
+                .into_iter()
+                .flat_map(|color| TacticalMotif::find(self, color))
+                .collect(),
+        }
+    }
+
+    /// The tactical motifs the move creates for the side making it, which explain what the move threatens.
+    pub fn get_tactical_motifs_created_by(&self, move_: Move) -> Vec<TacticalMotif> {
+        let motifs = self.get_tactical_motifs(self.turn());
+        self.make_move_new(move_)
+            .get_tactical_motifs(self.turn())
+            .into_iter()
+            .filter(|motif| !motifs.contains(motif))
+            .collect()
+    }
+
     #[inline]
     pub fn is_check(&self) -> bool {
         !self._checkers.is_empty()
@@ -906,7 +1354,7 @@ impl BoardPosition {
     pub fn to_board_string(&self, last_move: ValidOrNullMove, use_unicode: bool) -> String {
         let mut skeleton = get_board_skeleton();
         let checkers = self.get_checkers();
-        let king_square = self.get_king_square(self.turn());
+        let kings = self.get_colored_piece_mask(King, self.turn());
         for square in SQUARES_HORIZONTAL_MIRROR {
             let symbol = if use_unicode {
                 self.piece_unicode_symbol_at(square, false)
@@ -919,7 +1367,7 @@ impl BoardPosition {
                     White => WHITE_PIECES_STYLE,
                     Black => BLACK_PIECES_STYLE,
                 });
-                if square == king_square && !checkers.is_empty() {
+                if kings.contains(square) && !checkers.is_empty() {
                     styles.extend_from_slice(CHECK_STYLE);
                 }
             }
@@ -1260,6 +1708,15 @@ impl BoardPositionMethodOverload<Move> for BoardPosition {
 
         let source_bb = source.to_bitboard();
         let dest_bb = dest.to_bitboard();
+
+        if let Some(dropped) = move_.get_drop() {
+            result.remove_from_pocket(self.turn(), dropped);
+            result.xor(dropped, dest_bb, self.turn());
+            result.flip_turn_unchecked();
+            result.update_pin_and_checkers_info();
+            return result;
+        }
+
         let move_bb = source_bb ^ dest_bb;
         let moved = self.get_piece_type_at(source).unwrap();
 
@@ -1269,6 +1726,23 @@ impl BoardPositionMethodOverload<Move> for BoardPosition {
             result.xor(captured, dest_bb, !self.turn());
         }
 
+        if self.get_variant().has_pockets() {
+            if let Some(captured) = self.get_piece_type_at(dest) {
+                let captured = if self._promoted.contains(dest) {
+                    Pawn
+                } else {
+                    captured
+                };
+                result.add_to_pocket(self.turn(), captured);
+            } else if moved == Pawn && Some(dest) == self.ep_square() {
+                result.add_to_pocket(self.turn(), Pawn);
+            }
+            result._promoted &= !move_bb;
+            if self._promoted.contains(source) || move_.get_promotion().is_some() {
+                result._promoted |= dest_bb;
+            }
+        }
+
         result
             .remove_their_castle_rights(CastleRights::square_to_castle_rights(!self.turn(), dest));
 
@@ -1278,7 +1752,8 @@ impl BoardPositionMethodOverload<Move> for BoardPosition {
 
         let castles = moved == King && (move_bb & get_castle_moves()) == move_bb;
 
-        let ksq = opp_king.to_square_unchecked();
+        // The other king may be missing in the variants without standard kings, whose checks are found below.
+        let ksq = opp_king.to_square().unwrap_or(dest);
 
         if moved == Knight {
             result._checkers ^= ksq.get_knight_moves() & dest_bb;
@@ -1345,6 +1820,14 @@ impl BoardPositionMethodOverload<Move> for BoardPosition {
             result.xor(Rook, start, self.turn());
             result.xor(Rook, end, self.turn());
         }
+        if !self.get_variant().has_standard_kings() {
+            if self.get_variant() == Variant::Atomic && self.is_capture(move_) {
+                result.explode(dest);
+            }
+            result.flip_turn_unchecked();
+            result.update_pin_and_checkers_info();
+            return result;
+        }
         // now, lets see if we're in check or pinned
         let attackers = result.occupied_color(result.turn())
             & ((ksq.get_bishop_rays_bb()
@@ -1363,6 +1846,12 @@ impl BoardPositionMethodOverload<Move> for BoardPosition {
 
         result.flip_turn_unchecked();
 
+        if result.get_variant() == Variant::ThreeCheck && result.is_check() {
+            let remaining_checks =
+                get_item_unchecked_mut!(result._remaining_checks, self.turn().to_index());
+            *remaining_checks = remaining_checks.saturating_sub(1);
+        }
+
         result
     }
 }
@@ -1416,18 +1905,48 @@ impl TryFrom<&BoardPositionBuilder> for BoardPosition {
             position._turn = !position.turn();
         }
 
-        position.add_castle_rights(White, position_builder.get_castle_rights(White));
-        position.add_castle_rights(Black, position_builder.get_castle_rights(Black));
+        // The castle rights of a variant without castling are ignored, so that its positions can be set up from the
+        // FENs of standard chess.
+        if position_builder.get_variant().allows_castling() {
+            position.add_castle_rights(White, position_builder.get_castle_rights(White));
+            position.add_castle_rights(Black, position_builder.get_castle_rights(Black));
+        }
 
         position._halfmove_clock = position_builder.get_halfmove_clock();
         position._fullmove_number = position_builder.get_fullmove_number();
 
+        position._variant = position_builder.get_variant();
+        position._remaining_checks = [
+            position_builder.get_remaining_checks(White),
+            position_builder.get_remaining_checks(Black),
+        ];
+        for color in ALL_COLORS {
+            for piece_type in ALL_PIECE_TYPES {
+                *get_item_unchecked_mut!(
+                    position._pockets,
+                    color.to_index(),
+                    piece_type.to_index()
+                ) = position_builder.get_pocket_count(color, piece_type);
+            }
+        }
+        position._promoted = position_builder.get_promoted_mask();
+
         position.update_pin_and_checkers_info();
 
-        if position.is_sane() {
+        let mut issues = position.validate();
+        // An en passant square no pawn can capture on is not kept by the position, so it is checked on the builder.
+        if !position_builder.has_valid_en_passant()
+            && !issues.contains(&PositionIssue::InvalidEnPassant)
+        {
+            issues.push(PositionIssue::InvalidEnPassant);
+        }
+        if issues.is_empty() {
             Ok(position)
         } else {
-            Err(TimecatError::InvalidBoardPosition { position })
+            Err(TimecatError::InvalidPosition {
+                fen: position_builder.to_string(),
+                issues,
+            })
         }
     }
 }
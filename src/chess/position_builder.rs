@@ -10,6 +10,10 @@ pub struct BoardPositionBuilder {
     ep_file: Option<File>,
     halfmove_clock: u8,
     fullmove_number: NumMoves,
+    variant: Variant,
+    remaining_checks: [u8; 2],
+    pockets: [[u8; NUM_PIECE_TYPES]; 2],
+    promoted: BitBoard,
 }
 
 impl BoardPositionBuilder {
@@ -22,6 +26,10 @@ impl BoardPositionBuilder {
             ep_file: None,
             halfmove_clock: 0,
             fullmove_number: 1,
+            variant: Variant::default(),
+            remaining_checks: [THREE_CHECK_NUM_CHECKS; 2],
+            pockets: [[0; NUM_PIECE_TYPES]; 2],
+            promoted: BitBoard::EMPTY,
         }
     }
 
@@ -41,6 +49,10 @@ impl BoardPositionBuilder {
             ep_file,
             halfmove_clock,
             fullmove_number,
+            variant: Variant::default(),
+            remaining_checks: [THREE_CHECK_NUM_CHECKS; 2],
+            pockets: [[0; NUM_PIECE_TYPES]; 2],
+            promoted: BitBoard::EMPTY,
         };
 
         for (square, piece) in pieces {
@@ -73,6 +85,29 @@ impl BoardPositionBuilder {
         self.fullmove_number
     }
 
+    #[inline]
+    pub fn get_variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// The number of checks the color still has to give to win a game of Three-check.
+    #[inline]
+    pub fn get_remaining_checks(&self, color: Color) -> u8 {
+        self.remaining_checks[color.to_index()]
+    }
+
+    /// The number of pieces of the kind the color has in its pocket in Crazyhouse.
+    #[inline]
+    pub fn get_pocket_count(&self, color: Color, piece_type: PieceType) -> u8 {
+        self.pockets[color.to_index()][piece_type.to_index()]
+    }
+
+    /// The pieces promoted from pawns in Crazyhouse.
+    #[inline]
+    pub fn get_promoted_mask(&self) -> BitBoard {
+        self.promoted
+    }
+
     pub fn turn(&mut self, color: Color) -> &mut Self {
         self.turn = color;
         self
@@ -107,6 +142,106 @@ impl BoardPositionBuilder {
         self.fullmove_number = fullmove_number;
         self
     }
+
+    pub fn variant(&mut self, variant: Variant) -> &mut Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn remaining_checks(&mut self, color: Color, remaining_checks: u8) -> &mut Self {
+        self.remaining_checks[color.to_index()] = remaining_checks;
+        self
+    }
+
+    pub fn pocket_count(&mut self, color: Color, piece_type: PieceType, count: u8) -> &mut Self {
+        self.pockets[color.to_index()][piece_type.to_index()] = count;
+        self
+    }
+
+    pub fn promoted_mask(&mut self, promoted: BitBoard) -> &mut Self {
+        self.promoted = promoted;
+        self
+    }
+
+    /// Whether the pawn of the side which just moved can have skipped the en passant square, if any, with a double
+    /// move.
+    pub fn has_valid_en_passant(&self) -> bool {
+        let Some(ep_square) = self.get_en_passant() else {
+            return true;
+        };
+        let color = !self.get_turn();
+        self[ep_square].is_none()
+            && ep_square
+                .backward(color)
+                .is_some_and(|source| self[source].is_none())
+            && ep_square
+                .forward(color)
+                .is_some_and(|square| self[square] == Some(Piece::new(Pawn, color)))
+    }
+
+    /// Drops the castle rights of the kings and rooks which have left their starting squares and the en passant
+    /// square no pawn can have skipped, so that the FENs with such fields, which many tools write, can still be
+    /// loaded.
+    pub fn repair(&mut self) -> &mut Self {
+        for color in ALL_COLORS {
+            let backrank = color.to_my_backrank();
+            let is_on = |file, piece_type| {
+                self[Square::from_rank_and_file(backrank, file)]
+                    == Some(Piece::new(piece_type, color))
+            };
+            let mut castle_rights = self.get_castle_rights(color);
+            if !is_on(File::E, King) {
+                castle_rights = CastleRights::None;
+            }
+            if !is_on(File::H, Rook) {
+                castle_rights = castle_rights.remove(CastleRights::KingSide);
+            }
+            if !is_on(File::A, Rook) {
+                castle_rights = castle_rights.remove(CastleRights::QueenSide);
+            }
+            self.castle_rights(color, castle_rights);
+        }
+        if !self.has_valid_en_passant() {
+            self.ep_file(None);
+        }
+        self
+    }
+
+    /// Parses the pockets of a Crazyhouse FEN, like `QNpp` for a white queen and knight and two black pawns.
+    fn parse_pockets(pockets: &str) -> Option<[[u8; NUM_PIECE_TYPES]; 2]> {
+        let mut counts = [[0; NUM_PIECE_TYPES]; 2];
+        for piece in pockets.chars() {
+            let piece = Piece::from_str(&piece.to_string()).ok()?;
+            let count =
+                &mut counts[piece.get_color().to_index()][piece.get_piece_type().to_index()];
+            if *count == MAX_POCKET_COUNT {
+                return None;
+            }
+            *count += 1;
+        }
+        Some(counts)
+    }
+
+    /// Parses the checks field of a Three-check FEN, either the remaining checks like `3+2` or the checks given like
+    /// `+0+1`, returning them in the former form.
+    fn parse_remaining_checks(token: &str) -> Option<[u8; 2]> {
+        let (checks_given, token) = match token.strip_prefix('+') {
+            Some(token) => (true, token),
+            None => (false, token),
+        };
+        let (white_checks, black_checks) = token.split_once('+')?;
+        let mut checks = [white_checks.parse().ok()?, black_checks.parse().ok()?];
+        if checks
+            .iter()
+            .any(|&num_checks| num_checks > THREE_CHECK_NUM_CHECKS)
+        {
+            return None;
+        }
+        if checks_given {
+            checks = checks.map(|num_checks| THREE_CHECK_NUM_CHECKS - num_checks);
+        }
+        Some(checks)
+    }
 }
 
 impl Index<Square> for BoardPositionBuilder {
@@ -137,6 +272,9 @@ impl fmt::Display for BoardPositionBuilder {
 
                 if let Some(piece) = self.pieces[square] {
                     write!(f, "{piece}")?;
+                    if self.promoted.contains(Square::from_index(square)) {
+                        write!(f, "~")?;
+                    }
                 } else {
                     count += 1;
                 }
@@ -152,6 +290,21 @@ impl fmt::Display for BoardPositionBuilder {
             count = 0;
         }
 
+        if self.variant.has_pockets() {
+            write!(f, "[")?;
+            for color in ALL_COLORS {
+                for piece_type in ALL_PIECE_TYPES.into_iter().rev() {
+                    let piece = piece_type.to_colored_piece_string(color);
+                    write!(
+                        f,
+                        "{}",
+                        piece.repeat(self.get_pocket_count(color, piece_type) as usize)
+                    )?;
+                }
+            }
+            write!(f, "]")?;
+        }
+
         write!(f, " ")?;
 
         if self.turn == White {
@@ -183,6 +336,15 @@ impl fmt::Display for BoardPositionBuilder {
             write!(f, "-")?;
         }
 
+        if self.variant == Variant::ThreeCheck {
+            write!(
+                f,
+                " {}+{}",
+                self.get_remaining_checks(White),
+                self.get_remaining_checks(Black)
+            )?;
+        }
+
         write!(f, " {} {}", self.halfmove_clock, self.fullmove_number)
     }
 }
@@ -201,14 +363,72 @@ impl FromStr for BoardPositionBuilder {
         let mut cur_file = File::A;
         let mut position_builder = BoardPositionBuilder::new();
 
-        let tokens: Vec<&str> = value.split(' ').collect();
+        let mut tokens: Vec<&str> = value.split(' ').collect();
         if tokens.len() < 4 {
             return Err(TimecatError::BadFen {
                 fen: value.to_string(),
             });
         }
 
-        let pieces = tokens[0];
+        // The checks field of Three-check may come after the en passant square or at the end of the FEN.
+        if let Some(index) = (4..tokens.len()).find(|&index| tokens[index].contains('+')) {
+            let remaining_checks =
+                Self::parse_remaining_checks(tokens.remove(index)).ok_or_else(|| {
+                    TimecatError::BadFen {
+                        fen: value.to_string(),
+                    }
+                })?;
+            position_builder.variant = Variant::ThreeCheck;
+            position_builder.remaining_checks = remaining_checks;
+        }
+
+        // The pockets of Crazyhouse come in brackets or as a ninth rank after the board.
+        let mut pieces = tokens[0];
+        let optional_pockets = if let Some((board, pockets)) = pieces
+            .strip_suffix(']')
+            .and_then(|pieces| pieces.split_once('['))
+        {
+            pieces = board;
+            Some(pockets)
+        } else if pieces.matches('/').count() == 8 {
+            let (board, pockets) = pieces.rsplit_once('/').unwrap();
+            pieces = board;
+            Some(pockets)
+        } else {
+            None
+        };
+        if let Some(pockets) = optional_pockets {
+            position_builder.pockets =
+                Self::parse_pockets(pockets).ok_or_else(|| TimecatError::BadFen {
+                    fen: value.to_string(),
+                })?;
+            position_builder.variant = Variant::Crazyhouse;
+        }
+        let ranks = pieces.split('/').collect_vec();
+        let mut issues = Vec::new();
+        if ranks.len() != 8 {
+            issues.push(PositionIssue::WrongRankCount {
+                num_ranks: ranks.len(),
+            });
+        }
+        for (&rank, rank_string) in ALL_RANKS.iter().rev().zip(&ranks) {
+            let num_squares: u32 = rank_string
+                .chars()
+                .map(|c| match c {
+                    '~' => 0,
+                    _ => c.to_digit(10).unwrap_or(1),
+                })
+                .sum();
+            if num_squares != 8 {
+                issues.push(PositionIssue::WrongRankLength { rank });
+            }
+        }
+        if !issues.is_empty() {
+            return Err(TimecatError::InvalidPosition {
+                fen: value.to_string(),
+                issues,
+            });
+        }
         let side = tokens[1];
         let castles = tokens[2];
         let ep = tokens[3];
@@ -217,6 +437,17 @@ impl FromStr for BoardPositionBuilder {
 
         for x in pieces.chars() {
             match x {
+                // A promoted piece in Crazyhouse, which is the one before the marker.
+                '~' => {
+                    let square = Square::from_rank_and_file(cur_rank, cur_file.wrapping_left());
+                    if position_builder[square].is_none() {
+                        return Err(TimecatError::BadFen {
+                            fen: value.to_string(),
+                        });
+                    }
+                    position_builder.promoted ^= square.to_bitboard();
+                    position_builder.variant = Variant::Crazyhouse;
+                }
                 '/' => {
                     cur_rank = cur_rank.wrapping_down();
                     cur_file = File::A;
@@ -343,7 +574,7 @@ impl From<&BoardPosition> for BoardPositionBuilder {
             }
         }
 
-        BoardPositionBuilder::setup(
+        let mut position_builder = BoardPositionBuilder::setup(
             pieces,
             board.turn(),
             board.castle_rights(White),
@@ -351,7 +582,22 @@ impl From<&BoardPosition> for BoardPositionBuilder {
             board.ep_square().map(|square| square.get_file()),
             board.get_halfmove_clock(),
             board.get_fullmove_number(),
-        )
+        );
+        position_builder
+            .variant(board.get_variant())
+            .remaining_checks(White, board.get_remaining_checks(White))
+            .remaining_checks(Black, board.get_remaining_checks(Black))
+            .promoted_mask(board.get_promoted_mask());
+        for color in ALL_COLORS {
+            for piece_type in ALL_PIECE_TYPES {
+                position_builder.pocket_count(
+                    color,
+                    piece_type,
+                    board.get_pocket_count(color, piece_type),
+                );
+            }
+        }
+        position_builder
     }
 }
 
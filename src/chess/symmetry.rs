@@ -0,0 +1,75 @@
+use super::*;
+
+/// One of the eight symmetries of the board, named after how it moves the squares as seen from the side of white.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub enum Symmetry {
+    #[default]
+    Identity,
+    /// Swaps the first and the eighth rank, like [`BoardPosition::flip_vertical`].
+    FlipVertical,
+    /// Swaps the a-file and the h-file, like [`BoardPosition::flip_horizontal`].
+    FlipHorizontal,
+    /// Mirrors the board along the a1-h8 diagonal.
+    FlipDiagonal,
+    /// Mirrors the board along the h1-a8 diagonal.
+    FlipAntiDiagonal,
+    /// Turns the board a quarter clockwise, taking a1 to a8.
+    Rotate90,
+    Rotate180,
+    /// Turns the board a quarter anticlockwise, taking a1 to h1.
+    Rotate270,
+}
+
+impl Symmetry {
+    #[inline]
+    pub const fn to_index(self) -> usize {
+        self as usize
+    }
+
+    /// Whether the symmetry keeps every rank in place, which the positions with pawns or castle rights need.
+    #[inline]
+    pub const fn preserves_ranks(self) -> bool {
+        matches!(self, Self::Identity | Self::FlipHorizontal)
+    }
+
+    /// The symmetry undoing this one.
+    #[inline]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Rotate90 => Self::Rotate270,
+            Self::Rotate270 => Self::Rotate90,
+            _ => self,
+        }
+    }
+
+    pub fn transform_square(self, square: Square) -> Square {
+        let rank = square.get_rank().to_index();
+        let file = square.get_file().to_index();
+        let (rank, file) = match self {
+            Self::Identity => (rank, file),
+            Self::FlipVertical => (7 - rank, file),
+            Self::FlipHorizontal => (rank, 7 - file),
+            Self::FlipDiagonal => (file, rank),
+            Self::FlipAntiDiagonal => (7 - file, 7 - rank),
+            Self::Rotate90 => (7 - file, rank),
+            Self::Rotate180 => (7 - rank, 7 - file),
+            Self::Rotate270 => (file, 7 - rank),
+        };
+        Square::from_rank_and_file(Rank::from_index(rank), File::from_index(file))
+    }
+
+    /// <https://www.chessprogramming.org/Flipping_Mirroring_and_Rotating#Rotation>
+    pub const fn transform_bitboard(self, bb: BitBoard) -> BitBoard {
+        match self {
+            Self::Identity => bb,
+            Self::FlipVertical => bb.flip_vertical(),
+            Self::FlipHorizontal => bb.flip_horizontal(),
+            Self::FlipDiagonal => bb.flip_diagonal(),
+            Self::FlipAntiDiagonal => bb.flip_anti_diagonal(),
+            Self::Rotate90 => bb.flip_diagonal().flip_vertical(),
+            Self::Rotate180 => bb.flip_vertical().flip_horizontal(),
+            Self::Rotate270 => bb.flip_vertical().flip_diagonal(),
+        }
+    }
+}
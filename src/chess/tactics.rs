@@ -0,0 +1,284 @@
+use super::*;
+
+/// A tactical pattern on the board, found from the attacks of the pieces alone without any search. The color of a
+/// motif is the side which can make use of it.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum TacticalMotif {
+    /// The piece of the other side on the square is attacked and either undefended or attacked by a cheaper piece.
+    HangingPiece { color: Color, square: Square },
+    /// The piece attacks more than one piece worth attacking, which are the king, the pieces worth more than the
+    /// attacker and the undefended ones.
+    Fork {
+        color: Color,
+        attacker: Square,
+        targets: BitBoard,
+    },
+    /// The piece on `pinned` cannot leave the line of the slider without exposing the more valuable piece on `target`,
+    /// which is an absolute pin if the target is the king.
+    Pin {
+        color: Color,
+        pinner: Square,
+        pinned: Square,
+        target: Square,
+    },
+    /// The slider attacks the piece on `front`, which exposes the less valuable piece on `behind` when it moves away.
+    Skewer {
+        color: Color,
+        attacker: Square,
+        front: Square,
+        behind: Square,
+    },
+    /// Moving the own piece on `blocker` off the line of the slider uncovers an attack on the piece on `target`.
+    DiscoveredAttack {
+        color: Color,
+        attacker: Square,
+        blocker: Square,
+        target: Square,
+    },
+    /// The piece on `defender` is the only defender of every attacked piece in `defended`, so it cannot keep all of
+    /// them.
+    OverloadedDefender {
+        color: Color,
+        defender: Square,
+        defended: BitBoard,
+    },
+    /// The slider attacks the piece on `target` through the own slider on `through`, which moves along the same line
+    /// and leaves the attack behind when it captures.
+    XRayAttack {
+        color: Color,
+        attacker: Square,
+        through: Square,
+        target: Square,
+    },
+}
+
+impl TacticalMotif {
+    pub fn get_color(self) -> Color {
+        match self {
+            Self::HangingPiece { color, .. }
+            | Self::Fork { color, .. }
+            | Self::Pin { color, .. }
+            | Self::Skewer { color, .. }
+            | Self::DiscoveredAttack { color, .. }
+            | Self::OverloadedDefender { color, .. }
+            | Self::XRayAttack { color, .. } => color,
+        }
+    }
+
+    /// Finds every motif the color can make use of in the position.
+    pub fn find(position: &BoardPosition, color: Color) -> Vec<Self> {
+        let mut motifs = Vec::new();
+        Self::find_hanging_pieces(position, color, &mut motifs);
+        Self::find_forks(position, color, &mut motifs);
+        Self::find_line_motifs(position, color, &mut motifs);
+        Self::find_overloaded_defenders(position, color, &mut motifs);
+        motifs
+    }
+
+    #[inline]
+    fn get_value(position: &BoardPosition, square: Square) -> Score {
+        position
+            .get_piece_type_at(square)
+            .map_or(0, PieceType::evaluate)
+    }
+
+    /// The squares attacked by the piece on the square.
+    fn get_attacks(position: &BoardPosition, square: Square) -> BitBoard {
+        let Some(piece) = position.get_piece_at(square) else {
+            return BitBoard::EMPTY;
+        };
+        match piece.get_piece_type() {
+            Pawn => square.get_pawn_attacks(piece.get_color(), BB_ALL),
+            Knight => square.get_knight_moves(),
+            Bishop => get_bishop_moves(square, position.occupied()),
+            Rook => get_rook_moves(square, position.occupied()),
+            Queen => get_queen_moves(square, position.occupied()),
+            King => square.get_king_moves(),
+        }
+    }
+
+    fn is_defended(position: &BoardPosition, square: Square) -> bool {
+        position
+            .color_at(square)
+            .is_some_and(|color| !position.get_attackers_mask(square, color).is_empty())
+    }
+
+    fn is_worth_attacking(position: &BoardPosition, attacker: Square, target: Square) -> bool {
+        position.get_piece_mask(King).contains(target)
+            || Self::get_value(position, target) > Self::get_value(position, attacker)
+            || !Self::is_defended(position, target)
+    }
+
+    fn find_hanging_pieces(position: &BoardPosition, color: Color, motifs: &mut Vec<Self>) {
+        for square in position.occupied_color(!color) & !position.get_piece_mask(King) {
+            let Some(least_attacker_value) = position
+                .get_attackers_mask(square, color)
+                .map(|attacker| Self::get_value(position, attacker))
+                .min()
+            else {
+                continue;
+            };
+            if !Self::is_defended(position, square)
+                || least_attacker_value < Self::get_value(position, square)
+            {
+                motifs.push(Self::HangingPiece { color, square });
+            }
+        }
+    }
+
+    fn find_forks(position: &BoardPosition, color: Color, motifs: &mut Vec<Self>) {
+        for attacker in position.occupied_color(color) {
+            let targets: BitBoard = (Self::get_attacks(position, attacker)
+                & position.occupied_color(!color))
+            .filter(|&target| Self::is_worth_attacking(position, attacker, target))
+            .collect();
+            if targets.popcnt() > 1 {
+                motifs.push(Self::Fork {
+                    color,
+                    attacker,
+                    targets,
+                });
+            }
+        }
+    }
+
+    /// Finds the pins, the skewers, the discovered attacks and the x-ray attacks, which all look at the first two
+    /// pieces on a line of a slider.
+    fn find_line_motifs(position: &BoardPosition, color: Color, motifs: &mut Vec<Self>) {
+        let occupied = position.occupied();
+        for (piece, attacker) in position.custom_iter(&[Bishop, Rook, Queen], &[color], BB_ALL) {
+            let get_moves = |blockers| match piece.get_piece_type() {
+                Bishop => get_bishop_moves(attacker, blockers),
+                Rook => get_rook_moves(attacker, blockers),
+                _ => get_queen_moves(attacker, blockers),
+            };
+            let attacks = get_moves(occupied);
+            for blocker in attacks & occupied {
+                // Removing the blocker only opens up the line through it.
+                let Some(target) =
+                    (get_moves(occupied ^ blocker.to_bitboard()) & !attacks & occupied).to_square()
+                else {
+                    continue;
+                };
+                if position.occupied_color(color).contains(target) {
+                    continue;
+                }
+                if position.occupied_color(!color).contains(blocker) {
+                    let blocker_value = Self::get_value(position, blocker);
+                    let target_value = Self::get_value(position, target);
+                    if target_value > blocker_value {
+                        motifs.push(Self::Pin {
+                            color,
+                            pinner: attacker,
+                            pinned: blocker,
+                            target,
+                        });
+                    } else if blocker_value > target_value {
+                        motifs.push(Self::Skewer {
+                            color,
+                            attacker,
+                            front: blocker,
+                            behind: target,
+                        });
+                    }
+                } else {
+                    let moves_along_line = match position.get_piece_type_at(blocker) {
+                        Some(Queen) => true,
+                        Some(Rook) => attacker.get_rook_rays_bb().contains(blocker),
+                        Some(Bishop) => attacker.get_bishop_rays_bb().contains(blocker),
+                        _ => false,
+                    };
+                    if moves_along_line {
+                        motifs.push(Self::XRayAttack {
+                            color,
+                            attacker,
+                            through: blocker,
+                            target,
+                        });
+                    } else if Self::is_worth_attacking(position, attacker, target) {
+                        motifs.push(Self::DiscoveredAttack {
+                            color,
+                            attacker,
+                            blocker,
+                            target,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn find_overloaded_defenders(position: &BoardPosition, color: Color, motifs: &mut Vec<Self>) {
+        let their_pieces = position.occupied_color(!color);
+        for defender in their_pieces {
+            let defended: BitBoard = (Self::get_attacks(position, defender)
+                & their_pieces
+                & !position.get_piece_mask(King))
+            .filter(|&square| {
+                position.is_attacked_by(square, color)
+                    && position.get_attackers_mask(square, !color) == defender.to_bitboard()
+            })
+            .collect();
+            if defended.popcnt() > 1 {
+                motifs.push(Self::OverloadedDefender {
+                    color,
+                    defender,
+                    defended,
+                });
+            }
+        }
+    }
+}
+
+impl fmt::Display for TacticalMotif {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HangingPiece { square, .. } => write!(f, "hanging piece on {square}"),
+            Self::Fork {
+                attacker, targets, ..
+            } => write!(
+                f,
+                "fork by {attacker} of {}",
+                targets.map(|square| square.to_string()).join(", ")
+            ),
+            Self::Pin {
+                pinner,
+                pinned,
+                target,
+                ..
+            } => write!(f, "pin by {pinner} of {pinned} to {target}"),
+            Self::Skewer {
+                attacker,
+                front,
+                behind,
+                ..
+            } => write!(f, "skewer by {attacker} of {front} to {behind}"),
+            Self::DiscoveredAttack {
+                attacker,
+                blocker,
+                target,
+                ..
+            } => write!(
+                f,
+                "discovered attack by {attacker} on {target} moving {blocker}"
+            ),
+            Self::OverloadedDefender {
+                defender, defended, ..
+            } => write!(
+                f,
+                "overloaded defender on {defender} of {}",
+                defended.map(|square| square.to_string()).join(", ")
+            ),
+            Self::XRayAttack {
+                attacker,
+                through,
+                target,
+                ..
+            } => write!(
+                f,
+                "x-ray attack by {attacker} on {target} through {through}"
+            ),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::*;
+
+/// A reason a FEN or a [`BoardPosition`] cannot describe a legal position, as reported by
+/// [`BoardPosition::validate`] and carried by [`TimecatError::InvalidPosition`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum PositionIssue {
+    /// The board of the FEN does not have 8 ranks.
+    WrongRankCount {
+        num_ranks: usize,
+    },
+    /// The rank of the FEN does not have 8 squares.
+    WrongRankLength {
+        rank: Rank,
+    },
+    MissingKing {
+        color: Color,
+    },
+    TooManyKings {
+        color: Color,
+    },
+    TooManyPawns {
+        color: Color,
+    },
+    TooManyPieces {
+        color: Color,
+    },
+    /// A pawn is on the first or the eighth rank, where it would have promoted.
+    PawnsOnBackRank,
+    /// The side which just moved left its king in check.
+    OpponentInCheck,
+    /// The kings stand next to each other.
+    KingsAdjacent,
+    /// The king is attacked by more than two pieces, which no move can do.
+    TooManyCheckers,
+    /// The king is attacked by two pieces which no move can have given check with together, like two pieces which
+    /// are not sliders, two bishops, two rooks not coming from a promotion or two pieces on one line with the king.
+    ImpossibleCheck,
+    /// The color can castle without its king or rooks on their starting squares.
+    InvalidCastlingRights {
+        color: Color,
+    },
+    /// No pawn could have just skipped the en passant square.
+    InvalidEnPassant,
+    /// The position breaks a rule of its variant.
+    VariantRules {
+        variant: Variant,
+    },
+}
+
+impl fmt::Display for PositionIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongRankCount { num_ranks } => {
+                write!(f, "the board has {num_ranks} ranks instead of 8")
+            }
+            Self::WrongRankLength { rank } => {
+                write!(f, "rank {} does not have 8 squares", rank.to_index() + 1)
+            }
+            Self::MissingKing { color } => write!(f, "{color} has no king"),
+            Self::TooManyKings { color } => write!(f, "{color} has more than one king"),
+            Self::TooManyPawns { color } => write!(f, "{color} has too many pawns"),
+            Self::TooManyPieces { color } => write!(f, "{color} has too many pieces"),
+            Self::PawnsOnBackRank => write!(f, "there are pawns on the first or the eighth rank"),
+            Self::OpponentInCheck => write!(f, "the king of the side not to move is in check"),
+            Self::KingsAdjacent => write!(f, "the kings are next to each other"),
+            Self::TooManyCheckers => write!(f, "the king is attacked by more than two pieces"),
+            Self::ImpossibleCheck => write!(
+                f,
+                "the king is attacked by two pieces which cannot check together"
+            ),
+            Self::InvalidCastlingRights { color } => write!(
+                f,
+                "{color} has castling rights without its king and rooks on their squares"
+            ),
+            Self::InvalidEnPassant => write!(f, "the en passant square is invalid"),
+            Self::VariantRules { variant } => write!(
+                f,
+                "the position breaks the rules of {}",
+                variant.get_pgn_name()
+            ),
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use super::*;
+
+/// The rules a [`BoardPosition`] is played under. Every variant keeps the moves and the termination of standard chess
+/// unless it says otherwise.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub enum Variant {
+    #[default]
+    Standard,
+    /// Giving the third check wins.
+    ThreeCheck,
+    /// Bringing the king to one of the four center squares wins.
+    KingOfTheHill,
+    /// Played without pawns. No piece may give check and bringing the king to the eighth rank wins. If white gets there
+    /// first, black has one move to do the same and draw.
+    RacingKings,
+    /// The captured pieces go to the pocket of the capturer, who can drop them on an empty square instead of moving.
+    Crazyhouse,
+    /// A capture explodes the capturing piece and every piece but the pawns next to the captured one. Exploding the
+    /// king of the other side wins, and the kings may touch, since a king cannot capture.
+    Atomic,
+    /// Also known as losing chess or giveaway. Capturing is compulsory, the king is an ordinary piece without check or
+    /// castling and the side left without pieces or moves wins.
+    Antichess,
+    /// White has 36 pawns and no king against the standard army of black. White wins by checkmate and black by
+    /// capturing every white piece. The white pawns on the first rank may move two squares, without en passant.
+    Horde,
+}
+
+impl Variant {
+    /// The name used by the `UCI_Variant` option.
+    #[inline]
+    pub const fn get_uci_name(self) -> &'static str {
+        match self {
+            Self::Standard => "chess",
+            Self::ThreeCheck => "3check",
+            Self::KingOfTheHill => "kingofthehill",
+            Self::RacingKings => "racingkings",
+            Self::Crazyhouse => "crazyhouse",
+            Self::Atomic => "atomic",
+            Self::Antichess => "antichess",
+            Self::Horde => "horde",
+        }
+    }
+
+    /// The name used by the `Variant` tag of PGN.
+    #[inline]
+    pub const fn get_pgn_name(self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::ThreeCheck => "Three-check",
+            Self::KingOfTheHill => "King of the Hill",
+            Self::RacingKings => "Racing Kings",
+            Self::Crazyhouse => "Crazyhouse",
+            Self::Atomic => "Atomic",
+            Self::Antichess => "Antichess",
+            Self::Horde => "Horde",
+        }
+    }
+
+    #[inline]
+    pub const fn get_starting_fen(self) -> &'static str {
+        match self {
+            Self::Standard | Self::ThreeCheck | Self::KingOfTheHill | Self::Atomic => {
+                STARTING_POSITION_FEN
+            }
+            Self::RacingKings => RACING_KINGS_STARTING_FEN,
+            Self::Crazyhouse => CRAZYHOUSE_STARTING_FEN,
+            Self::Antichess => ANTICHESS_STARTING_FEN,
+            Self::Horde => HORDE_STARTING_FEN,
+        }
+    }
+
+    /// Whether some of the moves legal in standard chess are illegal in the variant, so that the fast paths of the
+    /// move generator cannot be used.
+    #[inline]
+    pub const fn restricts_moves(self) -> bool {
+        matches!(
+            self,
+            Self::RacingKings | Self::Atomic | Self::Antichess | Self::Horde
+        )
+    }
+
+    /// Whether each side has exactly one king, which must be kept out of check. Without them, the move generator
+    /// cannot take its paths built around the pins and the checkers of the king.
+    #[inline]
+    pub const fn has_standard_kings(self) -> bool {
+        !matches!(self, Self::Atomic | Self::Antichess | Self::Horde)
+    }
+
+    /// Whether a pawn can promote to a king.
+    #[inline]
+    pub const fn allows_king_promotion(self) -> bool {
+        matches!(self, Self::Antichess)
+    }
+
+    #[inline]
+    pub const fn allows_castling(self) -> bool {
+        !matches!(self, Self::Antichess)
+    }
+
+    /// Whether the evaluation of standard chess makes sense in the variant, which is not the case when the aim is to
+    /// lose the pieces.
+    #[inline]
+    pub const fn uses_standard_evaluation(self) -> bool {
+        !matches!(self, Self::Antichess)
+    }
+
+    /// Whether the captured pieces go to the pockets, from where they can be dropped.
+    #[inline]
+    pub const fn has_pockets(self) -> bool {
+        matches!(self, Self::Crazyhouse)
+    }
+
+    /// The most pawns the color can have on the board, which are more than 8 with the horde or the drops of
+    /// Crazyhouse.
+    #[inline]
+    pub fn get_max_num_pawns(self, color: Color) -> u32 {
+        match self {
+            Self::Horde if color == White => HORDE_NUM_PAWNS,
+            Self::Crazyhouse => 2 * MAX_NUM_PAWNS_PER_COLOR,
+            _ => MAX_NUM_PAWNS_PER_COLOR,
+        }
+    }
+
+    /// The most pieces, pawns and king included, the color can have on the board.
+    #[inline]
+    pub fn get_max_num_pieces(self, color: Color) -> u32 {
+        match self {
+            Self::Horde if color == White => HORDE_NUM_PAWNS,
+            Self::Crazyhouse => 2 * MAX_NUM_PIECES_PER_COLOR,
+            _ => MAX_NUM_PIECES_PER_COLOR,
+        }
+    }
+
+    /// Whether the rules tell no rank apart but through the pawns and the castling, so that the positions without
+    /// them can be turned or flipped along the diagonals.
+    #[inline]
+    pub const fn allows_all_symmetries(self) -> bool {
+        !matches!(self, Self::RacingKings | Self::Horde)
+    }
+
+    /// Whether both sides play by the same rules, so that a position can be seen from the side of the other color.
+    #[inline]
+    pub const fn is_color_symmetric(self) -> bool {
+        !matches!(self, Self::RacingKings | Self::Horde)
+    }
+
+    /// Whether the moves giving check are legal.
+    #[inline]
+    pub const fn allows_checks(self) -> bool {
+        !matches!(self, Self::RacingKings)
+    }
+
+    /// Whether the position is consistent with the rules of the variant, on top of [`BoardPosition::is_sane`].
+    pub fn is_sane(self, position: &BoardPosition) -> bool {
+        if !self.has_pockets()
+            && (!position.get_promoted_mask().is_empty()
+                || ALL_COLORS
+                    .into_iter()
+                    .any(|color| position.has_pocket_pieces(color)))
+        {
+            return false;
+        }
+        match self {
+            Self::Standard | Self::KingOfTheHill | Self::Antichess => true,
+            Self::ThreeCheck => ALL_COLORS
+                .into_iter()
+                .all(|color| position.get_remaining_checks(color) <= THREE_CHECK_NUM_CHECKS),
+            Self::RacingKings => !position.is_check() && position.get_piece_mask(Pawn).is_empty(),
+            Self::Horde => {
+                !position.has_king(White)
+                    && position.get_colored_piece_mask(King, Black).popcnt() == 1
+            }
+            // A king may be missing only once the game is over by its explosion.
+            Self::Atomic => {
+                ALL_COLORS
+                    .into_iter()
+                    .all(|color| position.get_colored_piece_mask(King, color).popcnt() <= 1)
+                    && !position.get_piece_mask(King).is_empty()
+            }
+            Self::Crazyhouse => {
+                let promoted_mask = position.get_promoted_mask();
+                // Every piece of a kind may end up in the same pocket, which must not overflow.
+                let pockets_can_overflow = ALL_PIECE_TYPES.into_iter().any(|piece_type| {
+                    let num_pieces = match piece_type {
+                        Pawn => position.get_piece_mask(Pawn).popcnt() + promoted_mask.popcnt(),
+                        _ => (position.get_piece_mask(piece_type) & !promoted_mask).popcnt(),
+                    } + ALL_COLORS
+                        .into_iter()
+                        .map(|color| position.get_pocket_count(color, piece_type) as u32)
+                        .sum::<u32>();
+                    num_pieces > MAX_POCKET_COUNT as u32
+                });
+                !pockets_can_overflow
+                    && position.get_pocket_count(White, King) == 0
+                    && position.get_pocket_count(Black, King) == 0
+                    && (promoted_mask
+                        & !(position.occupied()
+                            ^ position.get_piece_mask(Pawn)
+                            ^ position.get_piece_mask(King)))
+                    .is_empty()
+            }
+        }
+    }
+
+    /// The end of the game by a rule of the variant, which takes precedence over checkmate and stalemate. Returns
+    /// [`None`] if the rules of standard chess decide.
+    pub fn get_status(self, position: &BoardPosition) -> Option<BoardStatus> {
+        match self {
+            Self::Standard | Self::Crazyhouse => None,
+            Self::Atomic => ALL_COLORS
+                .into_iter()
+                .find(|&color| !position.has_king(color))
+                .map(|color| BoardStatus::VariantWin(!color)),
+            Self::Horde => position
+                .occupied_color(White)
+                .is_empty()
+                .then_some(BoardStatus::VariantWin(Black)),
+            Self::Antichess => (position.self_occupied().is_empty() || !position.has_legal_moves())
+                .then_some(BoardStatus::VariantWin(position.turn())),
+            Self::ThreeCheck => ALL_COLORS
+                .into_iter()
+                .find(|&color| position.get_remaining_checks(color) == 0)
+                .map(BoardStatus::VariantWin),
+            Self::KingOfTheHill => ALL_COLORS
+                .into_iter()
+                .find(|&color| BB_CENTER.contains(position.get_king_square_unchecked(color)))
+                .map(BoardStatus::VariantWin),
+            Self::RacingKings => {
+                let white_king_square = position.get_king_square_unchecked(White);
+                let black_king_square = position.get_king_square_unchecked(Black);
+                match (
+                    BB_RANK_8.contains(white_king_square),
+                    BB_RANK_8.contains(black_king_square),
+                ) {
+                    (true, true) => Some(BoardStatus::VariantDraw),
+                    (false, true) => Some(BoardStatus::VariantWin(Black)),
+                    (true, false) => {
+                        let black_can_draw = position.turn() == Black
+                            && position.generate_legal_moves().any(|move_| {
+                                move_.get_source() == black_king_square
+                                    && BB_RANK_8.contains(move_.get_dest())
+                            });
+                        (!black_can_draw).then_some(BoardStatus::VariantWin(White))
+                    }
+                    (false, false) => None,
+                }
+            }
+        }
+    }
+
+    /// The evaluation terms for the goals of the variant from the point of view of white, which the evaluators add to
+    /// their standard chess evaluation.
+    pub fn evaluate_terms(self, position: &BoardPosition) -> Score {
+        let evaluate_color = |color: Color| -> Score {
+            match self {
+                Self::Standard | Self::Atomic | Self::Horde => 0,
+                Self::ThreeCheck => *get_item_unchecked!(
+                    THREE_CHECK_CHECKS_GIVEN_BONUS,
+                    (THREE_CHECK_NUM_CHECKS - position.get_remaining_checks(color)) as usize
+                ),
+                Self::KingOfTheHill => {
+                    let distance = BB_CENTER
+                        .into_iter()
+                        .map(|square| square.distance(position.get_king_square_unchecked(color)))
+                        .min()
+                        .unwrap_or_default();
+                    *get_item_unchecked!(KING_OF_THE_HILL_CENTER_DISTANCE_BONUS, distance as usize)
+                }
+                Self::RacingKings => {
+                    let king_square = position.get_king_square_unchecked(color);
+                    RACING_KINGS_RANK_BONUS * king_square.get_rank().to_int() as Score
+                }
+                Self::Crazyhouse => ALL_PIECE_TYPES
+                    .into_iter()
+                    .map(|piece_type| {
+                        let pocket_value = piece_type.evaluate() as i32
+                            * position.get_pocket_count(color, piece_type) as i32;
+                        (pocket_value * CRAZYHOUSE_POCKET_PIECE_VALUE_PERCENTAGE as i32 / 100)
+                            as Score
+                    })
+                    .sum(),
+                Self::Antichess => {
+                    -ANTICHESS_PIECE_PENALTY * position.occupied_color(color).popcnt() as Score
+                }
+            }
+        };
+        evaluate_color(White) - evaluate_color(Black)
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_uci_name())
+    }
+}
+
+impl FromStr for Variant {
+    type Err = TimecatError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s
+            .trim()
+            .to_lowercase()
+            .replace(['-', '_', ' '], "")
+            .as_str()
+        {
+            "chess" | "standard" | "normal" => Ok(Self::Standard),
+            "3check" | "threecheck" => Ok(Self::ThreeCheck),
+            "kingofthehill" | "koth" => Ok(Self::KingOfTheHill),
+            "racingkings" => Ok(Self::RacingKings),
+            "crazyhouse" | "zh" => Ok(Self::Crazyhouse),
+            "atomic" => Ok(Self::Atomic),
+            "horde" => Ok(Self::Horde),
+            "antichess" | "giveaway" | "losingchess" => Ok(Self::Antichess),
+            _ => Err(TimecatError::UnknownVariant {
+                variant: s.trim().to_string(),
+            }),
+        }
+    }
+}
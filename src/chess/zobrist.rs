@@ -248,6 +248,47 @@ const ZOBRIST_EP: [u64; NUM_FILES] = [
 
 const TURN: u64 = 0xF8D626AAAF278509;
 
+const ZOBRIST_REMAINING_CHECKS: [[u64; THREE_CHECK_NUM_CHECKS as usize + 1]; NUM_COLORS] = [
+    [
+        0x529ED28196C194BF,
+        0xB92F5E7CF6C8D93B,
+        0x1ECB363FF3FE8045,
+        0x7856CB89364210A0,
+    ],
+    [
+        0x4AE957C18A0E5FE0,
+        0xB76EBD72444DB03C,
+        0x5946F6D10716A048,
+        0x016B16252345C1F3,
+    ],
+];
+
+/// The keys for the number of pieces of each kind in the pockets of Crazyhouse, generated with SplitMix64 as they are
+/// not part of the Polyglot specification. An empty pocket has no key, so that the hashes of standard chess positions
+/// are unchanged.
+const ZOBRIST_POCKETS: [[[u64; MAX_POCKET_COUNT as usize + 1]; NUM_PIECE_TYPES]; NUM_COLORS] = {
+    let mut keys = [[[0; MAX_POCKET_COUNT as usize + 1]; NUM_PIECE_TYPES]; NUM_COLORS];
+    let mut state: u64 = 0x2545F4914F6CDD1D;
+    let mut color_index = 0;
+    while color_index < NUM_COLORS {
+        let mut piece_type_index = 0;
+        while piece_type_index < NUM_PIECE_TYPES {
+            let mut count = 1;
+            while count <= MAX_POCKET_COUNT as usize {
+                state = state.wrapping_add(0x9E3779B97F4A7C15);
+                let mut key = state;
+                key = (key ^ (key >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+                key = (key ^ (key >> 27)).wrapping_mul(0x94D049BB133111EB);
+                keys[color_index][piece_type_index][count] = key ^ (key >> 31);
+                count += 1;
+            }
+            piece_type_index += 1;
+        }
+        color_index += 1;
+    }
+    keys
+};
+
 pub struct Zobrist;
 
 impl Zobrist {
@@ -280,4 +321,41 @@ impl Zobrist {
     pub fn color(color: Color) -> u64 {
         *get_item_unchecked!(const [TURN, 0], color.to_index())
     }
+
+    /// The key for the checks the color still has to give in Three-check.
+    #[inline]
+    pub fn remaining_checks(color: Color, remaining_checks: u8) -> u64 {
+        *get_item_unchecked!(
+            ZOBRIST_REMAINING_CHECKS,
+            color.to_index(),
+            remaining_checks as usize
+        )
+    }
+
+    /// The key for the number of pieces of the kind in the pocket of the color in Crazyhouse.
+    #[inline]
+    pub fn pocket(color: Color, piece_type: PieceType, count: u8) -> u64 {
+        *get_item_unchecked!(
+            ZOBRIST_POCKETS,
+            color.to_index(),
+            piece_type.to_index(),
+            count as usize
+        )
+    }
+
+    /// A fingerprint of all the keys, to detect hashes generated with different keys.
+    pub fn get_fingerprint() -> u64 {
+        let mut hasher = Fnv1aHasher::default();
+        ZOBRIST_PIECES
+            .iter()
+            .flatten()
+            .flatten()
+            .chain(ZOBRIST_CASTLES.iter().flatten())
+            .chain(ZOBRIST_EP.iter())
+            .chain([TURN].iter())
+            .chain(ZOBRIST_REMAINING_CHECKS.iter().flatten())
+            .chain(ZOBRIST_POCKETS.iter().flatten().flatten())
+            .for_each(|key| hasher.write(&key.to_le_bytes()));
+        hasher.finish()
+    }
 }
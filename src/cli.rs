@@ -0,0 +1,489 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CliSubcommand {
+    name: &'static str,
+    optional_argument_name: Option<&'static str>,
+    description: &'static str,
+}
+
+impl CliSubcommand {
+    pub const fn new(
+        name: &'static str,
+        optional_argument_name: Option<&'static str>,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            optional_argument_name,
+            description,
+        }
+    }
+
+    #[inline]
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The name of the positional argument, which takes all the remaining words, so that a FEN need not be quoted.
+    #[inline]
+    pub fn get_argument_name(&self) -> Option<&'static str> {
+        self.optional_argument_name
+    }
+
+    #[inline]
+    pub fn get_description(&self) -> &'static str {
+        self.description
+    }
+
+    fn find(name: &str) -> Result<&'static Self> {
+        CLI_SUBCOMMANDS
+            .iter()
+            .find(|subcommand| subcommand.name == name)
+            .ok_or_else(|| TimecatError::UnknownSubcommand {
+                subcommand: name.to_string(),
+            })
+    }
+
+    fn get_usage(&self) -> String {
+        match self.optional_argument_name {
+            Some(argument_name) => format!("{} <{argument_name}>", self.name),
+            None => self.name.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CliOption {
+    long: &'static str,
+    optional_short: Option<char>,
+    optional_value_name: Option<&'static str>,
+    description: &'static str,
+    subcommands: &'static [&'static str],
+}
+
+impl CliOption {
+    pub const fn new(
+        long: &'static str,
+        optional_short: Option<char>,
+        optional_value_name: Option<&'static str>,
+        description: &'static str,
+        subcommands: &'static [&'static str],
+    ) -> Self {
+        Self {
+            long,
+            optional_short,
+            optional_value_name,
+            description,
+            subcommands,
+        }
+    }
+
+    #[inline]
+    pub fn get_long(&self) -> &'static str {
+        self.long
+    }
+
+    #[inline]
+    pub fn get_short(&self) -> Option<char> {
+        self.optional_short
+    }
+
+    #[inline]
+    pub fn takes_value(&self) -> bool {
+        self.optional_value_name.is_some()
+    }
+
+    #[inline]
+    pub fn get_description(&self) -> &'static str {
+        self.description
+    }
+
+    /// The subcommands the option belongs to, where an empty list means the option is accepted everywhere.
+    #[inline]
+    pub fn get_subcommands(&self) -> &'static [&'static str] {
+        self.subcommands
+    }
+
+    #[inline]
+    pub fn is_global(&self) -> bool {
+        self.subcommands.is_empty()
+    }
+
+    fn find(option_string: &str) -> Option<&'static Self> {
+        if let Some(long) = option_string.strip_prefix("--") {
+            return CLI_OPTIONS.iter().find(|option| option.long == long);
+        }
+        let mut chars = option_string.strip_prefix('-')?.chars();
+        let short = chars.next()?;
+        if chars.next().is_some() {
+            return None;
+        }
+        CLI_OPTIONS
+            .iter()
+            .find(|option| option.optional_short == Some(short))
+    }
+
+    fn get_usage(&self) -> String {
+        let mut usage = match self.optional_short {
+            Some(short) => format!("-{short}, --{}", self.long),
+            None => format!("    --{}", self.long),
+        };
+        if let Some(value_name) = self.optional_value_name {
+            usage += &format!(" <{value_name}>");
+        }
+        usage
+    }
+}
+
+/// Arguments of the timecat binary, validated against [`CLI_SUBCOMMANDS`] and [`CLI_OPTIONS`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CliArgs {
+    optional_subcommand: Option<&'static CliSubcommand>,
+    optional_argument: Option<String>,
+    options: Vec<(&'static CliOption, Option<String>)>,
+}
+
+impl CliArgs {
+    /// Parses the arguments, the first of which is the program name.
+    pub fn parse(args: &[&str]) -> Result<Self> {
+        let mut cli_args = Self::default();
+        let mut arguments = vec![];
+        let mut args = args.iter().skip(1).copied().peekable();
+        while let Some(arg) = args.next() {
+            if !arg.starts_with('-') || arg == "-" {
+                if cli_args.optional_subcommand.is_none() {
+                    cli_args.optional_subcommand = Some(CliSubcommand::find(arg)?);
+                } else {
+                    arguments.push(arg.to_string());
+                }
+                continue;
+            }
+            let (option_string, inline_value) = match arg.split_once('=') {
+                Some((option_string, value)) => (option_string, Some(value.to_string())),
+                None => (arg, None),
+            };
+            let option =
+                CliOption::find(option_string).ok_or_else(|| TimecatError::UnknownCliOption {
+                    option: option_string.to_string(),
+                })?;
+            let value = if !option.takes_value() {
+                if let Some(value) = inline_value {
+                    return Err(TimecatError::InvalidCliValue {
+                        option: format!("--{}", option.long),
+                        value,
+                    });
+                }
+                None
+            } else if inline_value.is_some() {
+                inline_value
+            } else if option.long == "command" {
+                // The commands can contain spaces, so they run up to the next option.
+                let mut words = vec![];
+                while let Some(word) = args.next_if(|word| !word.starts_with("--")) {
+                    words.push(word);
+                }
+                Some(words.join(" "))
+            } else if option.long == "fen" {
+                // An unquoted FEN arrives as separate fields, which are joined back like the positional arguments.
+                let mut words = vec![];
+                let mut num_fields = 0;
+                while num_fields < NUM_FEN_FIELDS {
+                    let Some(word) = args.next_if(|word| !word.starts_with("--")) else {
+                        break;
+                    };
+                    num_fields += word.split_whitespace().count();
+                    words.push(word);
+                }
+                Some(words.join(" "))
+            } else {
+                args.next().map(|value| value.to_string())
+            };
+            if value.as_ref().is_some_and(|value| value.is_empty())
+                || (option.takes_value() && value.is_none())
+            {
+                return Err(TimecatError::MissingCliValue {
+                    option: format!("--{}", option.long),
+                });
+            }
+            if option.long == "serve" {
+                // Kept from before the subcommands, the option stands for the serve subcommand and its address.
+                if cli_args
+                    .optional_subcommand
+                    .is_some_and(|subcommand| subcommand.name != "serve")
+                {
+                    return Err(TimecatError::CliOptionNotApplicable {
+                        option: "--serve".to_string(),
+                        optional_subcommand: cli_args.get_subcommand().map(|name| name.to_string()),
+                    });
+                }
+                cli_args.optional_subcommand = Some(CliSubcommand::find("serve")?);
+                arguments.extend(value);
+                continue;
+            }
+            cli_args.options.push((option, value));
+        }
+        cli_args.validate(arguments)?;
+        Ok(cli_args)
+    }
+
+    fn validate(&mut self, arguments: Vec<String>) -> Result<()> {
+        match self.optional_subcommand {
+            Some(subcommand) => match subcommand.optional_argument_name {
+                Some(argument_name) => {
+                    if arguments.is_empty() {
+                        return Err(TimecatError::MissingCliArgument {
+                            name: argument_name.to_string(),
+                        });
+                    }
+                    self.optional_argument = Some(arguments.join(" "));
+                }
+                None => {
+                    if let Some(argument) = arguments.first() {
+                        return Err(TimecatError::UnexpectedCliArgument {
+                            argument: argument.clone(),
+                        });
+                    }
+                }
+            },
+            None => debug_assert!(arguments.is_empty()),
+        }
+        for (option, _) in &self.options {
+            let optional_subcommand_name = self.get_subcommand();
+            if !option.is_global()
+                && !optional_subcommand_name
+                    .is_some_and(|subcommand_name| option.subcommands.contains(&subcommand_name))
+            {
+                return Err(TimecatError::CliOptionNotApplicable {
+                    option: format!("--{}", option.long),
+                    optional_subcommand: optional_subcommand_name.map(|name| name.to_string()),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn get_subcommand(&self) -> Option<&'static str> {
+        self.optional_subcommand.map(|subcommand| subcommand.name)
+    }
+
+    #[inline]
+    pub fn get_argument(&self) -> Option<&str> {
+        self.optional_argument.as_deref()
+    }
+
+    pub fn has_flag(&self, long: &str) -> bool {
+        self.options.iter().any(|(option, _)| option.long == long)
+    }
+
+    /// Returns the value of the option, the last one winning if it was given more than once.
+    pub fn get_option(&self, long: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| option.long == long)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    pub fn parse_option<T: FromStr>(&self, long: &str) -> Result<Option<T>> {
+        self.get_option(long)
+            .map(|value| {
+                value.parse().map_err(|_| TimecatError::InvalidCliValue {
+                    option: format!("--{long}"),
+                    value: value.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    fn parse_argument<T: FromStr>(&self) -> Result<T> {
+        let argument = self.get_argument().unwrap_or_default();
+        argument
+            .parse()
+            .map_err(|_| TimecatError::UnexpectedCliArgument {
+                argument: argument.to_string(),
+            })
+    }
+
+    fn get_set_option_command(name: &str, value: impl fmt::Display) -> UserCommand {
+        UserCommand::SetUCIOption {
+            user_input: format!("setoption name {name} value {value}"),
+        }
+    }
+
+    fn get_search_config(&self, default_depth: Depth) -> Result<SearchConfig> {
+        if let Some(movetime) = self.parse_option::<u64>("movetime")? {
+            return Ok(SearchConfig::new_movetime(Duration::from_millis(movetime)));
+        }
+        let depth = self.parse_option("depth")?.unwrap_or(default_depth);
+        if depth <= 0 {
+            return Err(TimecatError::InvalidDepth { depth });
+        }
+        Ok(SearchConfig::new_depth(depth))
+    }
+
+    fn get_fen_commands(&self) -> Result<Vec<UserCommand>> {
+        let Some(fen) = self.get_option("fen") else {
+            return Ok(vec![]);
+        };
+        Board::from_fen(fen)?;
+        Ok(vec![UserCommand::SetFen(fen.to_string())])
+    }
+
+    /// A path to a PGN file, a FEN or PGN move text, tried in this order.
+    fn get_analysis_position_commands(&self) -> Result<Vec<UserCommand>> {
+        let argument = self.get_argument().unwrap_or_default();
+        let path = Path::new(argument);
+        if path.is_file() {
+            return Ok(vec![UserCommand::LoadPgn(fs::read_to_string(path)?)]);
+        }
+        if Board::from_fen(argument).is_ok() {
+            return Ok(vec![UserCommand::SetFen(argument.to_string())]);
+        }
+        Board::from_pgn(argument)?;
+        Ok(vec![UserCommand::LoadPgn(argument.to_string())])
+    }
+
+    fn get_setup_commands(&self) -> Result<Vec<UserCommand>> {
+        let mut user_commands = vec![];
+        if self.has_flag("no-color") {
+            #[cfg(feature = "colored")]
+            user_commands.push(UserCommand::SetColor(false));
+        }
+        if let Some(num_threads) = self.parse_option::<NonZeroUsize>("threads")? {
+            user_commands.push(Self::get_set_option_command("Threads", num_threads));
+        }
+        if let Some(hash_size) = self.parse_option::<NonZeroUsize>("hash")? {
+            user_commands.push(Self::get_set_option_command("Hash", hash_size));
+        }
+        if let Some(book_path) = self.get_option("book") {
+            user_commands.push(Self::get_set_option_command("BookFile", book_path));
+        }
+        if let Some(nnue_path) = self.get_option("nnue") {
+            user_commands.push(Self::get_set_option_command("EvalFile", nnue_path));
+        }
+        Ok(user_commands)
+    }
+
+    /// Translates the arguments into the commands the engine runs at startup. Commands which do a single task end with
+    /// [`UserCommand::TerminateEngine`].
+    pub fn to_user_commands(&self) -> Result<Vec<UserCommand>> {
+        if self.has_flag("help") {
+            return Ok(vec![
+                UserCommand::PrintText(generate_cli_help()),
+                UserCommand::TerminateEngine,
+            ]);
+        }
+        if self.has_flag("version") {
+            return Ok(vec![
+                UserCommand::EngineVersion,
+                UserCommand::TerminateEngine,
+            ]);
+        }
+        let mut user_commands = self.get_setup_commands()?;
+        if self.has_flag("uci") {
+            user_commands.push(UserCommand::ChangeToUCIMode { verbose: false });
+        }
+        if self.has_flag("test") {
+            #[cfg(feature = "debug")]
+            user_commands.extend([UserCommand::RunTest, UserCommand::TerminateEngine]);
+            #[cfg(not(feature = "debug"))]
+            return Err(TimecatError::FeatureNotEnabled {
+                s: "debug".to_string(),
+            });
+        }
+        let is_one_shot = match self.get_subcommand() {
+            None | Some("serve") => false,
+            Some("uci") => {
+                user_commands.push(UserCommand::ChangeToUCIMode { verbose: false });
+                false
+            }
+            Some("console") => {
+                user_commands.push(UserCommand::ChangeToConsoleMode { verbose: false });
+                false
+            }
+            Some("bench") => {
+                let depth = self.parse_option("depth")?.unwrap_or(DEFAULT_BENCH_DEPTH);
+                if depth <= 0 {
+                    return Err(TimecatError::InvalidDepth { depth });
+                }
+                user_commands.push(UserCommand::Bench(depth));
+                true
+            }
+            Some("perft") => {
+                user_commands.extend(self.get_fen_commands()?);
+                user_commands.push(UserCommand::Perft(self.parse_argument()?));
+                true
+            }
+            Some("analyse") => {
+                user_commands.extend(self.get_analysis_position_commands()?);
+                user_commands.push(UserCommand::Go(
+                    self.get_search_config(DEFAULT_ANALYSIS_DEPTH)?,
+                ));
+                true
+            }
+            Some("selfplay") => {
+                let config = if self.has_flag("depth") || self.has_flag("movetime") {
+                    self.get_search_config(DEFAULT_ANALYSIS_DEPTH)?
+                } else {
+                    DEFAULT_SELFPLAY_COMMAND
+                };
+                user_commands.push(UserCommand::SelfPlay(config));
+                true
+            }
+            Some("book") => {
+                user_commands.extend(self.get_fen_commands()?);
+                user_commands.push(UserCommand::ProbeBook(
+                    self.get_argument().unwrap_or_default().to_string(),
+                ));
+                true
+            }
+            Some(_) => unreachable!("Every subcommand is handled"),
+        };
+        if let Some(command_string) = self.get_option("command") {
+            user_commands.extend(Parser::parse_command(command_string)?);
+            user_commands.push(UserCommand::TerminateEngine);
+        } else if is_one_shot {
+            user_commands.push(UserCommand::TerminateEngine);
+        }
+        Ok(user_commands)
+    }
+}
+
+/// Generates the `--help` text from [`CLI_SUBCOMMANDS`] and [`CLI_OPTIONS`].
+pub fn generate_cli_help() -> String {
+    let subcommand_usages = CLI_SUBCOMMANDS
+        .iter()
+        .map(|subcommand| subcommand.get_usage())
+        .collect_vec();
+    let option_usages = CLI_OPTIONS
+        .iter()
+        .map(|option| option.get_usage())
+        .collect_vec();
+    let width = subcommand_usages
+        .iter()
+        .chain(&option_usages)
+        .map(|usage| usage.len())
+        .max()
+        .unwrap_or_default()
+        + 2;
+    let mut help = format!(
+        "{}\n\nUsage: {} [SUBCOMMAND] [OPTIONS]\n\nSubcommands:\n",
+        get_engine_version(),
+        ENGINE_NAME.to_lowercase(),
+    );
+    for (subcommand, usage) in CLI_SUBCOMMANDS.iter().zip(subcommand_usages) {
+        help += &format!("  {usage:width$}{}\n", subcommand.description);
+    }
+    help += "\nOptions:\n";
+    for (option, usage) in CLI_OPTIONS.iter().zip(option_usages) {
+        help += &format!("  {usage:width$}{}", option.description);
+        if !option.is_global() {
+            help += &format!(" [{}]", option.subcommands.join(", "));
+        }
+        help += "\n";
+    }
+    help += "\nWithout a subcommand, the engine reads commands from the standard input.";
+    help
+}
@@ -0,0 +1,262 @@
+use super::*;
+use std::collections::BTreeMap;
+
+/// Value of a UCI option in a config file, kept in its natural TOML or JSON type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum UCIOptionValue {
+    Check(bool),
+    Spin(u64),
+    String(String),
+}
+
+impl fmt::Display for UCIOptionValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Check(b) => write!(f, "{b}"),
+            Self::Spin(value) => write!(f, "{value}"),
+            Self::String(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl UCIOptionValue {
+    fn from_option<T: ChessEngine>(option: &UCIOption<T>, value: String) -> Self {
+        if option.is_check() {
+            if let Ok(b) = value.parse() {
+                return Self::Check(b);
+            }
+        } else if option.is_spin() {
+            if let Ok(value) = value.parse() {
+                return Self::Spin(value);
+            }
+        }
+        Self::String(value)
+    }
+}
+
+pub type UCIOptionValues = BTreeMap<String, UCIOptionValue>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConfigFileFormat {
+    #[default]
+    Toml,
+    Json,
+}
+
+impl ConfigFileFormat {
+    /// Guesses the format from the extension, treating anything other than `.json` as TOML.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Toml,
+        }
+    }
+}
+
+/// Engine configuration read at startup, which sets UCI options by name instead of re-sending them with `setoption`
+/// every session.
+///
+/// The options in `options` are always applied, followed by the ones of the selected profile, which is either given
+/// on the command line or named by the `profile` key of the file.
+///
+/// ```toml
+/// profile = "blitz"
+///
+/// [options]
+/// Threads = 4
+/// Hash = 256
+///
+/// [profiles.analysis]
+/// Threads = 16
+/// Hash = 4096
+///
+/// [profiles.blitz]
+/// "Move Overhead" = 50
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineConfigFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    profile: Option<String>,
+    #[serde(default)]
+    options: UCIOptionValues,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    profiles: BTreeMap<String, UCIOptionValues>,
+}
+
+impl EngineConfigFile {
+    /// The config file in the user config directory, like `~/.config/timecat/config.toml` on Linux.
+    pub fn get_default_path() -> Option<PathBuf> {
+        Some(
+            dirs::config_dir()?
+                .join(CONFIG_DIRECTORY_NAME)
+                .join(DEFAULT_CONFIG_FILE_NAME),
+        )
+    }
+
+    pub fn from_str_with_format(s: &str, format: ConfigFileFormat) -> Result<Self> {
+        match format {
+            ConfigFileFormat::Toml => {
+                toml::from_str(s).map_err(|error| error.message().to_string())
+            }
+            ConfigFileFormat::Json => serde_json::from_str(s).map_err(|error| error.to_string()),
+        }
+        .map_err(|err_msg| TimecatError::BadConfigFile {
+            path: PathBuf::new(),
+            err_msg,
+        })
+    }
+
+    pub fn to_string_with_format(&self, format: ConfigFileFormat) -> Result<String> {
+        match format {
+            ConfigFileFormat::Toml => {
+                toml::to_string_pretty(self).map_err(TimecatError::get_custom_error)
+            }
+            ConfigFileFormat::Json => {
+                serde_json::to_string_pretty(self).map_err(TimecatError::get_custom_error)
+            }
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::from_str_with_format(
+            &fs::read_to_string(path)?,
+            ConfigFileFormat::from_path(path),
+        )
+        .map_err(|error| match error {
+            TimecatError::BadConfigFile { err_msg, .. } => TimecatError::BadConfigFile {
+                path: path.to_path_buf(),
+                err_msg,
+            },
+            error => error,
+        })
+    }
+
+    /// Loads the file if it exists, returning an empty config otherwise.
+    pub fn load_or_default(path: &Path) -> Result<Self> {
+        if path.exists() {
+            Self::load(path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(
+            path,
+            self.to_string_with_format(ConfigFileFormat::from_path(path))?,
+        )?;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn get_profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    #[inline]
+    pub fn set_profile(&mut self, profile: Option<String>) {
+        self.profile = profile;
+    }
+
+    #[inline]
+    pub fn get_options(&self) -> &UCIOptionValues {
+        &self.options
+    }
+
+    #[inline]
+    pub fn get_options_mut(&mut self) -> &mut UCIOptionValues {
+        &mut self.options
+    }
+
+    #[inline]
+    pub fn get_profile_options(&self, profile: &str) -> Option<&UCIOptionValues> {
+        self.profiles.get(profile)
+    }
+
+    /// Returns the options of the profile, creating an empty profile if it does not exist yet.
+    #[inline]
+    pub fn get_profile_options_mut(&mut self, profile: &str) -> &mut UCIOptionValues {
+        self.profiles.entry(profile.to_string()).or_default()
+    }
+
+    pub fn get_profile_names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+    /// The options to set, with the ones of the profile overriding the common ones. The `optional_profile` takes
+    /// precedence over the profile named in the file.
+    pub fn get_resolved_options(&self, optional_profile: Option<&str>) -> Result<UCIOptionValues> {
+        // Option names are case insensitive, so a profile can override an option spelled differently.
+        let mut options: UCIOptionValues = self
+            .options
+            .iter()
+            .map(|(name, value)| (name.to_lowercase(), value.clone()))
+            .collect();
+        if let Some(profile) = optional_profile.or(self.get_profile()) {
+            let profile_options =
+                self.get_profile_options(profile)
+                    .ok_or_else(|| TimecatError::UnknownProfile {
+                        profile: profile.to_string(),
+                    })?;
+            options.extend(
+                profile_options
+                    .iter()
+                    .map(|(name, value)| (name.to_lowercase(), value.clone())),
+            );
+        }
+        Ok(options)
+    }
+
+    pub fn apply<T: ChessEngine>(
+        &self,
+        engine: &mut T,
+        uci_state_manager: &UCIStateManager<T>,
+        optional_profile: Option<&str>,
+    ) -> Result<()> {
+        for (name, value) in self.get_resolved_options(optional_profile)? {
+            uci_state_manager.set_option(engine, &name, value.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: ChessEngine> UCIStateManager<T> {
+    /// The options which were set since the engine started, by `setoption` or by a config file.
+    pub fn get_current_option_values(&self) -> UCIOptionValues {
+        self.get_all_options()
+            .iter()
+            .filter_map(|option| {
+                let value = self.get_current_value(option)?;
+                Some((
+                    option.get_name().to_string(),
+                    UCIOptionValue::from_option(option, value),
+                ))
+            })
+            .collect()
+    }
+
+    /// Writes the options set so far to the config file, either as the common options or into a profile. The other
+    /// contents of the file are kept.
+    pub fn save_options(&self, path: &Path, optional_profile: Option<&str>) -> Result<()> {
+        let mut config_file = EngineConfigFile::load_or_default(path)?;
+        let current_option_values = self.get_current_option_values();
+        let options = match optional_profile {
+            Some(profile) => config_file.get_profile_options_mut(profile),
+            None => config_file.get_options_mut(),
+        };
+        options.retain(|name, _| {
+            !current_option_values
+                .keys()
+                .any(|current_name| current_name.eq_ignore_ascii_case(name))
+        });
+        options.extend(current_option_values);
+        config_file.save(path)
+    }
+}
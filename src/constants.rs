@@ -148,17 +148,83 @@ pub mod bitboard_and_square {
 }
 
 pub mod board {
+    use super::*;
+
     pub const EMPTY_SPACE_SYMBOL: &str = " ";
     pub const EMPTY_SPACE_UNICODE_SYMBOL: &str = " ";
     pub const WHITE_PIECE_UNICODE_SYMBOLS: [&str; 6] = ["♙", "♘", "♗", "♖", "♕", "♔"];
     pub const BLACK_PIECE_UNICODE_SYMBOLS: [&str; 6] = ["♟", "♞", "♝", "♜", "♛", "♚"];
     pub const MAX_MOVES_PER_POSITION: usize = 250;
+    pub const MAX_NUM_PIECES_PER_COLOR: u32 = 16;
+    pub const MAX_NUM_PAWNS_PER_COLOR: u32 = 8;
+    pub const NUM_SYMMETRIES: usize = 8;
+    pub const ALL_SYMMETRIES: [Symmetry; NUM_SYMMETRIES] = [
+        Symmetry::Identity,
+        Symmetry::FlipVertical,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipDiagonal,
+        Symmetry::FlipAntiDiagonal,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+    ];
 }
 
 pub mod fen {
+    pub const NUM_FEN_FIELDS: usize = 6;
     pub const EMPTY_FEN: &str = "8/8/8/8/8/8/8/8 w - - 0 1";
     pub const STARTING_POSITION_FEN: &str =
         "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    pub const BENCH_FENS: &[&str] = &[
+        STARTING_POSITION_FEN,
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+        "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4",
+        "6k1/5ppp/8/8/8/8/5PPP/3R2K1 w - - 0 1",
+    ];
+    pub const RACING_KINGS_STARTING_FEN: &str = "8/8/8/8/8/8/krbnNBRK/qrbnNBRQ w - - 0 1";
+    pub const ANTICHESS_STARTING_FEN: &str =
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1";
+    pub const HORDE_STARTING_FEN: &str =
+        "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1";
+    pub const CRAZYHOUSE_STARTING_FEN: &str =
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1";
+}
+
+pub mod variant {
+    use super::*;
+
+    pub const NUM_VARIANTS: usize = 8;
+    pub const ALL_VARIANTS: [Variant; NUM_VARIANTS] = [
+        Variant::Standard,
+        Variant::ThreeCheck,
+        Variant::KingOfTheHill,
+        Variant::RacingKings,
+        Variant::Crazyhouse,
+        Variant::Atomic,
+        Variant::Antichess,
+        Variant::Horde,
+    ];
+    pub const THREE_CHECK_NUM_CHECKS: u8 = 3;
+    pub const THREE_CHECK_CHECKS_GIVEN_BONUS: [Score; THREE_CHECK_NUM_CHECKS as usize + 1] =
+        [0, 120, 350, 1000];
+    /// Indexed by the distance of the king to the nearest center square, which is at most 3.
+    pub const KING_OF_THE_HILL_CENTER_DISTANCE_BONUS: [Score; 4] = [1000, 150, 50, 0];
+    pub const RACING_KINGS_RANK_BONUS: Score = 60;
+    pub const HORDE_NUM_PAWNS: u32 = 36;
+    /// The number of pieces of a kind a pocket can hold, which is reached by the pawns.
+    pub const MAX_POCKET_COUNT: u8 = 16;
+    /// A piece in the pocket can be dropped anywhere, which makes it worth more than on the board.
+    pub const CRAZYHOUSE_POCKET_PIECE_VALUE_PERCENTAGE: Score = 120;
+    /// Every piece left on the board is a burden in Antichess, where the side losing all of its pieces wins.
+    pub const ANTICHESS_PIECE_PENALTY: Score = 100;
+    pub const ANTICHESS_NUM_PROMOTION_PIECES: usize = NUM_PROMOTION_PIECES + 1;
+    /// The king is an ordinary piece in Antichess, so a pawn can also promote to it.
+    pub const ANTICHESS_PROMOTION_PIECES: [PieceType; ANTICHESS_NUM_PROMOTION_PIECES] =
+        [Queen, Knight, Rook, Bishop, King];
 }
 
 pub mod strings {
@@ -209,6 +275,7 @@ pub mod evaluate {
         + 2 * Queen.evaluate();
     pub const MAX_MATERIAL_SCORE: Score = INITIAL_MATERIAL_SCORE_ABS / 2;
     pub const WINNING_SCORE_THRESHOLD: Score = 15 * PAWN_VALUE;
+    pub const INBUILT_EVAL_FILE: &str = "Inbuilt";
 }
 
 pub mod cache_table {
@@ -216,6 +283,19 @@ pub mod cache_table {
 
     // pub const DEFAULT_HASH: NonZeroU64 = NonZeroU64::new(1).unwrap();
     pub const DEFAULT_HASH: NonZeroU64 = unsafe { NonZeroU64::new_unchecked(1) };
+
+    pub const TRANSPOSITION_TABLE_CLUSTER_SIZE: usize = 3;
+    pub const TRANSPOSITION_TABLE_NUM_GENERATIONS: u8 = 32;
+    pub const TRANSPOSITION_TABLE_AGE_WEIGHT: i16 = 8;
+    pub const TRANSPOSITION_TABLE_REPLACEMENT_DEPTH_MARGIN: Depth = 3;
+    pub const HASH_FULL_SAMPLE_NUM_CLUSTERS: usize = 1000;
+
+    pub const CACHE_LINE_SIZE: usize = 64;
+    pub const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+    pub const PARALLEL_ZEROING_MIN_SIZE: usize = 16 * 1024 * 1024;
+
+    pub const HASH_FILE_MAGIC: [u8; 8] = *b"TIMECATH";
+    pub const HASH_FILE_VERSION: u32 = 1;
 }
 
 pub mod engine {
@@ -234,6 +314,10 @@ pub mod engine {
     pub const LMR_BASE_REDUCTION: f64 = 0.75;
     pub const LMR_MOVE_DIVIDER: f64 = 2.25;
 
+    pub const SINGULAR_EXTENSION_MIN_DEPTH: Depth = 8;
+    pub const SINGULAR_EXTENSION_TT_DEPTH_MARGIN: Depth = 3;
+    pub const SINGULAR_EXTENSION_MARGIN_PER_DEPTH: Score = 2;
+
     pub const ASPIRATION_WINDOW_CUTOFF: Score = PAWN_VALUE / 2;
 
     pub const FOLLOW_PV: bool = true;
@@ -254,11 +338,37 @@ pub mod engine {
     pub const LMR_TABLE: [[Depth; 64]; 64] = [[0; 64]; 64];
 }
 
+pub mod time_management {
+    use super::*;
+
+    pub const SUDDEN_DEATH_MOVES_HORIZON: NumMoves = 40;
+    pub const SUDDEN_DEATH_MIN_MOVES_LEFT: NumMoves = 20;
+    pub const SUDDEN_DEATH_MAXIMUM_USAGE_RATIO: f64 = 0.2;
+    pub const MOVES_TO_GO_MAXIMUM_USAGE_RATIO: f64 = 0.8;
+    pub const LAST_MOVE_BEFORE_TIME_CONTROL_RATIO: f64 = 0.9;
+    pub const MAXIMUM_TIME_RATIO: f64 = 5.0;
+
+    pub const STABILITY_SCALE_MAX: f64 = 1.4;
+    pub const STABILITY_SCALE_STEP: f64 = 0.08;
+    pub const MAX_STABILITY_SCALE_STEPS: usize = 8;
+    pub const SCORE_DROP_SCALE: f64 = 0.6;
+    pub const SCORE_DROP_NORMALIZER: Score = PAWN_VALUE;
+    pub const BEST_MOVE_CHANGES_SCALE: f64 = 0.5;
+    pub const BEST_MOVE_CHANGES_DECAY: f64 = 0.5;
+    pub const NODES_FRACTION_BASE: f64 = 1.5;
+    pub const NODES_FRACTION_SCALE: f64 = 1.35;
+}
+
 pub mod binary {
     use super::*;
 
     pub const DEFAULT_SELFPLAY_COMMAND: SearchConfig =
         SearchConfig::from_go_command(GoCommand::from_millis(3000));
+    pub const DEFAULT_PLAY_HINT_COMMAND: SearchConfig =
+        SearchConfig::from_go_command(GoCommand::from_millis(1000));
+    pub const PLAY_PGN_FILE_PREFIX: &str = "timecat_game_";
+    pub const AUTOMATIC_DRAW_REPETITIONS: usize = 5;
+    pub const AUTOMATIC_DRAW_HALFMOVE_CLOCK: u8 = 150;
 }
 
 pub mod io {
@@ -267,6 +377,71 @@ pub mod io {
     pub const COMMUNICATION_CHECK_INTERVAL: Duration = Duration::from_millis(1);
 }
 
+pub mod server {
+    use super::*;
+
+    pub const DEFAULT_SERVER_MAX_SESSIONS: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+    pub const UNIX_SOCKET_ADDRESS_PREFIX: &str = "unix:";
+    pub const SERVER_ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(10);
+}
+
+pub mod cli {
+    use super::*;
+
+    pub const DEFAULT_BENCH_DEPTH: Depth = 10;
+    pub const DEFAULT_ANALYSIS_DEPTH: Depth = 14;
+
+    const SEARCH_OPTIONS_SUBCOMMANDS: &[&str] = &["analyse", "selfplay"];
+    const FEN_OPTION_SUBCOMMANDS: &[&str] = &["perft", "book"];
+    const SERVER_OPTIONS_SUBCOMMANDS: &[&str] = &["serve"];
+
+    #[rustfmt::skip]
+    pub const CLI_SUBCOMMANDS: &[CliSubcommand] = &[
+        CliSubcommand::new("uci", None, "Starts in UCI mode, the default when the input is not a terminal."),
+        CliSubcommand::new("console", None, "Starts in console mode."),
+        CliSubcommand::new("bench", None, "Searches a fixed set of positions and prints the total nodes and speed."),
+        CliSubcommand::new("perft", Some("DEPTH"), "Counts the leaf nodes of the move tree up to the depth."),
+        CliSubcommand::new("analyse", Some("FEN|PGN"), "Analyses a position given as a FEN, a PGN file or PGN move text."),
+        CliSubcommand::new("selfplay", None, "Lets the engine play a game against itself."),
+        CliSubcommand::new("book", Some("BOOK_FILE"), "Lists the moves of a Polyglot book in a position."),
+        CliSubcommand::new("serve", Some("ADDRESS"), "Serves the engine on host:port over TCP or on unix:<path> over a Unix socket."),
+    ];
+
+    #[rustfmt::skip]
+    pub const CLI_OPTIONS: &[CliOption] = &[
+        CliOption::new("help", Some('h'), None, "Prints this help message.", &[]),
+        CliOption::new("version", Some('V'), None, "Prints the engine version.", &[]),
+        CliOption::new("command", Some('c'), Some("COMMANDS"), "Runs the console commands, separated by &&, and exits.", &[]),
+        CliOption::new("threads", None, Some("N"), "Sets the number of search threads.", &[]),
+        CliOption::new("hash", None, Some("MB"), "Sets the transposition table size in MB.", &[]),
+        CliOption::new("nnue", None, Some("FILE"), "Evaluates with the network in the NNUE file.", &[]),
+        CliOption::new("book", None, Some("FILE"), "Plays from the Polyglot opening book.", &[]),
+        CliOption::new("config", None, Some("FILE"), "Reads the options from the TOML or JSON config file.", &[]),
+        CliOption::new("profile", None, Some("NAME"), "Applies the profile of the config file.", &[]),
+        CliOption::new("no-color", None, None, "Disables the colored output.", &[]),
+        CliOption::new("disable-backtrace", None, None, "Does not print backtraces on panics.", &[]),
+        CliOption::new("uci", None, None, "Same as the uci subcommand.", &[]),
+        CliOption::new("serve", None, Some("ADDRESS"), "Same as the serve subcommand.", &[]),
+        CliOption::new("test", None, None, "Runs the engine tests, needs the debug feature.", &[]),
+        CliOption::new("depth", Some('d'), Some("DEPTH"), "Searches up to the depth.", &["bench", "analyse", "selfplay"]),
+        CliOption::new("movetime", None, Some("MS"), "Searches for the time in milliseconds.", SEARCH_OPTIONS_SUBCOMMANDS),
+        CliOption::new("fen", None, Some("FEN"), "Uses the position instead of the starting position.", FEN_OPTION_SUBCOMMANDS),
+        CliOption::new("max-sessions", None, Some("N"), "Limits the number of concurrent sessions.", SERVER_OPTIONS_SUBCOMMANDS),
+        CliOption::new("max-threads", None, Some("N"), "Limits the threads used by all sessions.", SERVER_OPTIONS_SUBCOMMANDS),
+        CliOption::new("max-hash", None, Some("MB"), "Limits the hash used by all sessions in MB.", SERVER_OPTIONS_SUBCOMMANDS),
+        CliOption::new("shared-resources", None, None, "Lets every session use all the threads.", SERVER_OPTIONS_SUBCOMMANDS),
+    ];
+}
+
+pub mod parse {
+    pub const MAX_COMMAND_SUGGESTION_DISTANCE: usize = 2;
+}
+
+pub mod config_file {
+    pub const CONFIG_DIRECTORY_NAME: &str = "timecat";
+    pub const DEFAULT_CONFIG_FILE_NAME: &str = "config.toml";
+}
+
 pub mod atomic {
     pub const MEMORY_ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::Relaxed;
 }
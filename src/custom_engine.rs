@@ -6,6 +6,10 @@ pub struct EngineProperties {
     _use_mate_distance_pruning: bool,
     _clear_table_after_each_search: bool,
     _use_lmr: bool,
+    _use_singular_extensions: bool,
+    _use_check_extensions: bool,
+    _use_multicut: bool,
+    _use_quiescence_checks: bool,
 }
 
 impl EngineProperties {
@@ -32,6 +36,42 @@ impl EngineProperties {
     pub fn set_using_lmr(&mut self, value: bool) {
         self._use_lmr = value;
     }
+
+    pub fn use_singular_extensions(&self) -> bool {
+        self._use_singular_extensions
+    }
+
+    pub fn set_using_singular_extensions(&mut self, value: bool) {
+        self._use_singular_extensions = value;
+    }
+
+    pub fn use_check_extensions(&self) -> bool {
+        self._use_check_extensions
+    }
+
+    pub fn set_using_check_extensions(&mut self, value: bool) {
+        self._use_check_extensions = value;
+    }
+
+    /// Multicut prunes a node when the singular verification search shows more than one move
+    /// failing high, and negatively extends the hash move when it is not singular but still
+    /// expected to fail high.
+    pub fn use_multicut(&self) -> bool {
+        self._use_multicut
+    }
+
+    pub fn set_using_multicut(&mut self, value: bool) {
+        self._use_multicut = value;
+    }
+
+    /// Searches the quiet checks, and the evasions from them, at the first ply of the quiescence search.
+    pub fn use_quiescence_checks(&self) -> bool {
+        self._use_quiescence_checks
+    }
+
+    pub fn set_using_quiescence_checks(&mut self, value: bool) {
+        self._use_quiescence_checks = value;
+    }
 }
 
 impl Default for EngineProperties {
@@ -40,6 +80,10 @@ impl Default for EngineProperties {
             _use_mate_distance_pruning: true,
             _clear_table_after_each_search: true,
             _use_lmr: true,
+            _use_singular_extensions: true,
+            _use_check_extensions: true,
+            _use_multicut: true,
+            _use_quiescence_checks: true,
         }
     }
 }
@@ -63,6 +107,9 @@ pub struct CustomEngine<T: SearchControl<Searcher<P>>, P: PositionEvaluation> {
     #[cfg_attr(feature = "serde", serde(with = "SerdeHandler"))]
     terminate: Arc<AtomicBool>,
     properties: EngineProperties,
+    config: TimecatConfig,
+    #[cfg_attr(feature = "serde", serde(skip, default = "get_default_output_sink"))]
+    output_sink: Arc<dyn OutputSink>,
     #[cfg_attr(feature = "serde", serde(skip))]
     opening_book: Option<Arc<dyn PolyglotBook>>,
 }
@@ -86,6 +133,8 @@ impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> CustomEngine<T, P> {
             stop_command: AtomicBool::new(false).into(),
             terminate: AtomicBool::new(false).into(),
             properties: EngineProperties::default(),
+            config: TimecatConfig::default(),
+            output_sink: get_default_output_sink(),
             opening_book: TIMECAT_DEFAULTS
                 .inbuilt_book_bytes
                 .map(|bytes| PolyglotBookHashMap::try_from(bytes).ok())
@@ -95,7 +144,7 @@ impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> CustomEngine<T, P> {
     }
 
     #[inline]
-    fn get_transposition_table(&self) -> &TranspositionTable {
+    pub fn get_transposition_table(&self) -> &TranspositionTable {
         &self.transposition_table
     }
 
@@ -157,6 +206,39 @@ impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> CustomEngine<T, P> {
         self.set_termination(false);
     }
 
+    fn go_helper(&mut self, config: &SearchConfig, verbose: bool) -> SearchInfo {
+        if let Some(WeightedMove { move_, weight }) = self.get_opening_book_weighted_move() {
+            return SearchInfoBuilder::new(self.board.get_position().clone(), vec![move_])
+                .set_score(weight as Score)
+                .build();
+        }
+        self.reset_variables();
+        self.transposition_table.increment_generation();
+        let mut join_handles = vec![];
+        for id in 1..self.num_threads.get() {
+            let mut threaded_searcher = self.generate_searcher(id);
+            let controller = self.controller.clone();
+            let join_handle = thread::spawn(move || {
+                threaded_searcher.go(const { &SearchConfig::new_infinite() }, controller, false);
+            });
+            join_handles.push(join_handle);
+        }
+        let _search_interrupt_guard = self.optional_io_reader.as_ref().map(|io_reader| {
+            io_reader.register_search(self.stop_command.clone(), self.terminate.clone())
+        });
+        let mut main_thread_searcher = self.generate_searcher(0);
+        main_thread_searcher.go(config, self.controller.clone(), verbose);
+        self.set_stop_command(true);
+        for join_handle in join_handles {
+            join_handle.join().unwrap();
+        }
+        let mut search_info = main_thread_searcher.get_search_info();
+        if search_info.get_pv().is_empty() && self.board.status() == BoardStatus::Ongoing {
+            search_info.set_pv(&[self.board.generate_legal_moves().next().unwrap()]);
+        }
+        search_info
+    }
+
     #[inline]
     pub fn generate_searcher(&self, id: usize) -> Searcher<P> {
         Searcher::new(
@@ -180,33 +262,31 @@ impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> CustomEngine<T, P> {
     pub fn set_stop_command(&self, b: bool) {
         self.stop_command.store(b, MEMORY_ORDERING);
     }
-
-    fn update_stop_command(
-        stop_command: Arc<AtomicBool>,
-        io_reader: IoReader,
-        terminate: Arc<AtomicBool>,
-    ) {
-        while !stop_command.load(MEMORY_ORDERING) {
-            match io_reader
-                .read_line_once()
-                .unwrap_or_default()
-                .to_lowercase()
-                .trim()
-            {
-                "stop" => stop_command.store(true, MEMORY_ORDERING),
-                "quit" | "exit" => {
-                    stop_command.store(true, MEMORY_ORDERING);
-                    terminate.store(true, MEMORY_ORDERING);
-                }
-                _ => {}
-            }
-        }
-    }
 }
 
 impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> ChessEngine for CustomEngine<T, P> {
     type IoReader = IoReader;
 
+    #[inline]
+    fn get_config(&self) -> &TimecatConfig {
+        &self.config
+    }
+
+    #[inline]
+    fn get_config_mut(&mut self) -> &mut TimecatConfig {
+        &mut self.config
+    }
+
+    #[inline]
+    fn get_output_sink(&self) -> &Arc<dyn OutputSink> {
+        &self.output_sink
+    }
+
+    #[inline]
+    fn set_output_sink(&mut self, output_sink: Arc<dyn OutputSink>) {
+        self.output_sink = output_sink;
+    }
+
     #[inline]
     fn get_board(&self) -> &Board {
         &self.board
@@ -225,14 +305,15 @@ impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> ChessEngine for Custo
 
     fn set_transposition_table_size(&self, size: CacheTableSize) {
         self.transposition_table.set_size(size);
-        if GLOBAL_TIMECAT_STATE.is_in_debug_mode() {
-            self.transposition_table.print_info();
+        if self.config.is_in_debug_mode() {
+            self.run_with_output_settings(|| self.transposition_table.print_info());
         }
     }
 
     #[inline]
     fn set_num_threads(&mut self, num_threads: NonZeroUsize) {
         self.num_threads = num_threads;
+        self.transposition_table.set_num_threads(num_threads);
     }
 
     #[inline]
@@ -265,11 +346,39 @@ impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> ChessEngine for Custo
         self.evaluator.clear();
     }
 
+    #[inline]
+    fn save_hash(&self, path: &Path) -> Result<()> {
+        self.transposition_table.save_hash(path)
+    }
+
+    #[inline]
+    fn load_hash(&self, path: &Path) -> Result<()> {
+        self.transposition_table.load_hash(path)
+    }
+
+    #[inline]
+    fn load_nnue_file(&mut self, path: &Path) -> Result<()> {
+        self.evaluator
+            .load_nnue_file(path, self.board.get_position())
+    }
+
+    #[inline]
+    fn load_inbuilt_nnue(&mut self) -> Result<()> {
+        self.evaluator.load_inbuilt_nnue(self.board.get_position())
+    }
+
     fn print_info(&self) {
-        print_engine_version();
-        println_wasm!();
-        self.transposition_table.print_info();
-        self.evaluator.print_info();
+        self.run_with_output_settings(|| {
+            print_engine_version();
+            println_wasm!();
+            self.transposition_table.print_info();
+            self.evaluator.print_info();
+        });
+    }
+
+    #[inline]
+    fn read_line(&self) -> Option<String> {
+        self.optional_io_reader.as_ref().map(IoReader::read_line)
     }
 
     #[inline]
@@ -289,40 +398,9 @@ impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> ChessEngine for Custo
 
     #[must_use = "If you don't need the search info, you can just search the position."]
     fn go(&mut self, config: &SearchConfig, verbose: bool) -> SearchInfo {
-        if let Some(WeightedMove { move_, weight }) = self.get_opening_book_weighted_move() {
-            return SearchInfoBuilder::new(self.board.get_position().clone(), vec![move_])
-                .set_score(weight as Score)
-                .build();
-        }
-        self.reset_variables();
-        let mut join_handles = vec![];
-        for id in 1..self.num_threads.get() {
-            let mut threaded_searcher = self.generate_searcher(id);
-            let controller = self.controller.clone();
-            let join_handle = thread::spawn(move || {
-                threaded_searcher.go(const { &SearchConfig::new_infinite() }, controller, false);
-            });
-            join_handles.push(join_handle);
-        }
-        if let Some(io_reader) = self.optional_io_reader.as_ref() {
-            let stop_command = self.stop_command.clone();
-            let reader = io_reader.clone();
-            let terminate = self.terminate.clone();
-            join_handles.push(thread::spawn(move || {
-                Self::update_stop_command(stop_command, reader, terminate);
-            }));
-        }
-        let mut main_thread_searcher = self.generate_searcher(0);
-        main_thread_searcher.go(config, self.controller.clone(), verbose);
-        self.set_stop_command(true);
-        for join_handle in join_handles {
-            join_handle.join().unwrap();
-        }
-        let mut search_info = main_thread_searcher.get_search_info();
-        if search_info.get_pv().is_empty() && self.board.status() == BoardStatus::Ongoing {
-            search_info.set_pv(&[self.board.generate_legal_moves().next().unwrap()]);
-        }
-        search_info
+        with_output_settings(self.config, self.output_sink.clone(), || {
+            self.go_helper(config, verbose)
+        })
     }
 }
 
@@ -357,6 +435,7 @@ impl<T: SearchControl<Searcher<P>>, P: PositionEvaluation> Clone for CustomEngin
             stop_command: AtomicBool::new(self.stop_command.load(MEMORY_ORDERING)).into(),
             terminate: AtomicBool::new(self.terminate.load(MEMORY_ORDERING)).into(),
             properties: self.properties.clone(),
+            output_sink: self.output_sink.clone(),
             opening_book: self.opening_book.clone(),
             ..*self
         }
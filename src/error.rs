@@ -25,6 +25,9 @@ impl From<Pyo3Error> for PyErr {
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum TimecatError {
     UnknownCommand,
+    UnknownHelpCommand {
+        command: String,
+    },
     NoInput,
     NotImplemented,
     EngineNotRunning,
@@ -53,6 +56,7 @@ pub enum TimecatError {
     WTimeNotMentioned,
     BTimeNotMentioned,
     GameAlreadyOver,
+    NoInputSource,
     UnknownDebugCommand {
         command: String,
     },
@@ -94,12 +98,27 @@ pub enum TimecatError {
     InvalidUciMoveString {
         s: String,
     },
+    #[deprecated(note = "Use InvalidPosition, which lists the issues of the position, instead.")]
     InvalidBoardPosition {
         position: BoardPosition,
     },
+    InvalidPosition {
+        fen: String,
+        issues: Vec<PositionIssue>,
+    },
     InvalidGoCommand {
         s: String,
     },
+    InvalidTimeControlString {
+        s: String,
+    },
+    UnknownVariant {
+        variant: String,
+    },
+    MoveGenerationMismatch {
+        fen: String,
+        mismatched_moves: Vec<Move>,
+    },
     IllegalSearchMoves {
         illegal_moves: Vec<Move>,
     },
@@ -109,6 +128,44 @@ pub enum TimecatError {
     BadNNUEFile,
     BadPolyglotFile,
     PolyglotTableParseError,
+    BadHashFile,
+    IncompatibleHashFile,
+    InvalidServerAddress {
+        address: String,
+    },
+    UnknownUCIOption {
+        name: String,
+    },
+    UnknownCliOption {
+        option: String,
+    },
+    UnknownSubcommand {
+        subcommand: String,
+    },
+    UnexpectedCliArgument {
+        argument: String,
+    },
+    MissingCliArgument {
+        name: String,
+    },
+    MissingCliValue {
+        option: String,
+    },
+    InvalidCliValue {
+        option: String,
+        value: String,
+    },
+    CliOptionNotApplicable {
+        option: String,
+        optional_subcommand: Option<String>,
+    },
+    UnknownProfile {
+        profile: String,
+    },
+    BadConfigFile {
+        path: PathBuf,
+        err_msg: String,
+    },
     CustomError {
         err_msg: String,
     },
@@ -126,6 +183,10 @@ impl fmt::Display for TimecatError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             UnknownCommand => write!(f, "{}", UnknownCommand.stringify_with_optional_raw_input(None)),
+            UnknownHelpCommand { command } => match CommandInfo::suggest(command) {
+                Some(command_info) => write!(f, "Unknown command {command:?}, did you mean {:?}? Type help for the list of commands!", command_info.get_name()),
+                None => write!(f, "Unknown command {command:?}! Type help for the list of commands!"),
+            },
             NoInput => write!(f, "No input! Please try again!"),
             NotImplemented => write!(f, "Sorry, this command is not implemented yet :("),
             EngineNotRunning => write!(f, "Engine is not running! Please try again!"),
@@ -141,6 +202,7 @@ impl fmt::Display for TimecatError {
             WTimeNotMentioned => write!(f, "You didn't mention wtime! Please try again!"),
             BTimeNotMentioned => write!(f, "You didn't mention btime! Please try again!"),
             GameAlreadyOver => write!(f, "Game is already over! Please start a game from another position!"),
+            NoInputSource => write!(f, "The engine has no input to read the moves from! Please try again!"),
             UnknownDebugCommand { command } => write!(f, "Debug command {command} is unknown! The possible commands are on or off! Please try again!"),
             InvalidSpinValue {name, value, min, max} => write!(f, "Cannot set value of {name} to {value}, the value must be from {min} to {max}! Please try again!"),
             InvalidMoveStructGeneration => write!(f, "The from square and to square of a move cannot be same!"),
@@ -154,13 +216,34 @@ impl fmt::Display for TimecatError {
             InvalidPieceTypeString { s } => write!(f, "Got invalid piece type string {s}! Please try again!"),
             InvalidPieceString { s } => write!(f, "Got invalid piece string {s}! Please try again!"),
             InvalidUciMoveString { s } => write!(f, "Invalid uci move string {s}! Please try again!"),
+            #[expect(deprecated)]
             InvalidBoardPosition { position } => write!(f, "Invalid position generated:\n\n{position:#?}"),
+            InvalidPosition { fen, issues } => write!(f, "Invalid position {fen}: {}! Please try again!", issues.iter().join(", ")),
             InvalidGoCommand { s } => write!(f, "Got invalid go command: {s:?}! Please try again!"),
+            InvalidTimeControlString { s } => write!(f, "Got invalid time control string {s:?}! Please try again!"),
+            UnknownVariant { variant } => write!(f, "Unknown variant {variant:?}! The variants are {}. Please try again!", ALL_VARIANTS.iter().join(", ")),
+            MoveGenerationMismatch { fen, mismatched_moves } => write!(f, "The move generators disagree on the moves {} in the position {fen}! Please try again!", mismatched_moves.iter().map(ToString::to_string).join(", ")),
             IllegalSearchMoves { illegal_moves } => write!(f, "Got illegal search moves: {}! Please try again!", illegal_moves.iter().map(ToString::to_string).join(", ")),
             FeatureNotEnabled { s } => write!(f, "The feature {s:?} is not enabled. Please recompile the chess engine with this feature enabled!"),
             BadNNUEFile => write!(f, "The NNUE file cannot be parsed properly! Try again with a different NNUE file!"),
             BadPolyglotFile => write!(f, "The Polyglot file cannot be parsed properly! Try again with a different Polyglot file!"),
             PolyglotTableParseError => write!(f, "The Polyglot Table cannot be parsed properly! Try again with a different Polyglot file!"),
+            BadHashFile => write!(f, "The hash file cannot be parsed properly! Try again with a different hash file!"),
+            IncompatibleHashFile => write!(f, "The hash file was saved by an incompatible version of the engine! Try again with a different hash file!"),
+            InvalidServerAddress { address } => write!(f, "Got invalid server address {address:?}! Expected host:port or {UNIX_SOCKET_ADDRESS_PREFIX}<path>! Please try again!"),
+            UnknownUCIOption { name } => write!(f, "Unknown UCI option {name:?}! Type uci to see all the options!"),
+            UnknownCliOption { option } => write!(f, "Unknown option {option}! Type --help for more information!"),
+            UnknownSubcommand { subcommand } => write!(f, "Unknown subcommand {subcommand:?}! Type --help for more information!"),
+            UnexpectedCliArgument { argument } => write!(f, "Unexpected argument {argument:?}! Type --help for more information!"),
+            MissingCliArgument { name } => write!(f, "The argument <{name}> is missing! Type --help for more information!"),
+            MissingCliValue { option } => write!(f, "The option {option} expects a value! Type --help for more information!"),
+            InvalidCliValue { option, value } => write!(f, "Invalid value {value:?} for the option {option}! Type --help for more information!"),
+            CliOptionNotApplicable { option, optional_subcommand } => match optional_subcommand {
+                Some(subcommand) => write!(f, "The option {option} cannot be used with the subcommand {subcommand}! Type --help for more information!"),
+                None => write!(f, "The option {option} can only be used with a subcommand! Type --help for more information!"),
+            },
+            UnknownProfile { profile } => write!(f, "Profile {profile:?} is not defined in the config file! Please try again!"),
+            BadConfigFile { path, err_msg } => write!(f, "The config file {} cannot be parsed properly: {err_msg}", path.display()),
             CustomError { err_msg } => write!(f, "{err_msg}"),
         }
     }
@@ -172,16 +255,22 @@ impl TimecatError {
     pub fn stringify_with_optional_raw_input(&self, optional_raw_input: Option<&str>) -> String {
         match self {
             Self::UnknownCommand => {
-                let command_type = if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+                let command_type = if TimecatConfig::get_current().is_in_console_mode() {
                     "Console"
                 } else {
                     "UCI"
                 };
                 match optional_raw_input {
-                    Some(raw_input) => format!(
-                        "Unknown {command_type} Command: {:?}\nType help for more information!",
-                        raw_input.trim_end_matches('\n')
-                    ),
+                    Some(raw_input) => {
+                        let raw_input = raw_input.trim_end_matches('\n');
+                        let mut message = format!("Unknown {command_type} Command: {raw_input:?}");
+                        if let Some(hint) =
+                            CommandInfo::get_hint(&Parser::sanitize_string(raw_input))
+                        {
+                            message += &format!("\n{hint}");
+                        }
+                        message + "\nType help for more information!"
+                    }
                     None => format!("Unknown {command_type} Command!\nPlease try again!"),
                 }
             }
@@ -64,8 +64,8 @@ impl EvaluatorNNUE {
         winning_side: Color,
         is_bishop_knight_endgame: bool,
     ) -> Score {
-        let winning_side_king_square = position.get_king_square(winning_side);
-        let losing_side_king_square = position.get_king_square(!winning_side);
+        let winning_side_king_square = position.get_king_square_unchecked(winning_side);
+        let losing_side_king_square = position.get_king_square_unchecked(!winning_side);
         let mut probable_least_distant_corner = None;
         if is_bishop_knight_endgame {
             let is_light_squared_bishop =
@@ -187,6 +187,10 @@ impl EvaluatorNNUE {
     }
 
     fn evaluate_raw(position: &BoardPosition, mut nnue_eval_func: impl FnMut() -> Score) -> Score {
+        // The network needs a king of each color, which some variants can do without.
+        if !ALL_COLORS.into_iter().all(|color| position.has_king(color)) {
+            return position.get_material_score();
+        }
         let knights_mask = position.get_piece_mask(Knight);
         if position.get_non_king_pieces_mask() == knights_mask && knights_mask.popcnt() < 3 {
             return 0;
@@ -270,6 +274,26 @@ impl PositionEvaluation for EvaluatorNNUE {
         self.score_cache.clear();
     }
 
+    fn load_nnue_file(&mut self, path: &Path, position: &BoardPosition) -> Result<()> {
+        let path = path.to_str().ok_or(TimecatError::BadNNUEFile)?;
+        *self = Self::from_nnue_path(path, position)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "inbuilt_nnue")]
+    fn load_inbuilt_nnue(&mut self, position: &BoardPosition) -> Result<()> {
+        *self = Self::new(position);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "inbuilt_nnue"))]
+    #[expect(unused_variables)]
+    fn load_inbuilt_nnue(&mut self, position: &BoardPosition) -> Result<()> {
+        Err(TimecatError::FeatureNotEnabled {
+            s: "inbuilt_nnue".to_string(),
+        })
+    }
+
     #[inline]
     fn print_info(&self) {
         print_cache_table_info(
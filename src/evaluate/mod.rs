@@ -56,13 +56,43 @@ impl PositionEvaluation for Evaluator {
         self.inner_evaluator.print_info()
     }
 
+    fn load_nnue_file(&mut self, path: &Path, position: &BoardPosition) -> Result<()> {
+        self.inner_evaluator.load_nnue_file(path, position)
+    }
+
+    #[cfg(feature = "inbuilt_nnue")]
+    fn load_inbuilt_nnue(&mut self, position: &BoardPosition) -> Result<()> {
+        self.inner_evaluator.load_inbuilt_nnue(position)
+    }
+
+    #[cfg(not(feature = "inbuilt_nnue"))]
+    #[expect(unused_variables)]
+    fn load_inbuilt_nnue(&mut self, position: &BoardPosition) -> Result<()> {
+        Err(TimecatError::FeatureNotEnabled {
+            s: "inbuilt_nnue".to_string(),
+        })
+    }
+
     fn evaluate_flipped(&mut self, position: &BoardPosition) -> Score {
         self.inner_evaluator.evaluate_flipped(position)
     }
 
+    fn evaluate_variant_terms(&mut self, position: &BoardPosition) -> Score {
+        self.inner_evaluator.evaluate_variant_terms(position)
+    }
+
     fn evaluate_checkmate_in(&mut self, mate_distance: Ply) -> Score {
         self.inner_evaluator.evaluate_checkmate_in(mate_distance)
     }
+
+    fn evaluate_variant_terminal_in(
+        &mut self,
+        position: &BoardPosition,
+        mate_distance: Ply,
+    ) -> Option<Score> {
+        self.inner_evaluator
+            .evaluate_variant_terminal_in(position, mate_distance)
+    }
 }
 
 impl Deref for Evaluator {
@@ -196,9 +196,13 @@ impl HalfKPModelReader {
     }
 
     pub fn to_model(&self, position: &BoardPosition) -> HalfKPModel {
+        // The features are relative to the kings, which some variants can do without.
+        if !ALL_COLORS.into_iter().all(|color| position.has_king(color)) {
+            return self.to_default_model();
+        }
         let mut halfkp_model = self.to_empty_model(
-            position.get_king_square(White),
-            position.get_king_square(Black),
+            position.get_king_square_unchecked(White),
+            position.get_king_square_unchecked(Black),
         );
         halfkp_model.update_empty_model(position);
         halfkp_model.update_last_position(position.clone());
@@ -285,8 +289,8 @@ impl HalfKPModel {
     pub fn reset_model(&mut self, position: &BoardPosition) {
         self.clear();
         self.accumulator.king_squares_rotated = [
-            position.get_king_square(White),
-            position.get_king_square(Black).rotate(),
+            position.get_king_square_unchecked(White),
+            position.get_king_square_unchecked(Black).rotate(),
         ];
         self.update_empty_model(position);
         self.update_last_position(position.clone());
@@ -294,9 +298,9 @@ impl HalfKPModel {
 
     fn update_king(&mut self, position: &BoardPosition, color: Color) {
         let new_king_square = if color == White {
-            position.get_king_square(color)
+            position.get_king_square_unchecked(color)
         } else {
-            position.get_king_square(color).rotate()
+            position.get_king_square_unchecked(color).rotate()
         };
         self.accumulator.king_squares_rotated[color.to_index()] = new_king_square;
         self.clear_one_side(color);
@@ -306,11 +310,15 @@ impl HalfKPModel {
     pub fn update_model(&mut self, position: &BoardPosition) {
         let mut white_king_updated = false;
         let mut black_king_updated = false;
-        if self.last_position.get_king_square(White) != position.get_king_square(White) {
+        if self.last_position.get_king_square_unchecked(White)
+            != position.get_king_square_unchecked(White)
+        {
             self.update_king(position, White);
             white_king_updated = true;
         }
-        if self.last_position.get_king_square(Black) != position.get_king_square(Black) {
+        if self.last_position.get_king_square_unchecked(Black)
+            != position.get_king_square_unchecked(Black)
+        {
             self.update_king(position, Black);
             black_king_updated = true;
         }
@@ -414,8 +422,8 @@ impl HalfKPModel {
                     self.transformer.get_biases().clone(),
                 ],
                 king_squares_rotated: [
-                    position.get_king_square(White),
-                    position.get_king_square(Black).rotate(),
+                    position.get_king_square_unchecked(White),
+                    position.get_king_square_unchecked(Black).rotate(),
                 ],
             },
             last_position: position.clone(),
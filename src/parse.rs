@@ -24,18 +24,34 @@ pub enum UserCommand {
     UCINewGame,
     IsReady,
     Stop,
-    Help,
+    Help(Option<String>),
     Perft(Depth),
+    PerftCrossCheck(Depth),
+    Bench(Depth),
     Go(SearchConfig),
     PushMoves(String),
     PopMoves(u16),
     SetFen(String),
+    LoadPgn(String),
     #[cfg(feature = "colored")]
     SetColor(bool),
     SetUCIOption {
         user_input: String,
     },
     SelfPlay(SearchConfig),
+    SelfPlayWithTimeControl(TimeControl),
+    Play {
+        human_color: Color,
+        optional_time_control: Option<TimeControl>,
+        optional_pgn_path: Option<String>,
+    },
+    SaveHash(String),
+    LoadHash(String),
+    ProbeBook(String),
+    SaveOptions {
+        optional_profile: Option<String>,
+        optional_path: Option<String>,
+    },
     // SetHashSize(u64),
     // SetThreads(u8),
     // SetMultiPV(u8),
@@ -64,25 +80,72 @@ impl UserCommand {
         }
     }
 
-    pub fn generate_help_message() -> String {
-        "Sadly, the help message is till now not implemented. But type uci to go into the uci mode and visit the link \"https://backscattering.de/chess/uci/\" to know the necessary commands required to use an uci chess engine.".colorize(ERROR_MESSAGE_STYLE)
+    /// Lists every command of [`COMMAND_REGISTRY`], or describes a single one in detail.
+    pub fn generate_help_message(optional_command_name: Option<&str>) -> String {
+        match optional_command_name.and_then(CommandInfo::find) {
+            Some(command_info) => command_info.get_details(),
+            None => {
+                let width = COMMAND_REGISTRY
+                    .iter()
+                    .map(|command_info| command_info.name.len())
+                    .max()
+                    .unwrap_or_default()
+                    + 2;
+                let mut help = "Commands:\n".to_string();
+                for command_info in COMMAND_REGISTRY {
+                    help += &format!("  {:width$}{}\n", command_info.name, command_info.summary);
+                }
+                help += "\nType help <COMMAND> for the syntax and arguments of a command.";
+                help
+            }
+        }
     }
 
+    /// Runs the command with the output settings and the output sink of the engine.
     pub fn run_command<T: ChessEngine>(
         &self,
         engine: &mut T,
         uci_state_manager: &UCIStateManager<T>,
+    ) -> Result<()> {
+        with_output_settings(
+            *engine.get_config(),
+            engine.get_output_sink().clone(),
+            || self.run_command_helper(engine, uci_state_manager),
+        )
+    }
+
+    fn run_command_helper<T: ChessEngine>(
+        &self,
+        engine: &mut T,
+        uci_state_manager: &UCIStateManager<T>,
     ) -> Result<()> {
         match self {
             Self::TerminateEngine => engine.set_termination(true),
             Self::EngineVersion => print_engine_version(),
             #[cfg(feature = "debug")]
             Self::RunTest => test.run_and_print_time(engine)?,
-            &Self::ChangeToUCIMode { verbose } => GLOBAL_TIMECAT_STATE.set_uci_mode(true, verbose),
+            &Self::ChangeToUCIMode { verbose } => {
+                engine.get_config_mut().set_uci_mode(true);
+                if verbose {
+                    engine
+                        .get_config()
+                        .apply(|| force_println_info("UCI mode is set to", true));
+                }
+            }
             &Self::ChangeToConsoleMode { verbose } => {
-                GLOBAL_TIMECAT_STATE.set_console_mode(true, verbose)
+                engine.get_config_mut().set_console_mode(true);
+                if verbose {
+                    engine
+                        .get_config()
+                        .apply(|| force_println_info("Console mode is set to", true));
+                }
+            }
+            &Self::SetDebugMode(b) => {
+                engine.get_config_mut().set_debug_mode(b);
+                engine
+                    .get_config()
+                    .apply(|| print_uci_info("Debug Mode is set to", b));
             }
-            &Self::SetDebugMode(b) => GLOBAL_TIMECAT_STATE.set_debug_mode(b),
             Self::PrintText(s) => println_wasm!("{s}"),
             Self::DisplayBoard => println_wasm!("{}", engine.get_board()),
             #[cfg(feature = "inbuilt_nnue")]
@@ -105,12 +168,17 @@ impl UserCommand {
             }
             Self::IsReady => println_wasm!("{}", "readyok".colorize(SUCCESS_MESSAGE_STYLE)),
             Self::Stop => {
-                if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+                if TimecatConfig::get_current().is_in_console_mode() {
                     return Err(EngineNotRunning);
                 }
             }
-            Self::Help => println_wasm!("{}", Self::generate_help_message()),
+            Self::Help(optional_command_name) => println_wasm!(
+                "{}",
+                Self::generate_help_message(optional_command_name.as_deref())
+            ),
             &Self::Perft(depth) => GoAndPerft::run_perft_command(engine, depth)?,
+            &Self::PerftCrossCheck(depth) => GoAndPerft::run_perft_cross_check(engine, depth)?,
+            &Self::Bench(depth) => GoAndPerft::run_bench(engine, depth)?,
             Self::Go(config) => GoAndPerft::run_search(engine, config)?,
             Self::PushMoves(user_input) => {
                 let binding = Parser::sanitize_string(user_input);
@@ -118,12 +186,78 @@ impl UserCommand {
             }
             &Self::PopMoves(num_moves) => Pop::pop_moves(engine, num_moves)?,
             Self::SetFen(fen) => Set::set_board_fen(engine, fen)?,
+            Self::LoadPgn(pgn) => Set::load_pgn(engine, pgn)?,
             #[cfg(feature = "colored")]
-            &Self::SetColor(b) => Set::set_color(b)?,
+            &Self::SetColor(b) => Set::set_color(engine, b)?,
             Self::SetUCIOption { user_input } => {
                 uci_state_manager.run_command(engine, user_input)?
             }
             Self::SelfPlay(config) => self_play(engine, config, true, None)?,
+            Self::SelfPlayWithTimeControl(time_control) => {
+                self_play_with_time_control(engine, time_control, true, None)?
+            }
+            Self::Play {
+                human_color,
+                optional_time_control,
+                optional_pgn_path,
+            } => play(
+                engine,
+                *human_color,
+                optional_time_control.as_ref(),
+                optional_pgn_path.as_deref().map(Path::new),
+            )?,
+            Self::SaveHash(path) => {
+                engine.save_hash(Path::new(path))?;
+                force_println_info("Hash table saved to", path);
+            }
+            Self::LoadHash(path) => {
+                engine.load_hash(Path::new(path))?;
+                force_println_info("Hash table loaded from", path);
+            }
+            Self::ProbeBook(path) => {
+                let board = engine.get_board();
+                let mut weighted_moves =
+                    PolyglotBookReader::from_file_path(path)?.get_all_weighted_moves(board)?;
+                weighted_moves.sort_unstable_by_key(|weighted_move| Reverse(weighted_move.weight));
+                if weighted_moves.is_empty() {
+                    force_println_info("Book Moves", "None");
+                }
+                for WeightedMove { move_, weight } in weighted_moves {
+                    force_println_info(
+                        &move_
+                            .stringify_move(board.get_position())
+                            .unwrap_or_else(|_| move_.to_string()),
+                        weight,
+                    );
+                }
+            }
+            #[cfg(feature = "config_file")]
+            Self::SaveOptions {
+                optional_profile,
+                optional_path,
+            } => {
+                let path = optional_path
+                    .as_ref()
+                    .map(PathBuf::from)
+                    .or_else(|| {
+                        uci_state_manager
+                            .get_config_file_path()
+                            .map(Path::to_path_buf)
+                    })
+                    .or_else(EngineConfigFile::get_default_path)
+                    .ok_or(TimecatError::CustomError {
+                        err_msg: "Could not find the config directory! Please mention a path!"
+                            .to_string(),
+                    })?;
+                uci_state_manager.save_options(&path, optional_profile.as_deref())?;
+                force_println_info("Options saved to", path.display());
+            }
+            #[cfg(not(feature = "config_file"))]
+            Self::SaveOptions { .. } => {
+                return Err(FeatureNotEnabled {
+                    s: "config_file".to_string(),
+                })
+            }
         }
 
         Ok(())
@@ -140,20 +274,277 @@ impl From<UserCommand> for Result<Vec<UserCommand>> {
     }
 }
 
+/// Syntax and description of a console or UCI command, which power `help`, completions and the suggestions for
+/// mistyped commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommandInfo {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    usages: &'static [&'static str],
+    arguments: &'static [(&'static str, &'static str)],
+    summary: &'static str,
+}
+
+impl CommandInfo {
+    pub const fn new(
+        name: &'static str,
+        aliases: &'static [&'static str],
+        usages: &'static [&'static str],
+        arguments: &'static [(&'static str, &'static str)],
+        summary: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            aliases,
+            usages,
+            arguments,
+            summary,
+        }
+    }
+
+    #[inline]
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline]
+    pub fn get_aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    #[inline]
+    pub fn get_usages(&self) -> &'static [&'static str] {
+        self.usages
+    }
+
+    #[inline]
+    pub fn get_arguments(&self) -> &'static [(&'static str, &'static str)] {
+        self.arguments
+    }
+
+    #[inline]
+    pub fn get_summary(&self) -> &'static str {
+        self.summary
+    }
+
+    fn get_names(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.name).chain(self.aliases.iter().copied())
+    }
+
+    /// Finds the command by its name or one of its aliases, ignoring case.
+    pub fn find(name: &str) -> Option<&'static Self> {
+        let name = name.to_lowercase();
+        COMMAND_REGISTRY
+            .iter()
+            .find(|command_info| command_info.get_names().any(|other| other == name))
+    }
+
+    /// Finds the command whose name or alias is closest to a mistyped one, if any is close enough.
+    pub fn suggest(name: &str) -> Option<&'static Self> {
+        let name = &name.to_lowercase();
+        COMMAND_REGISTRY
+            .iter()
+            .flat_map(|command_info| {
+                command_info
+                    .get_names()
+                    .map(move |other| (get_edit_distance(name, other), command_info))
+            })
+            .filter(|&(distance, _)| distance <= MAX_COMMAND_SUGGESTION_DISTANCE)
+            .filter(|&(distance, _)| distance < name.chars().count())
+            .min_by_key(|&(distance, _)| distance)
+            .map(|(_, command_info)| command_info)
+    }
+
+    /// The names and aliases of all the commands starting with the prefix, sorted for completion.
+    pub fn get_completions(prefix: &str) -> Vec<&'static str> {
+        let prefix = prefix.to_lowercase();
+        COMMAND_REGISTRY
+            .iter()
+            .flat_map(Self::get_names)
+            .filter(|name| name.starts_with(&prefix))
+            .sorted_unstable()
+            .dedup()
+            .collect()
+    }
+
+    /// The hint shown after an unknown command, which is its syntax if the command exists but its arguments are wrong,
+    /// or the closest command otherwise.
+    pub fn get_hint(raw_input: &str) -> Option<String> {
+        let first_word = raw_input.split_whitespace().next()?;
+        if let Some(command_info) = Self::find(first_word) {
+            return Some(format!(
+                "Usage: {}",
+                command_info.usages.join(&format!("\n{}", " ".repeat(7)))
+            ));
+        }
+        Self::suggest(first_word)
+            .map(|command_info| format!("Did you mean {:?}?", command_info.name))
+    }
+
+    pub fn get_details(&self) -> String {
+        let mut details = format!(
+            "Usage: {}\n\n{}",
+            self.usages.join(&format!("\n{}", " ".repeat(7))),
+            self.summary,
+        );
+        if !self.aliases.is_empty() {
+            details += &format!("\n\nAliases: {}", self.aliases.join(", "));
+        }
+        if !self.arguments.is_empty() {
+            let width = self
+                .arguments
+                .iter()
+                .map(|(argument, _)| argument.len())
+                .max()
+                .unwrap_or_default()
+                + 2;
+            details += "\n\nArguments:";
+            for (argument, description) in self.arguments {
+                details += &format!("\n  {argument:width$}{description}");
+            }
+        }
+        details
+    }
+}
+
+/// Every command understood by [`Parser`], in the order `help` lists them.
+#[rustfmt::skip]
+pub const COMMAND_REGISTRY: &[CommandInfo] = &[
+    CommandInfo::new("go", &[], &[
+        "go [depth <DEPTH>] [nodes <NODES>] [mate <MOVES>] [movetime <MS>]",
+        "go [wtime <MS>] [btime <MS>] [winc <MS>] [binc <MS>] [movestogo <MOVES>]",
+        "go infinite",
+        "go ponder",
+        "go perft <DEPTH> [check]",
+    ], &[
+        ("depth <DEPTH>", "Searches up to the depth in plies."),
+        ("nodes <NODES>", "Stops after searching the number of nodes."),
+        ("mate <MOVES>", "Searches for a mate in the number of moves."),
+        ("movetime <MS>", "Searches for exactly the time in milliseconds."),
+        ("wtime, btime <MS>", "Remaining clock time of white and black in milliseconds."),
+        ("winc, binc <MS>", "Increment per move of white and black in milliseconds."),
+        ("movestogo <MOVES>", "Number of moves until the next time control."),
+        ("searchmoves <MOVE>...", "Restricts the search to the moves, given last."),
+        ("infinite", "Searches until stop is sent."),
+        ("perft <DEPTH>", "Counts the leaf nodes of the move tree instead of searching."),
+        ("check", "Checks the pseudo-legal move generator against the legal one while counting."),
+    ], "Searches the current position and prints the best move."),
+    CommandInfo::new("perft", &[], &["perft <DEPTH> [check]"], &[
+        ("<DEPTH>", "Depth of the move tree in plies."),
+        ("check", "Checks the pseudo-legal move generator against the legal one in every node."),
+    ], "Counts the leaf nodes of the move tree of the current position, same as go perft."),
+    CommandInfo::new("stop", &[], &["stop"], &[], "Stops the running search."),
+    CommandInfo::new("push", &[], &[
+        "push san <MOVE>...",
+        "push lan <MOVE>...",
+        "push uci <MOVE>...",
+        "push moves <MOVE>...",
+    ], &[
+        ("san", "Moves in standard algebraic notation, like Nf3."),
+        ("lan", "Moves in long algebraic notation, like Ng1-f3."),
+        ("uci", "Moves in UCI notation, like g1f3."),
+        ("moves", "Moves in any of the above notations."),
+    ], "Plays the moves on the board."),
+    CommandInfo::new("pop", &[], &["pop [<COUNT>]"], &[
+        ("<COUNT>", "Number of moves to take back, 1 by default."),
+    ], "Takes back the last moves."),
+    CommandInfo::new("set", &[], &[
+        "set board fen <FEN>",
+        "set board startpos",
+        "set color <true|false>",
+    ], &[
+        ("board fen <FEN>", "Sets up the position from the FEN."),
+        ("board startpos", "Sets up the starting position."),
+        ("color <true|false>", "Turns colored output on or off."),
+    ], "Changes the board or a console setting."),
+    CommandInfo::new("position", &[], &[
+        "position startpos [moves <MOVE>...]",
+        "position fen <FEN> [moves <MOVE>...]",
+    ], &[
+        ("startpos", "Starts from the starting position."),
+        ("fen <FEN>", "Starts from the position of the FEN."),
+        ("moves <MOVE>...", "Moves in UCI notation to play from there."),
+    ], "Sets up the position, as sent by UCI GUIs."),
+    CommandInfo::new("reset", &[], &["reset board"], &[], "Resets the board to the starting position."),
+    CommandInfo::new("display", &["d"], &["display"], &[], "Displays the current board."),
+    CommandInfo::new("eval", &[], &["eval"], &[], "Prints the static evaluation of the current position."),
+    CommandInfo::new("selfplay", &[], &[
+        "selfplay [<GO_ARGUMENTS>]",
+        "selfplay tc <TIME_CONTROL>",
+    ], &[
+        ("<GO_ARGUMENTS>", "Limits of every search, like those of go."),
+        ("tc <TIME_CONTROL>", "Plays with a clock, like 40/300+2 or 60+1."),
+    ], "Lets the engine play against itself from the current position."),
+    CommandInfo::new("play", &[], &["play [white|black] [<TIME_CONTROL>] [save [<PATH>]]"], &[
+        ("white, black", "Color you play, white by default."),
+        ("<TIME_CONTROL>", "Clock of both sides, like 40/300+2 or 60+1. Without it, the engine thinks 3 seconds a move."),
+        ("save [<PATH>]", "Saves the PGN of the game to the path, or to a file named after the time in the current directory."),
+    ], "Plays a game against the engine from the current position, with undo, hint, resign and draw claims, printing its PGN at the end."),
+    CommandInfo::new("setoption", &[], &["setoption name <NAME> [value <VALUE>]"], &[
+        ("name <NAME>", "Name of the UCI option, listed by uci."),
+        ("value <VALUE>", "New value of the option, left out for buttons."),
+    ], "Sets a UCI option."),
+    CommandInfo::new("saveoptions", &["save_options"], &["saveoptions [profile <NAME>] [<PATH>]"], &[
+        ("profile <NAME>", "Saves the options into the profile instead of the common options."),
+        ("<PATH>", "Config file to write, the one loaded at startup by default."),
+    ], "Saves the UCI options set so far to a config file."),
+    CommandInfo::new("savehash", &["save_hash"], &["savehash <PATH>"], &[
+        ("<PATH>", "File to write the transposition table to."),
+    ], "Saves the transposition table to a file."),
+    CommandInfo::new("loadhash", &["load_hash"], &["loadhash <PATH>"], &[
+        ("<PATH>", "File written by savehash."),
+    ], "Loads the transposition table from a file."),
+    CommandInfo::new("uci", &[], &["uci"], &[], "Switches to UCI mode and prints the engine info and options."),
+    CommandInfo::new("ucinewgame", &[], &["ucinewgame"], &[], "Clears the hash and resets the board for a new game."),
+    CommandInfo::new("isready", &[], &["isready"], &[], "Prints readyok once the engine is ready."),
+    CommandInfo::new("debug", &[], &["debug <on|off>"], &[], "Turns the debug mode on or off."),
+    CommandInfo::new("ucimode", &[], &["ucimode"], &[], "Switches to UCI mode."),
+    CommandInfo::new("console", &["consolemode"], &["console"], &[], "Switches to console mode."),
+    CommandInfo::new("help", &[], &["help [<COMMAND>]"], &[
+        ("<COMMAND>", "Command to describe in detail."),
+    ], "Lists the commands or describes one of them."),
+    CommandInfo::new("quit", &["q", "exit"], &["quit"], &[], "Quits the engine."),
+];
+
 struct GoAndPerft;
 
 impl GoAndPerft {
     pub fn parse_sub_commands(commands: &[&str]) -> Result<Vec<UserCommand>> {
         let second_command = commands.get(1).ok_or(UnknownCommand)?.to_lowercase();
         if second_command == "perft" {
-            UserCommand::Perft(commands.get(2).ok_or(UnknownCommand)?.parse()?).into()
+            Self::parse_perft(&commands[2..])
         } else {
             UserCommand::Go(SearchConfig::try_from(commands)?).into()
         }
     }
 
+    /// Parses `<DEPTH> [check]`, the arguments of both `perft` and `go perft`.
+    pub fn parse_perft(arguments: &[&str]) -> Result<Vec<UserCommand>> {
+        let depth = arguments.first().ok_or(UnknownCommand)?.parse()?;
+        match &arguments[1..] {
+            [] => UserCommand::Perft(depth).into(),
+            [mode] if mode.eq_ignore_ascii_case("check") => {
+                UserCommand::PerftCrossCheck(depth).into()
+            }
+            _ => Err(UnknownCommand),
+        }
+    }
+
+    fn run_perft_cross_check(engine: &mut impl ChessEngine, depth: Depth) -> Result<()> {
+        let clock = Instant::now();
+        let position_count = MoveGenerator::perft_cross_check(
+            engine.get_board().get_position(),
+            depth.max(1) as usize,
+        )?;
+        println_wasm!();
+        force_println_info("Position Count", position_count);
+        force_println_info("Time", clock.elapsed().stringify());
+        force_println_info("Move Generators", "Consistent");
+        Ok(())
+    }
+
     fn run_perft_command(engine: &mut impl ChessEngine, depth: Depth) -> Result<()> {
-        if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+        if TimecatConfig::get_current().is_in_console_mode() {
             println_wasm!("{}\n", engine.get_board());
         }
         let clock = Instant::now();
@@ -170,6 +561,38 @@ impl GoAndPerft {
         Ok(())
     }
 
+    /// Searches every bench position to the depth from an empty hash table, printing the total nodes and speed. The
+    /// node count is deterministic with one thread, so it doubles as a signature of the search.
+    fn run_bench(engine: &mut impl ChessEngine, depth: Depth) -> Result<()> {
+        let config = SearchConfig::new_depth(depth);
+        let mut total_nodes = 0;
+        let mut total_time = Duration::ZERO;
+        for (index, fen) in BENCH_FENS.iter().enumerate() {
+            engine.set_fen(fen)?;
+            engine.clear_hash();
+            let search_info = engine.go_quiet(&config);
+            let nodes = search_info.get_num_nodes_searched().unwrap_or_default();
+            total_nodes += nodes;
+            total_time += search_info.get_time_elapsed().unwrap_or_default();
+            force_println_info(
+                &format!("Position {}/{}", index + 1, BENCH_FENS.len()),
+                format!("{fen} ({nodes} nodes)"),
+            );
+        }
+        engine.set_fen(STARTING_POSITION_FEN)?;
+        println_wasm!();
+        force_println_info("Total Nodes", total_nodes);
+        force_println_info("Total Time", total_time.stringify());
+        force_println_info(
+            "Speed",
+            format!(
+                "{} nodes/sec",
+                (total_nodes as u128 * 10u128.pow(9)) / total_time.as_nanos().max(1)
+            ),
+        );
+        Ok(())
+    }
+
     fn run_search(engine: &mut impl ChessEngine, config: &SearchConfig) -> Result<()> {
         if let Some(moves_to_search) = config.get_moves_to_search() {
             let legal_moves = engine.get_board().generate_legal_moves();
@@ -186,7 +609,7 @@ impl GoAndPerft {
                 });
             }
         }
-        if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+        if TimecatConfig::get_current().is_in_console_mode() {
             println_wasm!("{}\n", engine.get_board());
         }
         let clock = Instant::now();
@@ -196,7 +619,7 @@ impl GoAndPerft {
         })?;
         let elapsed_time = clock.elapsed();
         let pv_string = get_pv_string(engine.get_board().get_position(), response.get_pv());
-        if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+        if TimecatConfig::get_current().is_in_console_mode() {
             println_wasm!();
         }
         println_info("Score", response.get_score().stringify());
@@ -216,7 +639,7 @@ impl GoAndPerft {
             }),
         );
         println_info("Speed", nps);
-        if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+        if TimecatConfig::get_current().is_in_console_mode() {
             println_info(
                 "Best Move",
                 best_move
@@ -289,19 +712,38 @@ impl Set {
     }
 
     fn set_board_fen(engine: &mut impl ChessEngine, fen: &str) -> Result<()> {
+        // The starting position is the one of the variant being played.
+        let fen = if fen == STARTING_POSITION_FEN {
+            engine.get_board().get_variant().get_starting_fen()
+        } else {
+            fen
+        };
         engine.set_fen(fen)?;
-        if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+        if TimecatConfig::get_current().is_in_console_mode() {
             println_wasm!("{}", engine.get_board());
         }
         Ok(())
     }
 
+    fn load_pgn(engine: &mut impl ChessEngine, pgn: &str) -> Result<()> {
+        let board = Board::from_pgn(pgn)?;
+        engine.set_fen(&board.get_starting_board_fen())?;
+        for valid_or_null_move in board.get_all_stack_moves() {
+            engine.get_board_mut().push(valid_or_null_move)?;
+        }
+        println_info("PGN loaded with moves", board.get_ply());
+        Ok(())
+    }
+
     #[cfg(feature = "colored")]
-    fn set_color(b: bool) -> Result<()> {
-        if GLOBAL_TIMECAT_STATE.is_colored_output() == b {
+    fn set_color(engine: &mut impl ChessEngine, b: bool) -> Result<()> {
+        if engine.get_config().is_colored_output() == b {
             return Err(ColoredOutputUnchanged { b });
         }
-        GLOBAL_TIMECAT_STATE.set_colored_output(b, true);
+        engine.get_config_mut().set_colored_output(b);
+        engine
+            .get_config()
+            .apply(|| print_uci_info("Colored output is set to", b));
         Ok(())
     }
 }
@@ -359,6 +801,11 @@ struct SelfPlay;
 
 impl SelfPlay {
     fn parse_sub_commands(commands: &[&str]) -> Result<Vec<UserCommand>> {
+        if let Some(&"tc" | &"timecontrol") = commands.get(1) {
+            // The time control periods are separated by spaces after sanitization.
+            let time_control = commands[2..].join(":").parse()?;
+            return UserCommand::SelfPlayWithTimeControl(time_control).into();
+        }
         let mut commands = commands.to_vec();
         commands[0] = "go";
         let config = if commands.get(1).is_some() {
@@ -370,6 +817,64 @@ impl SelfPlay {
     }
 }
 
+struct Play;
+
+impl Play {
+    /// The path after `save` in the raw input, as sanitizing it mangles the paths, or a new file in the current
+    /// directory named after the time.
+    fn get_pgn_path(raw_input: &str) -> String {
+        let mut rest = raw_input;
+        while let Some((word, after_word)) = Parser::split_first_word(rest) {
+            rest = after_word;
+            if word.eq_ignore_ascii_case("save") {
+                break;
+            }
+        }
+        if !rest.is_empty() {
+            return rest.to_string();
+        }
+        format!(
+            "{PLAY_PGN_FILE_PREFIX}{}.pgn",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs()
+        )
+    }
+
+    /// Parses `play [white|black] [<TIME_CONTROL>] [save [<PATH>]]`.
+    fn parse_sub_commands(commands: &[&str], raw_input: &str) -> Result<Vec<UserCommand>> {
+        let save_index = commands
+            .iter()
+            .position(|command| command.eq_ignore_ascii_case("save"));
+        let optional_pgn_path = save_index.map(|_| Self::get_pgn_path(raw_input));
+        let mut commands = &commands[1..save_index.unwrap_or(commands.len())];
+        let human_color = match commands.first().map(|s| s.to_lowercase()).as_deref() {
+            Some("white" | "w") => {
+                commands = &commands[1..];
+                White
+            }
+            Some("black" | "b") => {
+                commands = &commands[1..];
+                Black
+            }
+            _ => White,
+        };
+        // The time control periods are separated by spaces after sanitization.
+        let optional_time_control = if commands.is_empty() {
+            None
+        } else {
+            Some(commands.join(":").parse()?)
+        };
+        UserCommand::Play {
+            human_color,
+            optional_time_control,
+            optional_pgn_path,
+        }
+        .into()
+    }
+}
+
 struct DebugMode;
 
 impl DebugMode {
@@ -441,7 +946,66 @@ impl Parser {
         user_input
     }
 
-    fn parse_single_command(single_input: &str) -> Result<Vec<UserCommand>> {
+    /// Splits the first word off the input, returning it along with the rest of the input.
+    fn split_first_word(input: &str) -> Option<(&str, &str)> {
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+        Some(
+            input
+                .split_once(char::is_whitespace)
+                .map_or((input, ""), |(word, rest)| (word, rest.trim_start())),
+        )
+    }
+
+    /// The paths are taken from the raw input, as sanitizing it mangles the paths containing `,` or `:`.
+    fn get_path_argument(raw_input: &str) -> Result<String> {
+        match Self::split_first_word(raw_input) {
+            Some((_, path)) if !path.is_empty() => Ok(path.to_string()),
+            _ => Err(UnknownCommand),
+        }
+    }
+
+    /// Parses `saveoptions [profile <name>] [<path>]`.
+    fn parse_save_options(raw_input: &str) -> Result<Vec<UserCommand>> {
+        let (_, mut rest) = Self::split_first_word(raw_input).ok_or(UnknownCommand)?;
+        let mut optional_profile = None;
+        if let Some((_, after_profile)) = Self::split_first_word(rest)
+            .filter(|(command, _)| command.eq_ignore_ascii_case("profile"))
+        {
+            let (profile, after_name) =
+                Self::split_first_word(after_profile).ok_or(UnknownCommand)?;
+            optional_profile = Some(profile.to_string());
+            rest = after_name;
+        }
+        UserCommand::SaveOptions {
+            optional_profile,
+            optional_path: (!rest.is_empty()).then(|| rest.to_string()),
+        }
+        .into()
+    }
+
+    fn parse_help(commands: &[&str]) -> Result<Vec<UserCommand>> {
+        let command_name = commands[1..].join(" ");
+        let command_info = CommandInfo::find(&command_name).ok_or(UnknownHelpCommand {
+            command: command_name,
+        })?;
+        UserCommand::Help(Some(command_info.get_name().to_string())).into()
+    }
+
+    /// The names of the commands which complete the partial input, for tab-completion in a line editor.
+    pub fn get_completions(partial_input: &str) -> Vec<&'static str> {
+        if partial_input.contains(char::is_whitespace) {
+            return vec![];
+        }
+        CommandInfo::get_completions(partial_input)
+    }
+
+    fn parse_single_command(
+        single_input: &str,
+        raw_single_input: &str,
+    ) -> Result<Vec<UserCommand>> {
         match single_input.to_lowercase().as_str() {
             "q" | "quit" | "quit()" | "quit(0)" | "exit" | "exit()" | "exit(0)" => {
                 UserCommand::TerminateEngine.into()
@@ -455,21 +1019,21 @@ impl Parser {
                 },
             ]),
             "ucimode" => {
-                if GLOBAL_TIMECAT_STATE.is_in_uci_mode() {
+                if TimecatConfig::get_current().is_in_uci_mode() {
                     Err(UCIModeUnchanged)
                 } else {
                     UserCommand::ChangeToUCIMode { verbose: true }.into()
                 }
             }
             "console" | "consolemode" => {
-                if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+                if TimecatConfig::get_current().is_in_console_mode() {
                     Err(ConsoleModeUnchanged)
                 } else {
                     UserCommand::ChangeToConsoleMode { verbose: true }.into()
                 }
             }
             "isready" => UserCommand::IsReady.into(),
-            "d" => UserCommand::DisplayBoard.into(),
+            "d" | "display" => UserCommand::DisplayBoard.into(),
             #[cfg(feature = "inbuilt_nnue")]
             "eval" => UserCommand::DisplayBoardEvaluation.into(),
             #[cfg(not(feature = "inbuilt_nnue"))]
@@ -478,12 +1042,19 @@ impl Parser {
             }),
             "reset board" => UserCommand::SetFen(STARTING_POSITION_FEN.to_owned()).into(),
             "stop" => UserCommand::Stop.into(),
-            "help" => UserCommand::Help.into(),
+            "help" => UserCommand::Help(None).into(),
+            "saveoptions" | "save_options" => UserCommand::SaveOptions {
+                optional_profile: None,
+                optional_path: None,
+            }
+            .into(),
             _ => {
                 let commands = single_input.split_whitespace().collect_vec();
                 let first_command = commands.first().ok_or(UnknownCommand)?.to_lowercase();
                 match first_command.as_str() {
                     "go" => GoAndPerft::parse_sub_commands(&commands),
+                    "perft" => GoAndPerft::parse_perft(&commands[1..]),
+                    "help" => Self::parse_help(&commands),
                     "set" => Set::parse_sub_commands(&commands),
                     "setoption" => UserCommand::SetUCIOption {
                         user_input: single_input.to_string(),
@@ -493,7 +1064,15 @@ impl Parser {
                     "pop" => Pop::parse_sub_commands(&commands),
                     "position" => Position::parse_sub_commands(&commands),
                     "selfplay" => SelfPlay::parse_sub_commands(&commands),
+                    "play" => Play::parse_sub_commands(&commands, raw_single_input),
                     "debug" => DebugMode::parse_sub_commands(&commands),
+                    "save_hash" | "savehash" => {
+                        UserCommand::SaveHash(Self::get_path_argument(raw_single_input)?).into()
+                    }
+                    "load_hash" | "loadhash" => {
+                        UserCommand::LoadHash(Self::get_path_argument(raw_single_input)?).into()
+                    }
+                    "save_options" | "saveoptions" => Self::parse_save_options(raw_single_input),
                     _ => Err(UnknownCommand),
                 }
             }
@@ -510,10 +1089,13 @@ impl Parser {
         if raw_input.trim().is_empty() {
             return Err(NoInput);
         }
-        Self::sanitize_string(raw_input)
+        raw_input
             .split("&&")
-            .try_fold(Vec::new(), |mut vec, input| {
-                vec.extend(Self::parse_single_command(input.trim())?);
+            .try_fold(Vec::new(), |mut vec, raw_single_input| {
+                vec.extend(Self::parse_single_command(
+                    &Self::sanitize_string(raw_single_input),
+                    raw_single_input,
+                )?);
                 Ok(vec)
             })
     }
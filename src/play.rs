@@ -0,0 +1,340 @@
+use super::*;
+
+const GAME_COMMANDS_HELP: &str = "\
+Enter a move in SAN or UCI notation, like Nf3 or g1f3, or one of the following commands:
+  undo     Takes back your last move and the reply of the engine.
+  hint     Suggests a move.
+  draw     Claims a draw by threefold repetition or by the fifty move rule.
+  resign   Resigns the game.
+  display  Displays the board.
+  abort    Ends the game without a result.
+  quit     Ends the game without a result and quits the engine.";
+
+struct GameEnding {
+    result: GameResult,
+    termination: String,
+}
+
+impl GameEnding {
+    fn new(result: GameResult, termination: impl Into<String>) -> Self {
+        Self {
+            result,
+            termination: termination.into(),
+        }
+    }
+
+    /// The endings which need no claim: checkmate, stalemate, the rules of the variant, insufficient material and the
+    /// fivefold repetition and seventy-five move rules.
+    fn get_automatic(board: &Board) -> Option<Self> {
+        match board.status() {
+            BoardStatus::Checkmate => {
+                return Some(Self::new(GameResult::Win(!board.turn()), "checkmate"))
+            }
+            BoardStatus::Stalemate => return Some(Self::new(GameResult::Draw, "stalemate")),
+            BoardStatus::VariantWin(color) => {
+                return Some(Self::new(GameResult::Win(color), "variant rules"))
+            }
+            BoardStatus::VariantDraw => return Some(Self::new(GameResult::Draw, "variant rules")),
+            BoardStatus::Ongoing => (),
+        }
+        let termination = if board.is_insufficient_material() {
+            "insufficient material"
+        } else if board.is_repetition(AUTOMATIC_DRAW_REPETITIONS) {
+            "fivefold repetition"
+        } else if board.get_halfmove_clock() >= AUTOMATIC_DRAW_HALFMOVE_CLOCK {
+            "seventy-five move rule"
+        } else {
+            return None;
+        };
+        Some(Self::new(GameResult::Draw, termination))
+    }
+
+    fn get_claimable_draw(board: &Board) -> Option<Self> {
+        if board.is_threefold_repetition() {
+            Some(Self::new(GameResult::Draw, "threefold repetition"))
+        } else if board.is_fifty_moves() {
+            Some(Self::new(GameResult::Draw, "fifty move rule"))
+        } else {
+            None
+        }
+    }
+}
+
+struct ConsoleGame<'a, E: ChessEngine> {
+    engine: &'a mut E,
+    human_color: Color,
+    game_clock: Option<GameClock>,
+    /// The clocks before every move of the game, restored when the moves are taken back.
+    clock_history: Vec<GameClock>,
+    starting_ply: usize,
+}
+
+impl<E: ChessEngine> ConsoleGame<'_, E> {
+    fn print_clocks(&self) {
+        if let Some(game_clock) = self.game_clock.as_ref() {
+            println_info(
+                "White Clock",
+                game_clock.get_remaining_time(White).stringify(),
+            );
+            println_info(
+                "Black Clock",
+                game_clock.get_remaining_time(Black).stringify(),
+            );
+        }
+    }
+
+    fn get_move_search_config(&self, turn: Color) -> SearchConfig {
+        self.game_clock
+            .as_ref()
+            .and_then(|game_clock| game_clock.get_go_command(turn))
+            .map_or(DEFAULT_SELFPLAY_COMMAND, SearchConfig::from_go_command)
+    }
+
+    /// Charges the time of the move to the clock of the side, returning the ending if it flagged.
+    fn update_clock(&mut self, turn: Color, time_taken: Duration) -> Option<GameEnding> {
+        let game_clock = self.game_clock.as_mut()?;
+        self.clock_history.push(game_clock.clone());
+        if game_clock.update(turn, time_taken) {
+            return None;
+        }
+        Some(GameEnding::new(GameResult::Win(!turn), "time forfeit"))
+    }
+
+    fn play_engine_move(&mut self) -> Result<Option<GameEnding>> {
+        let turn = self.engine.get_board().turn();
+        let search_config = self.get_move_search_config(turn);
+        let clock = Instant::now();
+        let search_info = self.engine.go_quiet(&search_config);
+        let time_taken = clock.elapsed();
+        if self.engine.terminate() {
+            return Ok(Some(GameEnding::new(GameResult::InProgress, "abandoned")));
+        }
+        // The engine claims a draw unless it thinks it is better.
+        if let Some(game_ending) = GameEnding::get_claimable_draw(self.engine.get_board()) {
+            if search_info.get_score().unwrap_or_default() <= 0 {
+                println_info(ENGINE_NAME, "claims a draw");
+                return Ok(Some(game_ending));
+            }
+        }
+        let best_move =
+            search_info
+                .get_best_move()
+                .ok_or_else(|| TimecatError::BestMoveNotFound {
+                    fen: self.engine.get_board().get_fen(),
+                })?;
+        if let Some(game_ending) = self.update_clock(turn, time_taken) {
+            return Ok(Some(game_ending));
+        }
+        let best_move_san = best_move.stringify_move(self.engine.get_board().get_position())?;
+        self.engine.get_board_mut().push(best_move)?;
+        println_wasm!("\n{}\n", self.engine.get_board());
+        println_info(&format!("{ENGINE_NAME} plays"), best_move_san);
+        println_info("Score", search_info.get_score().stringify());
+        self.print_clocks();
+        Ok(None)
+    }
+
+    fn undo(&mut self) -> Result<()> {
+        // The reply of the engine is taken back too, so that it is the turn of the human again.
+        if self.engine.get_board().get_ply() < self.starting_ply + 2 {
+            return Err(TimecatError::CustomError {
+                err_msg: "There is no move of yours to take back! Please try again!".to_string(),
+            });
+        }
+        let mut popped_moves = vec![];
+        for _ in 0..2 {
+            let valid_or_null_move = self.engine.get_board_mut().pop();
+            popped_moves
+                .push(valid_or_null_move.stringify_move(self.engine.get_board().get_position())?);
+        }
+        if self.game_clock.is_some() {
+            let num_clocks = self.clock_history.len() - 2;
+            self.game_clock = self.clock_history.drain(num_clocks..).next();
+        }
+        println_wasm!("\n{}\n", self.engine.get_board());
+        println_info("Took back", popped_moves.into_iter().rev().join(" "));
+        self.print_clocks();
+        Ok(())
+    }
+
+    fn show_hint(&mut self) -> Result<()> {
+        let search_info = self.engine.go_quiet(&DEFAULT_PLAY_HINT_COMMAND);
+        let best_move =
+            search_info
+                .get_best_move()
+                .ok_or_else(|| TimecatError::BestMoveNotFound {
+                    fen: self.engine.get_board().get_fen(),
+                })?;
+        println_info(
+            "Hint",
+            best_move.stringify_move(self.engine.get_board().get_position())?,
+        );
+        Ok(())
+    }
+
+    fn push_human_move(&mut self, move_text: &str) -> Result<()> {
+        let board = self.engine.get_board_mut();
+        board
+            .push_san(move_text)
+            .or_else(|_| board.push_uci(move_text))?;
+        Ok(())
+    }
+
+    /// Reads commands until the human moves or the game ends.
+    fn play_human_move(&mut self) -> Result<Option<GameEnding>> {
+        let turn = self.engine.get_board().turn();
+        let clock = Instant::now();
+        loop {
+            println_wasm!();
+            print_line(format!("Your move ({turn}): ").colorize(INPUT_MESSAGE_STYLE));
+            let user_input = self.engine.read_line().ok_or(TimecatError::NoInputSource)?;
+            let user_input = user_input.trim();
+            let result = match user_input.to_lowercase().as_str() {
+                "" => continue,
+                "help" => {
+                    println_wasm!("{GAME_COMMANDS_HELP}");
+                    Ok(())
+                }
+                "d" | "display" => {
+                    println_wasm!("{}", self.engine.get_board());
+                    Ok(())
+                }
+                "undo" | "takeback" => self.undo(),
+                "hint" => self.show_hint(),
+                "resign" => {
+                    return Ok(Some(GameEnding::new(
+                        GameResult::Win(!turn),
+                        format!("{turn} resigned"),
+                    )))
+                }
+                "draw" => match GameEnding::get_claimable_draw(self.engine.get_board()) {
+                    Some(game_ending) => return Ok(Some(game_ending)),
+                    None => Err(TimecatError::CustomError {
+                        err_msg: "A draw can only be claimed after a threefold repetition or under the fifty move rule! Please try again!".to_string(),
+                    }),
+                },
+                "abort" => return Ok(Some(GameEnding::new(GameResult::InProgress, "abandoned"))),
+                "q" | "quit" | "exit" => {
+                    self.engine.set_termination(true);
+                    return Ok(Some(GameEnding::new(GameResult::InProgress, "abandoned")));
+                }
+                _ => match self.push_human_move(user_input) {
+                    Ok(()) => {
+                        if let Some(game_ending) = self.update_clock(turn, clock.elapsed()) {
+                            return Ok(Some(game_ending));
+                        }
+                        println_wasm!("\n{}\n", self.engine.get_board());
+                        self.print_clocks();
+                        return Ok(None);
+                    }
+                    Err(error) => Err(error),
+                },
+            };
+            if let Err(error) = result {
+                println_wasm!(
+                    "{}",
+                    error
+                        .stringify_with_optional_raw_input(Some(user_input))
+                        .colorize(ERROR_MESSAGE_STYLE)
+                );
+            }
+        }
+    }
+
+    fn get_pgn(&self, game_ending: &GameEnding) -> String {
+        let player_names = [String::from("Human"), get_engine_version()];
+        let (white, black) = match self.human_color {
+            White => (player_names[0].clone(), player_names[1].clone()),
+            Black => (player_names[1].clone(), player_names[0].clone()),
+        };
+        let time_control = self
+            .game_clock
+            .as_ref()
+            .map_or("-".to_string(), |game_clock| {
+                game_clock.get_time_control().to_string()
+            });
+        self.engine.get_board().get_pgn_with_tags(
+            &[
+                ("Event", format!("{ENGINE_NAME} Console Game")),
+                ("White", white),
+                ("Black", black),
+                ("TimeControl", time_control),
+                ("Termination", game_ending.termination.clone()),
+            ],
+            game_ending.result,
+        )
+    }
+
+    fn run(&mut self) -> Result<GameEnding> {
+        println_wasm!("{}", self.engine.get_board());
+        println_info("You play", self.human_color);
+        println_wasm!("{GAME_COMMANDS_HELP}");
+        loop {
+            if let Some(game_ending) = GameEnding::get_automatic(self.engine.get_board()) {
+                return Ok(game_ending);
+            }
+            let optional_game_ending = if self.engine.get_board().turn() == self.human_color {
+                self.play_human_move()?
+            } else {
+                self.play_engine_move()?
+            };
+            if let Some(game_ending) = optional_game_ending {
+                return Ok(game_ending);
+            }
+        }
+    }
+}
+
+/// Plays a game between the human at the console and the engine from the current position, printing its PGN at the end
+/// and saving it to the path if one is given.
+pub fn play(
+    engine: &mut impl ChessEngine,
+    human_color: Color,
+    optional_time_control: Option<&TimeControl>,
+    optional_pgn_path: Option<&Path>,
+) -> Result<()> {
+    with_output_settings(
+        *engine.get_config(),
+        engine.get_output_sink().clone(),
+        || {
+            play_helper(
+                engine,
+                human_color,
+                optional_time_control,
+                optional_pgn_path,
+            )
+        },
+    )
+}
+
+fn play_helper(
+    engine: &mut impl ChessEngine,
+    human_color: Color,
+    optional_time_control: Option<&TimeControl>,
+    optional_pgn_path: Option<&Path>,
+) -> Result<()> {
+    if engine.get_board().is_game_over() {
+        return Err(TimecatError::GameAlreadyOver);
+    }
+    let mut console_game = ConsoleGame {
+        starting_ply: engine.get_board().get_ply(),
+        engine,
+        human_color,
+        game_clock: optional_time_control
+            .cloned()
+            .map(GameClock::new)
+            .transpose()?,
+        clock_history: vec![],
+    };
+    let game_ending = console_game.run()?;
+    let pgn = console_game.get_pgn(&game_ending);
+    println_wasm!();
+    println_info("Result", game_ending.result);
+    println_info("Termination", &game_ending.termination);
+    println_wasm!("\n{}:\n\n{pgn}\n", "Game PGN".colorize(INFO_MESSAGE_STYLE));
+    if let Some(pgn_path) = optional_pgn_path {
+        fs::write(pgn_path, pgn + "\n")?;
+        println_info("Game saved to", pgn_path.display());
+    }
+    Ok(())
+}
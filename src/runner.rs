@@ -4,82 +4,154 @@ use super::*;
 pub struct TimecatBuilder<T: ChessEngine> {
     user_commands: Vec<UserCommand>,
     engine: Option<T>,
+    config: Option<TimecatConfig>,
+    output_sink: Option<Arc<dyn OutputSink>>,
+    io_reader: Option<IoReader>,
+    uci_state_manager: Option<UCIStateManager<T>>,
+    #[cfg(feature = "config_file")]
+    config_file_path: Option<PathBuf>,
+    #[cfg(feature = "config_file")]
+    profile: Option<String>,
 }
 
 impl<T: ChessEngine<IoReader = IoReader> + Default> TimecatBuilder<T> {
     pub fn build(self) -> Timecat<T> {
-        let io_reader = IoReader::default();
-        Timecat {
+        let io_reader = self.io_reader.unwrap_or_default();
+        let mut engine = self
+            .engine
+            .unwrap_or_default()
+            .with_io_reader(io_reader.clone());
+        if let Some(config) = self.config {
+            *engine.get_config_mut() = config;
+        }
+        if let Some(output_sink) = self.output_sink {
+            engine.set_output_sink(output_sink);
+        }
+        #[cfg_attr(not(feature = "config_file"), expect(unused_mut))]
+        let mut uci_state_manager = self.uci_state_manager.unwrap_or_default();
+        #[cfg(feature = "config_file")]
+        let config_file_result = Self::apply_config_file(
+            &mut engine,
+            &mut uci_state_manager,
+            self.config_file_path,
+            self.profile.as_deref(),
+        );
+        let timecat = Timecat {
             user_commands: self.user_commands,
-            engine: self
-                .engine
-                .unwrap_or_default()
-                .with_io_reader(io_reader.clone()),
+            engine,
             io_reader,
-            uci_state_manager: UCIStateManager::default(),
+            uci_state_manager,
+        };
+        #[cfg(feature = "config_file")]
+        if let Err(error) = config_file_result {
+            timecat.print_error(&error, None);
         }
+        timecat
+    }
+
+    #[cfg(feature = "config_file")]
+    fn apply_config_file(
+        engine: &mut T,
+        uci_state_manager: &mut UCIStateManager<T>,
+        optional_config_file_path: Option<PathBuf>,
+        optional_profile: Option<&str>,
+    ) -> Result<()> {
+        let Some(config_file_path) = optional_config_file_path else {
+            return match optional_profile {
+                Some(profile) => Err(TimecatError::UnknownProfile {
+                    profile: profile.to_string(),
+                }),
+                None => Ok(()),
+            };
+        };
+        let config_file = EngineConfigFile::load(&config_file_path)?;
+        uci_state_manager.set_config_file_path(Some(config_file_path));
+        with_output_settings(
+            *engine.get_config(),
+            engine.get_output_sink().clone(),
+            || config_file.apply(engine, uci_state_manager, optional_profile),
+        )
     }
 }
 
 impl<T: ChessEngine> TimecatBuilder<T> {
+    pub fn set_engine(mut self, engine: T) -> Self {
+        self.engine = Some(engine);
+        self
+    }
+
+    /// Sets the output settings of the instance, overriding the ones of the engine.
+    pub fn set_config(mut self, config: TimecatConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Sets where the output of the instance goes, overriding the sink of the engine.
+    pub fn set_output_sink(mut self, output_sink: Arc<dyn OutputSink>) -> Self {
+        self.output_sink = Some(output_sink);
+        self
+    }
+
+    /// Sets where the commands of the instance come from, instead of the standard input.
+    pub fn set_io_reader(mut self, io_reader: IoReader) -> Self {
+        self.io_reader = Some(io_reader);
+        self
+    }
+
+    /// Sets the options of the engine from a config file when the instance is built, see [`EngineConfigFile`].
+    #[cfg(feature = "config_file")]
+    pub fn set_config_file(mut self, config_file_path: impl Into<PathBuf>) -> Self {
+        self.config_file_path = Some(config_file_path.into());
+        self
+    }
+
+    /// Selects the profile of the config file to apply, instead of the one named in the file.
+    #[cfg(feature = "config_file")]
+    pub fn set_profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    pub fn set_uci_state_manager(mut self, uci_state_manager: UCIStateManager<T>) -> Self {
+        self.uci_state_manager = Some(uci_state_manager);
+        self
+    }
+
+    /// Sets up the commands to run at startup from the arguments of the binary, see [`CliArgs`].
     pub fn parse_args(mut self, args: &[&str]) -> Self {
-        if args.contains(&"--uci") {
-            self.user_commands
-                .push(UserCommand::ChangeToUCIMode { verbose: false });
-        }
-        #[cfg(feature = "colored")]
-        if args.contains(&"--no-color") {
-            self.user_commands.push(UserCommand::SetColor(false));
-        }
-        if args.contains(&"--threads") {
-            let num_threads = args
-                .iter()
-                .skip_while(|&arg| !arg.starts_with("--threads"))
-                .nth(1)
-                .unwrap_or(&"")
-                .parse()
-                .unwrap_or(TIMECAT_DEFAULTS.num_threads);
-            self.user_commands.push(UserCommand::SetUCIOption {
-                user_input: format!("setoption name Threads value {}", num_threads),
-            });
-        }
-        if args.contains(&"--help") {
-            self.user_commands.push(UserCommand::Help);
-            self.user_commands.push(UserCommand::TerminateEngine);
-            return self;
-        }
-        if args.contains(&"--version") {
-            self.user_commands.push(UserCommand::EngineVersion);
-            self.user_commands.push(UserCommand::TerminateEngine);
-            return self;
-        }
-        #[cfg(feature = "debug")]
-        if args.contains(&"--test") {
-            self.user_commands.push(UserCommand::RunTest);
-            self.user_commands.push(UserCommand::TerminateEngine);
-            return self;
-        }
-        if args.contains(&"-c") || args.contains(&"--command") {
-            let command_string = args
-                .iter()
-                .skip_while(|&arg| !["-c", "--command"].contains(arg))
-                .skip(1)
-                .take_while(|&&arg| !arg.starts_with("--"))
-                .join(" ");
-            match Parser::parse_command(&command_string) {
-                Ok(user_commands) => self.user_commands.extend(user_commands),
-                Err(error) => println_wasm!(
-                    "{}",
-                    error
-                        .stringify_with_optional_raw_input(Some(&command_string))
-                        .colorize(ERROR_MESSAGE_STYLE)
-                ),
+        let cli_args = match CliArgs::parse(args) {
+            Ok(cli_args) => cli_args,
+            Err(error) => {
+                self.push_error(&error);
+                return self;
             }
-            self.user_commands.push(UserCommand::TerminateEngine);
-            return self;
+        };
+        #[cfg(feature = "config_file")]
+        {
+            self.config_file_path = cli_args
+                .get_option("config")
+                .map(PathBuf::from)
+                .or_else(|| EngineConfigFile::get_default_path().filter(|path| path.exists()));
+            if let Some(profile) = cli_args.get_option("profile") {
+                self.profile = Some(profile.to_string());
+            }
+        }
+        match cli_args.to_user_commands() {
+            Ok(user_commands) => self.user_commands.extend(user_commands),
+            Err(error) => self.push_error(&error),
         }
         self
     }
+
+    fn push_error(&mut self, error: &TimecatError) {
+        println_wasm!(
+            "{}",
+            error
+                .stringify_with_optional_raw_input(None)
+                .colorize(ERROR_MESSAGE_STYLE)
+        );
+        self.user_commands.push(UserCommand::TerminateEngine);
+    }
 }
 
 pub struct Timecat<T: ChessEngine> {
@@ -90,24 +162,77 @@ pub struct Timecat<T: ChessEngine> {
 }
 
 impl<T: ChessEngine> Timecat<T> {
+    #[inline]
+    pub fn get_engine(&self) -> &T {
+        &self.engine
+    }
+
+    #[inline]
+    pub fn get_engine_mut(&mut self) -> &mut T {
+        &mut self.engine
+    }
+
+    #[inline]
+    pub fn get_config(&self) -> &TimecatConfig {
+        self.engine.get_config()
+    }
+
+    #[inline]
+    pub fn get_config_mut(&mut self) -> &mut TimecatConfig {
+        self.engine.get_config_mut()
+    }
+
+    #[inline]
+    pub fn get_output_sink(&self) -> &Arc<dyn OutputSink> {
+        self.engine.get_output_sink()
+    }
+
+    #[inline]
+    pub fn set_output_sink(&mut self, output_sink: Arc<dyn OutputSink>) {
+        self.engine.set_output_sink(output_sink);
+    }
+
+    #[inline]
+    pub fn get_io_reader(&self) -> &IoReader {
+        &self.io_reader
+    }
+
+    fn print_error(&self, error: &TimecatError, optional_raw_input: Option<&str>) {
+        self.engine.run_with_output_settings(|| {
+            println_wasm!(
+                "{}",
+                error
+                    .stringify_with_optional_raw_input(optional_raw_input)
+                    .colorize(ERROR_MESSAGE_STYLE)
+            )
+        });
+    }
+
     pub fn run(&mut self) {
         self.io_reader.start_reader_in_parallel();
         for user_command in self.user_commands.iter() {
             user_command
                 .run_command(&mut self.engine, &self.uci_state_manager)
-                .unwrap_or_else(|error| {
-                    println_wasm!("{}", error.stringify().colorize(ERROR_MESSAGE_STYLE))
-                });
+                .unwrap_or_else(|error| self.print_error(&error, None));
         }
         if self.engine.terminate() {
             return;
         }
         self.engine.print_info();
-        Self::main_loop.run_and_print_time(self);
+        let ((), time_taken) = Self::main_loop.run_and_measure_time(self);
+        self.engine.run_with_output_settings(|| {
+            if self.get_config().is_in_console_mode() {
+                println_wasm!();
+            }
+            println_info("Run Time", time_taken.stringify());
+        });
     }
 
     pub fn run_uci_command(&mut self, raw_input: &str) -> Result<()> {
-        for user_command in Parser::parse_command(raw_input)? {
+        let user_commands = self
+            .engine
+            .run_with_output_settings(|| Parser::parse_command(raw_input))?;
+        for user_command in user_commands {
             user_command.run_command(&mut self.engine, &self.uci_state_manager)?;
         }
         Ok(())
@@ -115,39 +240,38 @@ impl<T: ChessEngine> Timecat<T> {
 
     pub fn main_loop(&mut self) {
         loop {
+            let config = *self.get_config();
             if self.engine.terminate() {
-                if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
-                    println_wasm!(
-                        "{}",
-                        "Program ended successfully!".colorize(SUCCESS_MESSAGE_STYLE)
-                    );
+                if config.is_in_console_mode() {
+                    self.engine.run_with_output_settings(|| {
+                        println_wasm!(
+                            "{}",
+                            "Program ended successfully!".colorize(SUCCESS_MESSAGE_STYLE)
+                        )
+                    });
                 }
                 break;
             }
-            let raw_input = if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
-                println_wasm!();
-                let raw_input = get_input(
-                    "Enter Command: ".colorize(INPUT_MESSAGE_STYLE),
-                    &self.io_reader,
-                );
-                println_wasm!();
-                raw_input
-            } else {
-                get_input("", &self.io_reader)
-            };
-            self.run_uci_command(&raw_input).unwrap_or_else(|error| {
-                println_wasm!(
-                    "{}",
-                    error
-                        .stringify_with_optional_raw_input(Some(raw_input.as_str()))
-                        .colorize(ERROR_MESSAGE_STYLE)
-                )
+            let raw_input = self.engine.run_with_output_settings(|| {
+                if config.is_in_console_mode() {
+                    println_wasm!();
+                    let raw_input = get_input(
+                        "Enter Command: ".colorize(INPUT_MESSAGE_STYLE),
+                        &self.io_reader,
+                    );
+                    println_wasm!();
+                    raw_input
+                } else {
+                    get_input("", &self.io_reader)
+                }
             });
+            self.run_uci_command(&raw_input)
+                .unwrap_or_else(|error| self.print_error(&error, Some(raw_input.as_str())));
         }
     }
 
     pub fn uci_loop(&mut self) {
-        GLOBAL_TIMECAT_STATE.set_to_uci_mode();
+        self.get_config_mut().set_uci_mode(true);
         self.run();
     }
 }
@@ -53,6 +53,12 @@ impl Default for PVTable {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum SingularExtension {
+    Extend(Depth),
+    MultiCut(Score),
+}
+
 // #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 pub struct Searcher<P: PositionEvaluation> {
@@ -62,12 +68,15 @@ pub struct Searcher<P: PositionEvaluation> {
     evaluator: P,
     transposition_table: Arc<TranspositionTable>,
     pv_table: PVTable,
+    excluded_moves: [Option<Move>; MAX_PLY],
     best_moves: Vec<Move>,
     move_sorter: MoveSorter,
     num_nodes_searched: Arc<AtomicUsize>,
+    num_thread_nodes_searched: usize,
     selective_depth: Arc<AtomicUsize>,
     ply: Ply,
     score: Score,
+    best_move_nodes_fraction: f64,
     depth_completed: Depth,
     is_outside_aspiration_window: bool,
     clock: Instant,
@@ -93,12 +102,15 @@ impl<P: PositionEvaluation> Searcher<P> {
             evaluator,
             transposition_table,
             pv_table: PVTable::new(),
+            excluded_moves: [None; MAX_PLY],
             best_moves: Vec::new(),
             move_sorter: MoveSorter::new(),
             num_nodes_searched,
+            num_thread_nodes_searched: 0,
             selective_depth,
             ply: 0,
             score: 0,
+            best_move_nodes_fraction: 0.0,
             depth_completed: 0,
             is_outside_aspiration_window: false,
             clock: Instant::now(),
@@ -147,6 +159,11 @@ impl<P: PositionEvaluation> Searcher<P> {
         &self.pv_table
     }
 
+    #[inline]
+    pub fn get_excluded_move(&self, ply: Ply) -> Option<Move> {
+        *get_item_unchecked!(self.excluded_moves, ply)
+    }
+
     #[inline]
     pub fn get_best_moves(&self) -> &[Move] {
         &self.best_moves
@@ -220,6 +237,12 @@ impl<P: PositionEvaluation> Searcher<P> {
         self.score
     }
 
+    /// Fraction of the nodes of the last root search of the main thread spent on the best root move.
+    #[inline]
+    pub fn get_best_move_nodes_fraction(&self) -> f64 {
+        self.best_move_nodes_fraction
+    }
+
     #[inline]
     pub fn get_depth_completed(&self) -> Depth {
         self.depth_completed
@@ -256,7 +279,28 @@ impl<P: PositionEvaluation> Searcher<P> {
     }
 
     fn evaluate_flipped(&mut self) -> Score {
-        self.evaluator.evaluate_flipped(&self.board)
+        let variant = self.board.get_variant();
+        if variant == Variant::Standard {
+            return self.evaluator.evaluate_flipped(&self.board);
+        }
+        let score = if variant.uses_standard_evaluation() {
+            self.evaluator.evaluate_flipped(&self.board)
+        } else {
+            0
+        };
+        score
+            + self
+                .board
+                .score_flipped(self.evaluator.evaluate_variant_terms(&self.board))
+    }
+
+    /// The score for the side to move if the game has ended by a rule of the variant.
+    fn get_variant_terminal_score(&mut self) -> Option<Score> {
+        if self.board.get_variant() == Variant::Standard {
+            return None;
+        }
+        self.evaluator
+            .evaluate_variant_terminal_in(&self.board, self.ply)
     }
 
     #[inline]
@@ -299,6 +343,23 @@ impl<P: PositionEvaluation> Searcher<P> {
         }
     }
 
+    /// Only the main thread drives the time management, so only its own nodes are counted, which the helper threads
+    /// would otherwise inflate.
+    fn update_best_move_nodes_fraction(
+        &mut self,
+        best_move_num_nodes_searched: usize,
+        initial_num_nodes_searched: usize,
+    ) {
+        if !self.is_main_threaded() {
+            return;
+        }
+        let num_nodes_searched = self.num_thread_nodes_searched - initial_num_nodes_searched;
+        if num_nodes_searched > 0 {
+            self.best_move_nodes_fraction =
+                best_move_num_nodes_searched as f64 / num_nodes_searched as f64;
+        }
+    }
+
     fn get_sorted_root_node_moves(
         &mut self,
         controller: Option<&mut impl SearchControl<Self>>,
@@ -349,6 +410,9 @@ impl<P: PositionEvaluation> Searcher<P> {
         if self.is_main_threaded() {
             self.selective_depth.store(0, MEMORY_ORDERING);
         }
+        if let Some(score) = self.get_variant_terminal_score() {
+            return Some(score);
+        }
         if self.board.is_game_over() {
             return if self.board.is_checkmate() {
                 Some(-self.evaluator.evaluate_checkmate_in(0))
@@ -367,11 +431,14 @@ impl<P: PositionEvaluation> Searcher<P> {
         let mut flag = EntryFlagHash::Alpha;
         let is_endgame = self.board.is_endgame();
         let moves = self.get_sorted_root_node_moves(controller.as_deref_mut());
+        let initial_num_nodes_searched = self.num_thread_nodes_searched;
+        let mut best_move_num_nodes_searched = 0;
         for (move_index, &(move_, _)) in moves.iter().enumerate() {
             if !is_endgame && self.is_draw_move(move_.into()) && score > -DRAW_SCORE {
                 continue;
             }
             let clock = Instant::now();
+            let move_initial_num_nodes_searched = self.num_thread_nodes_searched;
             self.push_unchecked(move_);
             if move_index == 0
                 || -self.alpha_beta(depth - 1, -alpha - 1, -alpha, controller.as_deref_mut())?
@@ -397,6 +464,12 @@ impl<P: PositionEvaluation> Searcher<P> {
                 flag = EntryFlagHash::Exact;
                 alpha = score;
                 self.pv_table.update_table(self.ply, move_);
+                best_move_num_nodes_searched =
+                    self.num_thread_nodes_searched - move_initial_num_nodes_searched;
+                self.update_best_move_nodes_fraction(
+                    best_move_num_nodes_searched,
+                    initial_num_nodes_searched,
+                );
                 if score >= beta {
                     self.transposition_table.write(
                         key,
@@ -410,6 +483,10 @@ impl<P: PositionEvaluation> Searcher<P> {
                 }
             }
         }
+        self.update_best_move_nodes_fraction(
+            best_move_num_nodes_searched,
+            initial_num_nodes_searched,
+        );
         if !self.stop_search_at_every_node(controller) {
             self.transposition_table
                 .write(key, depth, self.ply, alpha, flag, self.get_best_move());
@@ -429,6 +506,48 @@ impl<P: PositionEvaluation> Searcher<P> {
         reduction.round() as Depth
     }
 
+    /// Runs a reduced depth verification search with the hash move excluded to decide whether
+    /// the hash move is singular, i.e. the only move that keeps the score near the hash score.
+    fn get_singular_extension(
+        &mut self,
+        key: u64,
+        depth: Depth,
+        beta: Score,
+        tt_move: Move,
+        controller: Option<&mut impl SearchControl<Self>>,
+    ) -> Option<SingularExtension> {
+        if !self.properties.use_singular_extensions() {
+            return Some(SingularExtension::Extend(0));
+        }
+        let (Some((tt_score, flag)), _) = self.transposition_table.read(
+            key,
+            depth - SINGULAR_EXTENSION_TT_DEPTH_MARGIN,
+            self.ply,
+        ) else {
+            return Some(SingularExtension::Extend(0));
+        };
+        if flag == EntryFlagHash::Alpha || is_checkmate(tt_score) {
+            return Some(SingularExtension::Extend(0));
+        }
+        let singular_beta = tt_score - SINGULAR_EXTENSION_MARGIN_PER_DEPTH * depth as Score;
+        let singular_depth = (depth - 1) / 2;
+        *get_item_unchecked_mut!(self.excluded_moves, self.ply) = Some(tt_move);
+        let score = self.alpha_beta(singular_depth, singular_beta - 1, singular_beta, controller);
+        *get_item_unchecked_mut!(self.excluded_moves, self.ply) = None;
+        let score = score?;
+        Some(if score < singular_beta {
+            SingularExtension::Extend(1)
+        } else if !self.properties.use_multicut() {
+            SingularExtension::Extend(0)
+        } else if singular_beta >= beta {
+            SingularExtension::MultiCut(singular_beta)
+        } else if tt_score >= beta {
+            SingularExtension::Extend(-1)
+        } else {
+            SingularExtension::Extend(0)
+        })
+    }
+
     fn alpha_beta(
         &mut self,
         mut depth: Depth,
@@ -442,6 +561,9 @@ impl<P: PositionEvaluation> Searcher<P> {
         if self.board.is_other_draw() {
             return Some(draw_score);
         }
+        if let Some(score) = self.get_variant_terminal_score() {
+            return Some(score);
+        }
         if self.properties.use_mate_distance_pruning() {
             // mate distance pruning
             alpha = alpha.max(-mate_score);
@@ -451,14 +573,22 @@ impl<P: PositionEvaluation> Searcher<P> {
             }
         }
         let checkers = self.board.get_checkers();
-        if depth > 10 {
-            depth += checkers.popcnt() as Depth;
+        if self.properties.use_check_extensions()
+            && !checkers.is_empty()
+            && self.ply < 2 * (self.depth_completed as Ply + 1)
+        {
+            depth += 1;
         }
         let min_depth = self.move_sorter.is_following_pv() as Depth;
         depth = depth.max(min_depth);
         let is_pv_node = alpha != beta - 1;
         let key = self.board.get_hash();
-        let best_move = if is_pv_node && self.is_main_threaded() {
+        let excluded_move = self.get_excluded_move(self.ply);
+        let best_move = if excluded_move.is_some() {
+            // The transposition table entry belongs to the unrestricted node, so it can only
+            // be used for move ordering in a singular extension verification search.
+            self.transposition_table.read_best_move(key)
+        } else if is_pv_node && self.is_main_threaded() {
             self.transposition_table.read_best_move(key)
         } else {
             let (optional_data, best_move) = self.transposition_table.read(key, depth, self.ply);
@@ -501,9 +631,11 @@ impl<P: PositionEvaluation> Searcher<P> {
             self.selective_depth.fetch_max(self.ply, MEMORY_ORDERING);
         }
         self.num_nodes_searched.fetch_add(1, MEMORY_ORDERING);
+        self.num_thread_nodes_searched += 1;
         let not_in_check = checkers.is_empty();
         let mut futility_pruning = false;
-        if not_in_check && !DISABLE_ALL_PRUNINGS {
+        self.move_sorter.set_threat_move(None, self.ply);
+        if not_in_check && excluded_move.is_none() && !DISABLE_ALL_PRUNINGS {
             // static evaluation
             let static_evaluation = self.evaluate_flipped();
             if depth < 3 && !is_pv_node && !is_checkmate(beta) {
@@ -545,10 +677,16 @@ impl<P: PositionEvaluation> Searcher<P> {
                 self.push_unchecked(ValidOrNullMove::NullMove);
                 let score =
                     -self.alpha_beta(reduced_depth, -beta, -beta + 1, controller.as_deref_mut())?;
+                // The best reply to passing is the threat, which the moves of this node have to answer.
+                let optional_threat_move = self
+                    .transposition_table
+                    .read_best_move(self.board.get_hash());
                 self.pop();
                 if score >= beta {
                     return Some(beta);
                 }
+                self.move_sorter
+                    .set_threat_move(optional_threat_move, self.ply);
             }
             // futility pruning condition
             if depth < 4 && alpha < mate_score {
@@ -562,23 +700,49 @@ impl<P: PositionEvaluation> Searcher<P> {
                 futility_pruning = static_evaluation + futility_margin <= alpha;
             }
         }
+        let mut singular_extension = 0;
+        if excluded_move.is_none() && depth >= SINGULAR_EXTENSION_MIN_DEPTH {
+            // An entry of another position sharing the index can hold a move which is not even pseudo-legal here, so
+            // it is validated before the verification search excludes it.
+            if let Some(tt_move) = best_move.filter(|tt_move| self.board.is_legal(tt_move)) {
+                match self.get_singular_extension(
+                    key,
+                    depth,
+                    beta,
+                    tt_move,
+                    controller.as_deref_mut(),
+                )? {
+                    SingularExtension::Extend(extension) => singular_extension = extension,
+                    SingularExtension::MultiCut(score) => return Some(score),
+                }
+            }
+        }
         let mut flag = EntryFlagHash::Alpha;
+        // The legality of a move is only checked when it is reached, as a cutoff usually leaves most moves unplayed.
         let weighted_moves = self.move_sorter.get_weighted_moves_sorted(
             &self.board,
-            self.board.generate_legal_moves(),
+            self.board.generate_pseudo_legal_moves(),
             &self.transposition_table,
             self.ply,
             best_move,
             self.get_nth_pv_move(self.ply),
         );
-        if weighted_moves.is_empty() {
-            return if not_in_check {
-                Some(draw_score)
+        let mut num_legal_moves = 0;
+        for WeightedMove { move_, .. } in weighted_moves {
+            if !self.board.is_legal_after_pseudo(&move_) {
+                continue;
+            }
+            let move_index = num_legal_moves;
+            num_legal_moves += 1;
+            if excluded_move == Some(move_) {
+                continue;
+            }
+            let extension = if best_move == Some(move_) {
+                singular_extension
             } else {
-                Some(-mate_score)
+                0
             };
-        }
-        for (move_index, WeightedMove { move_, .. }) in weighted_moves.enumerate() {
+            let new_depth = depth - 1 + extension;
             let not_capture_move = !self.board.is_capture(move_);
             let not_an_interesting_position = not_capture_move
                 && not_in_check
@@ -595,13 +759,13 @@ impl<P: PositionEvaluation> Searcher<P> {
             safe_to_apply_lmr &= !self.board.is_check();
             let mut score: Score;
             if move_index == 0 {
-                score = -self.alpha_beta(depth - 1, -beta, -alpha, controller.as_deref_mut())?;
+                score = -self.alpha_beta(new_depth, -beta, -alpha, controller.as_deref_mut())?;
             } else {
                 if safe_to_apply_lmr {
                     let lmr_reduction = Self::get_lmr_reduction(depth, move_index, is_pv_node);
-                    score = if depth > lmr_reduction {
+                    score = if new_depth >= lmr_reduction {
                         -self.alpha_beta(
-                            depth - 1 - lmr_reduction,
+                            new_depth - lmr_reduction,
                             -alpha - 1,
                             -alpha,
                             controller.as_deref_mut(),
@@ -614,14 +778,14 @@ impl<P: PositionEvaluation> Searcher<P> {
                 }
                 if score > alpha {
                     score = -self.alpha_beta(
-                        depth - 1,
+                        new_depth,
                         -alpha - 1,
                         -alpha,
                         controller.as_deref_mut(),
                     )?;
                     if score > alpha && score < beta {
                         score = -self.alpha_beta(
-                            depth - 1,
+                            new_depth,
                             -beta,
                             -alpha,
                             controller.as_deref_mut(),
@@ -638,14 +802,16 @@ impl<P: PositionEvaluation> Searcher<P> {
                     self.move_sorter.add_history_move(move_, &self.board, depth);
                 }
                 if score >= beta {
-                    self.transposition_table.write(
-                        key,
-                        depth,
-                        self.ply,
-                        beta,
-                        EntryFlagHash::Beta,
-                        Some(move_),
-                    );
+                    if excluded_move.is_none() {
+                        self.transposition_table.write(
+                            key,
+                            depth,
+                            self.ply,
+                            beta,
+                            EntryFlagHash::Beta,
+                            Some(move_),
+                        );
+                    }
                     if not_capture_move {
                         self.move_sorter.update_killer_moves(move_, self.ply);
                     }
@@ -653,7 +819,14 @@ impl<P: PositionEvaluation> Searcher<P> {
                 }
             }
         }
-        if !self.stop_search_at_every_node(controller) {
+        if num_legal_moves == 0 {
+            return if not_in_check {
+                Some(draw_score)
+            } else {
+                Some(-mate_score)
+            };
+        }
+        if excluded_move.is_none() && !self.stop_search_at_every_node(controller) {
             self.transposition_table.write(
                 key,
                 depth,
@@ -666,7 +839,14 @@ impl<P: PositionEvaluation> Searcher<P> {
         Some(alpha)
     }
 
-    fn quiescence(&mut self, mut alpha: Score, beta: Score) -> Score {
+    fn quiescence(&mut self, alpha: Score, beta: Score) -> Score {
+        self.quiescence_helper(alpha, beta, self.properties.use_quiescence_checks())
+    }
+
+    /// With `include_checks`, a node in check searches its evasions and any other node searches the quiet checks after
+    /// the captures. Only the first ply of the quiescence search and the replies to its quiet checks get it, which
+    /// keeps the search finite.
+    fn quiescence_helper(&mut self, mut alpha: Score, beta: Score, include_checks: bool) -> Score {
         if self.ply == MAX_PLY - 1 {
             return self.evaluate_flipped();
         }
@@ -674,11 +854,20 @@ impl<P: PositionEvaluation> Searcher<P> {
         if self.board.is_other_draw() {
             return self.evaluator.evaluate_draw();
         }
+        if let Some(score) = self.get_variant_terminal_score() {
+            return score;
+        }
         let is_pv_node = alpha != beta - 1;
         if self.is_main_threaded() && is_pv_node {
             self.selective_depth.fetch_max(self.ply, MEMORY_ORDERING);
         }
         self.num_nodes_searched.fetch_add(1, MEMORY_ORDERING);
+        self.num_thread_nodes_searched += 1;
+        // The threat at this ply may still be the one found at a sibling node by the main search.
+        self.move_sorter.set_threat_move(None, self.ply);
+        if include_checks && self.board.is_check() {
+            return self.quiescence_evasions(alpha, beta);
+        }
         let evaluation = self.evaluate_flipped();
         if evaluation >= beta {
             return beta;
@@ -692,7 +881,7 @@ impl<P: PositionEvaluation> Searcher<P> {
                 break;
             }
             self.push_unchecked(move_);
-            let score = -self.quiescence(-beta, -alpha);
+            let score = -self.quiescence_helper(-beta, -alpha, false);
             self.pop();
             if score >= beta {
                 return beta;
@@ -710,6 +899,48 @@ impl<P: PositionEvaluation> Searcher<P> {
                 return alpha;
             }
         }
+        if include_checks {
+            for move_ in self.board.generate_legal_quiet_checks() {
+                self.push_unchecked(move_);
+                let score = -self.quiescence_helper(-beta, -alpha, true);
+                self.pop();
+                if score >= beta {
+                    return beta;
+                }
+                if score > alpha {
+                    self.pv_table.update_table(self.ply, move_);
+                    alpha = score;
+                }
+            }
+        }
+        alpha
+    }
+
+    /// Searches every evasion, since standing pat in check would hide the threat of the check.
+    fn quiescence_evasions(&mut self, mut alpha: Score, beta: Score) -> Score {
+        let weighted_moves = self.move_sorter.get_weighted_moves_sorted(
+            &self.board,
+            self.board.generate_legal_evasions(),
+            &self.transposition_table,
+            self.ply,
+            None,
+            None,
+        );
+        if weighted_moves.is_empty() {
+            return -self.evaluator.evaluate_checkmate_in(self.ply);
+        }
+        for WeightedMove { move_, .. } in weighted_moves {
+            self.push_unchecked(move_);
+            let score = -self.quiescence_helper(-beta, -alpha, false);
+            self.pop();
+            if score >= beta {
+                return beta;
+            }
+            if score > alpha {
+                self.pv_table.update_table(self.ply, move_);
+                alpha = score;
+            }
+        }
         alpha
     }
 
@@ -744,8 +975,8 @@ impl<P: PositionEvaluation> Searcher<P> {
             if verbose && self.is_main_threaded() {
                 search_info.print_info();
             }
-            controller.on_each_search_completion(self);
             self.is_outside_aspiration_window = self.score <= alpha || self.score >= beta;
+            controller.on_each_search_completion(self);
             if self.is_outside_aspiration_window {
                 if verbose && self.is_main_threaded() {
                     search_info.print_warning_message(alpha, beta);
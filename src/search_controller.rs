@@ -3,8 +3,7 @@ use super::*;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 pub struct SearchController {
-    move_overhead: Duration,
-    max_time: Duration,
+    time_manager: TimeManager,
     max_depth: Depth,
     max_num_nodes_searched: usize,
     max_abs_score_reached: Score,
@@ -17,8 +16,7 @@ impl SearchController {
     #[inline]
     pub fn new() -> Self {
         Self {
-            move_overhead: TIMECAT_DEFAULTS.move_overhead,
-            max_time: Duration::MAX,
+            time_manager: TimeManager::new(TIMECAT_DEFAULTS.move_overhead),
             max_depth: Depth::MAX,
             max_num_nodes_searched: usize::MAX,
             max_abs_score_reached: Score::MAX,
@@ -38,21 +36,36 @@ impl SearchController {
         self.stop_search_at_every_node = false;
     }
 
+    #[inline]
+    pub fn get_time_manager(&self) -> &TimeManager {
+        &self.time_manager
+    }
+
+    #[inline]
+    pub fn get_time_manager_mut(&mut self) -> &mut TimeManager {
+        &mut self.time_manager
+    }
+
     pub fn set_max_time(&mut self, duration: Duration) {
-        self.max_time = duration;
+        self.time_manager.set_fixed_time(duration);
         self.stop_search_at_every_node = false;
     }
 
     #[inline]
     pub fn max_time(&self) -> Duration {
-        self.max_time
+        self.time_manager.get_maximum_time()
+    }
+
+    #[inline]
+    pub fn optimum_time(&self) -> Duration {
+        self.time_manager.get_optimum_time()
     }
 
     pub fn is_time_up(&mut self, time_elapsed: Duration) -> bool {
-        if self.max_time == Duration::MAX {
+        if !self.time_manager.is_time_limited() {
             return false;
         }
-        self.stop_search_at_every_node = time_elapsed + self.move_overhead >= self.max_time;
+        self.stop_search_at_every_node = self.time_manager.is_hard_limit_reached(time_elapsed);
         self.stop_search_at_every_node
     }
 }
@@ -60,16 +73,16 @@ impl SearchController {
 impl<P: PositionEvaluation> SearchControl<Searcher<P>> for SearchController {
     #[inline]
     fn get_move_overhead(&self) -> Duration {
-        self.move_overhead
+        self.time_manager.get_move_overhead()
     }
 
     #[inline]
     fn set_move_overhead(&mut self, duration: Duration) {
-        self.move_overhead = duration;
+        self.time_manager.set_move_overhead(duration);
     }
 
     fn reset_variables(&mut self) {
-        self.max_time = Duration::MAX;
+        self.time_manager.reset_variables();
         self.max_depth = Depth::MAX;
         self.max_num_nodes_searched = usize::MAX;
         self.max_abs_score_reached = Score::MAX;
@@ -77,15 +90,29 @@ impl<P: PositionEvaluation> SearchControl<Searcher<P>> for SearchController {
     }
 
     fn on_each_search_completion(&mut self, searcher: &mut Searcher<P>) {
-        if !self.is_infinite_search()
-            && searcher.is_main_threaded()
-            && !searcher.is_outside_aspiration_window()
-            && searcher.get_depth_completed() >= 10
+        if self.is_infinite_search()
+            || !searcher.is_main_threaded()
+            || searcher.is_outside_aspiration_window()
+        {
+            return;
+        }
+        if searcher.get_depth_completed() >= 10
             && searcher.get_score() >= WINNING_SCORE_THRESHOLD
             && searcher.get_time_elapsed() > Duration::from_secs(10)
         {
             self.stop_search_at_every_node = true;
         }
+        self.time_manager.update_on_iteration(
+            searcher.get_best_move(),
+            searcher.get_score(),
+            searcher.get_best_move_nodes_fraction(),
+        );
+        if self
+            .time_manager
+            .is_soft_limit_reached(searcher.get_time_elapsed())
+        {
+            self.stop_search_at_every_node = true;
+        }
     }
 
     fn on_receiving_search_config(&mut self, config: &SearchConfig, searcher: &mut Searcher<P>) {
@@ -119,48 +146,16 @@ impl<P: PositionEvaluation> SearchControl<Searcher<P>> for SearchController {
                         .evaluate_checkmate_in(2 * *mate);
                 }
                 if let Some(movetime) = movetime {
-                    self.set_max_time(self.max_time.min(*movetime));
+                    self.set_max_time(*movetime);
                 }
-                if let Some(TimedGoCommand {
-                    wtime,
-                    btime,
-                    winc,
-                    binc,
-                    moves_to_go,
-                }) = time_clock
-                {
+                if let Some(time_clock) = time_clock {
                     let board = searcher.get_board();
-                    let (self_time, self_inc, opponent_time, _) = match board.turn() {
-                        White => (*wtime, *winc, *btime, *binc),
-                        Black => (*btime, *binc, *wtime, *winc),
-                    };
-                    let divider = moves_to_go.unwrap_or(
-                        (20 as NumMoves)
-                            .checked_sub(board.get_fullmove_number() / 2)
-                            .unwrap_or_default()
-                            .max(5),
+                    self.time_manager.set_time_clock(
+                        time_clock,
+                        board.turn(),
+                        board.get_fullmove_number(),
                     );
-                    let new_inc = self_inc
-                        .checked_sub(Duration::from_secs(1))
-                        .unwrap_or_default();
-                    let self_time_advantage_bonus =
-                        self_time.checked_sub(opponent_time).unwrap_or_default();
-                    let opponent_time_advantage =
-                        opponent_time.checked_sub(self_time).unwrap_or_default();
-                    let mut search_time = self_time
-                        .checked_sub(opponent_time_advantage)
-                        .unwrap_or_default()
-                        / divider as u32
-                        + new_inc
-                        + self_time_advantage_bonus
-                            .checked_sub(Duration::from_secs(10))
-                            .unwrap_or_default()
-                            / 4;
-                    search_time = search_time
-                        .max((self_time / 2).min(Duration::from_secs(3)))
-                        .min(Duration::from_secs(board.get_fullmove_number() as u64) / 2)
-                        .max(Duration::from_millis(100));
-                    self.set_max_time(self.max_time.min(search_time));
+                    self.stop_search_at_every_node = false;
                 }
             }
         }
@@ -16,7 +16,45 @@ pub fn self_play(
     verbose: bool,
     move_limit: impl Into<Option<NumMoves>> + Copy,
 ) -> Result<()> {
-    let move_limit = move_limit.into().unwrap_or(NumMoves::MAX);
+    with_output_settings(
+        *engine.get_config(),
+        engine.get_output_sink().clone(),
+        || self_play_helper(engine, search_config, None, verbose, move_limit.into()),
+    )
+}
+
+/// Plays the engine against itself while keeping both clocks under the given time control. The
+/// game stops as soon as a side flags. Without a time control every move is searched with
+/// [`DEFAULT_SELFPLAY_COMMAND`].
+pub fn self_play_with_time_control(
+    engine: &mut impl ChessEngine,
+    time_control: &TimeControl,
+    verbose: bool,
+    move_limit: impl Into<Option<NumMoves>> + Copy,
+) -> Result<()> {
+    with_output_settings(
+        *engine.get_config(),
+        engine.get_output_sink().clone(),
+        || {
+            self_play_helper(
+                engine,
+                &DEFAULT_SELFPLAY_COMMAND,
+                Some(GameClock::new(time_control.to_owned())?),
+                verbose,
+                move_limit.into(),
+            )
+        },
+    )
+}
+
+fn self_play_helper(
+    engine: &mut impl ChessEngine,
+    search_config: &SearchConfig,
+    mut game_clock: Option<GameClock>,
+    verbose: bool,
+    move_limit: Option<NumMoves>,
+) -> Result<()> {
+    let move_limit = move_limit.unwrap_or(NumMoves::MAX);
     if move_limit == 0 {
         return Ok(());
     }
@@ -37,7 +75,15 @@ pub fn self_play(
         if verbose {
             println_wasm!();
         }
-        let response = engine.go(search_config, verbose);
+        let turn = engine.get_board().turn();
+        let clock_search_config = game_clock
+            .as_ref()
+            .and_then(|game_clock| game_clock.get_go_command(turn))
+            .map(SearchConfig::from_go_command);
+        let response = engine.go(
+            clock_search_config.as_ref().unwrap_or(search_config),
+            verbose,
+        );
         let Some(best_move) = response.get_best_move() else {
             return Err(TimecatError::BestMoveNotFound {
                 fen: engine.get_board().get_fen(),
@@ -49,6 +95,13 @@ pub fn self_play(
             .stringify_move(engine.get_board().get_position())
             .unwrap();
         let pv = get_pv_string(engine.get_board().get_position(), response.get_pv());
+        if let Some(game_clock) = game_clock.as_mut() {
+            if !game_clock.update(turn, time_elapsed) {
+                println_wasm!();
+                println_info("Flagged", format!("{turn} lost on time"));
+                break;
+            }
+        }
         engine.get_board_mut().push_unchecked(best_move);
         if time_elapsed.as_secs_f64()
             > *time_taken_vec
@@ -66,6 +119,16 @@ pub fn self_play(
         println_info("Score", score.stringify());
         println_info("PV Line", pv);
         println_info("Time Taken", time_elapsed.stringify());
+        if let Some(game_clock) = game_clock.as_ref() {
+            println_info(
+                "White Clock",
+                game_clock.get_remaining_time(White).stringify(),
+            );
+            println_info(
+                "Black Clock",
+                game_clock.get_remaining_time(Black).stringify(),
+            );
+        }
         if let Some(num_nodes_searched) = response.get_num_nodes_searched() {
             let nps = format!(
                 "{} Nodes/sec",
@@ -75,6 +138,10 @@ pub fn self_play(
             println_info("Nodes per second", nps);
         }
     }
+    if time_taken_vec.is_empty() {
+        engine.set_fen(&stating_fen)?;
+        return Ok(());
+    }
     let mean = time_taken_vec.iter().sum::<f64>() / time_taken_vec.len() as f64;
     let std_err = (time_taken_vec
         .iter()
@@ -105,7 +172,14 @@ pub fn self_play(
     println_wasm!(
         "\n{}:\n\n{}",
         "Game PGN".colorize(INFO_MESSAGE_STYLE),
-        engine.get_board().get_pgn(),
+        match game_clock.as_ref() {
+            Some(game_clock) => format!(
+                "[TimeControl \"{}\"]\n{}",
+                game_clock.get_time_control(),
+                engine.get_board().get_pgn(),
+            ),
+            None => engine.get_board().get_pgn(),
+        },
     );
     println_wasm!(
         "\n{}:\n\n[{}]",
@@ -123,15 +197,19 @@ pub fn self_play(
             .map(|&score| score.stringify())
             .join(", "),
     );
-    match search_config.get_go_command() {
-        GoCommand::Limit {
-            depth: Some(depth), ..
-        } => println_info("Depth Searched", depth),
-        GoCommand::Limit {
-            movetime: Some(movetime),
-            ..
-        } => println_info("Time Searched Per Move", movetime.stringify()),
-        _ => (),
+    if let Some(game_clock) = game_clock.as_ref() {
+        println_info("Time Control", game_clock.get_time_control());
+    } else {
+        match search_config.get_go_command() {
+            GoCommand::Limit {
+                depth: Some(depth), ..
+            } => println_info("Depth Searched", depth),
+            GoCommand::Limit {
+                movetime: Some(movetime),
+                ..
+            } => println_info("Time Searched Per Move", movetime.stringify()),
+            _ => (),
+        }
     }
     println_info(
         "Time taken per move",
@@ -0,0 +1,374 @@
+use super::*;
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+
+/// Address a [`TimecatServer`] listens on, written as `host:port` for TCP or `unix:<path>` for a Unix socket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ServerAddress {
+    Tcp(String),
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl FromStr for ServerAddress {
+    type Err = TimecatError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Some(path) = s.strip_prefix(UNIX_SOCKET_ADDRESS_PREFIX) {
+            #[cfg(unix)]
+            return Ok(Self::Unix(PathBuf::from(path)));
+            #[cfg(not(unix))]
+            return Err(TimecatError::FeatureNotEnabled {
+                s: format!("Unix sockets at {path:?}"),
+            });
+        }
+        if s.is_empty() || !s.contains(':') {
+            return Err(TimecatError::InvalidServerAddress {
+                address: s.to_string(),
+            });
+        }
+        Ok(Self::Tcp(s.to_string()))
+    }
+}
+
+impl fmt::Display for ServerAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp(address) => write!(f, "{address}"),
+            #[cfg(unix)]
+            Self::Unix(path) => write!(f, "{UNIX_SOCKET_ADDRESS_PREFIX}{}", path.display()),
+        }
+    }
+}
+
+/// How the threads and hash of the server are handed out to the sessions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResourceSharing {
+    /// Every session may use all the threads of the server. The hash is still split, as every session allocates a
+    /// table of its own.
+    Shared,
+    /// The threads and hash are split evenly between the maximum number of sessions, so that the sessions never
+    /// compete for the same cores.
+    #[default]
+    Partitioned,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ServerLimits {
+    max_sessions: NonZeroUsize,
+    max_threads: NonZeroUsize,
+    max_hash_size_in_mb: NonZeroUsize,
+    resource_sharing: ResourceSharing,
+}
+
+impl ServerLimits {
+    #[inline]
+    pub fn get_max_sessions(&self) -> NonZeroUsize {
+        self.max_sessions
+    }
+
+    #[inline]
+    pub fn set_max_sessions(&mut self, max_sessions: NonZeroUsize) {
+        self.max_sessions = max_sessions;
+    }
+
+    #[inline]
+    pub fn get_max_threads(&self) -> NonZeroUsize {
+        self.max_threads
+    }
+
+    #[inline]
+    pub fn set_max_threads(&mut self, max_threads: NonZeroUsize) {
+        self.max_threads = max_threads;
+    }
+
+    #[inline]
+    pub fn get_max_hash_size_in_mb(&self) -> NonZeroUsize {
+        self.max_hash_size_in_mb
+    }
+
+    #[inline]
+    pub fn set_max_hash_size_in_mb(&mut self, max_hash_size_in_mb: NonZeroUsize) {
+        self.max_hash_size_in_mb = max_hash_size_in_mb;
+    }
+
+    #[inline]
+    pub fn get_resource_sharing(&self) -> ResourceSharing {
+        self.resource_sharing
+    }
+
+    #[inline]
+    pub fn set_resource_sharing(&mut self, resource_sharing: ResourceSharing) {
+        self.resource_sharing = resource_sharing;
+    }
+
+    #[inline]
+    fn get_partition(&self, total: NonZeroUsize) -> NonZeroUsize {
+        NonZeroUsize::new(total.get() / self.max_sessions).unwrap_or(NonZeroUsize::MIN)
+    }
+
+    /// The number of threads a single session is allowed to use.
+    pub fn get_session_max_threads(&self) -> NonZeroUsize {
+        match self.resource_sharing {
+            ResourceSharing::Shared => self.max_threads,
+            ResourceSharing::Partitioned => self.get_partition(self.max_threads),
+        }
+    }
+
+    /// The transposition table size in MB a single session is allowed to use, which is always a share of the total, so
+    /// that the tables of all the sessions fit in it.
+    #[inline]
+    pub fn get_session_max_hash_size_in_mb(&self) -> NonZeroUsize {
+        self.get_partition(self.max_hash_size_in_mb)
+    }
+}
+
+impl Default for ServerLimits {
+    fn default() -> Self {
+        let max_sessions = DEFAULT_SERVER_MAX_SESSIONS;
+        Self {
+            max_sessions,
+            max_threads: thread::available_parallelism().unwrap_or(max_sessions),
+            max_hash_size_in_mb: NonZeroUsize::new(
+                TIMECAT_DEFAULTS.t_table_size.unwrap() * max_sessions.get(),
+            )
+            .unwrap_or(NonZeroUsize::MIN),
+            resource_sharing: ResourceSharing::default(),
+        }
+    }
+}
+
+enum ServerListener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener, PathBuf),
+}
+
+/// A connected client, which can be split into the reading and the writing half.
+trait SessionStream: Read + Write + Send + Sized + 'static {
+    fn try_clone_stream(&self) -> io::Result<Self>;
+
+    /// Closes both halves, which also ends the thread still reading from the client.
+    fn shutdown_stream(&self);
+}
+
+impl SessionStream for TcpStream {
+    #[inline]
+    fn try_clone_stream(&self) -> io::Result<Self> {
+        self.try_clone()
+    }
+
+    #[inline]
+    fn shutdown_stream(&self) {
+        let _ = self.shutdown(std::net::Shutdown::Both);
+    }
+}
+
+#[cfg(unix)]
+impl SessionStream for UnixStream {
+    #[inline]
+    fn try_clone_stream(&self) -> io::Result<Self> {
+        self.try_clone()
+    }
+
+    #[inline]
+    fn shutdown_stream(&self) {
+        let _ = self.shutdown(std::net::Shutdown::Both);
+    }
+}
+
+/// Counts a session as active for as long as it lives.
+struct SessionGuard(Arc<AtomicUsize>);
+
+impl SessionGuard {
+    fn try_new(num_active_sessions: &Arc<AtomicUsize>, max_sessions: NonZeroUsize) -> Option<Self> {
+        num_active_sessions
+            .fetch_update(MEMORY_ORDERING, MEMORY_ORDERING, |num_sessions| {
+                (num_sessions < max_sessions.get()).then_some(num_sessions + 1)
+            })
+            .ok()?;
+        Some(Self(num_active_sessions.clone()))
+    }
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, MEMORY_ORDERING);
+    }
+}
+
+/// Serves the engine over a TCP port or a Unix socket. Every connection gets its own [`Timecat`] session speaking UCI,
+/// with the threads and hash it can use capped by the [`ServerLimits`]. A session ends, freeing its resources, when the
+/// client sends quit or disconnects.
+pub struct TimecatServer {
+    listener: ServerListener,
+    limits: ServerLimits,
+    num_active_sessions: Arc<AtomicUsize>,
+}
+
+impl TimecatServer {
+    pub fn bind(address: &ServerAddress, limits: ServerLimits) -> Result<Self> {
+        let listener = match address {
+            ServerAddress::Tcp(address) => ServerListener::Tcp(TcpListener::bind(address)?),
+            #[cfg(unix)]
+            ServerAddress::Unix(path) => {
+                ServerListener::Unix(UnixListener::bind(path)?, path.to_owned())
+            }
+        };
+        Ok(Self {
+            listener,
+            limits,
+            num_active_sessions: Default::default(),
+        })
+    }
+
+    #[inline]
+    pub fn get_limits(&self) -> ServerLimits {
+        self.limits
+    }
+
+    /// The TCP address the server is bound to, which is useful when binding to port 0.
+    pub fn get_local_tcp_address(&self) -> Option<SocketAddr> {
+        match &self.listener {
+            ServerListener::Tcp(listener) => listener.local_addr().ok(),
+            #[cfg(unix)]
+            ServerListener::Unix(..) => None,
+        }
+    }
+
+    #[inline]
+    pub fn get_num_active_sessions(&self) -> usize {
+        self.num_active_sessions.load(MEMORY_ORDERING)
+    }
+
+    fn get_session_uci_state_manager(limits: ServerLimits) -> UCIStateManager<Engine> {
+        let mut uci_state_manager = UCIStateManager::default();
+        uci_state_manager
+            .restrict_spin_option("Threads", limits.get_session_max_threads().get() as Spin)
+            .unwrap();
+        uci_state_manager
+            .restrict_spin_option(
+                "Hash",
+                limits.get_session_max_hash_size_in_mb().get() as Spin,
+            )
+            .unwrap();
+        uci_state_manager
+    }
+
+    fn get_session_engine(limits: ServerLimits) -> Engine {
+        let mut engine = Engine::default();
+        let mut config = TimecatConfig::default();
+        config.set_uci_mode(true);
+        #[cfg(feature = "colored")]
+        config.set_colored_output(false);
+        *engine.get_config_mut() = config;
+        engine.set_num_threads(
+            TIMECAT_DEFAULTS
+                .num_threads
+                .min(limits.get_session_max_threads()),
+        );
+        let max_hash_size_in_mb = limits.get_session_max_hash_size_in_mb().get();
+        if TIMECAT_DEFAULTS.t_table_size.unwrap() > max_hash_size_in_mb {
+            engine.set_transposition_table_size(CacheTableSize::Exact(max_hash_size_in_mb));
+        }
+        engine
+    }
+
+    fn start_session<S: SessionStream>(&self, stream: S) -> io::Result<()> {
+        let Some(session_guard) =
+            SessionGuard::try_new(&self.num_active_sessions, self.limits.get_max_sessions())
+        else {
+            let mut stream = stream;
+            writeln!(
+                stream,
+                "info string Server is full with {} sessions, please try again later!",
+                self.limits.get_max_sessions()
+            )?;
+            return Ok(());
+        };
+        let io_reader =
+            IoReader::from_reader_quitting_at_end(BufReader::new(stream.try_clone_stream()?));
+        let control_stream = stream.try_clone_stream()?;
+        let output_sink: Arc<dyn OutputSink> = Arc::new(WriterSink::new(stream));
+        let limits = self.limits;
+        thread::spawn(move || {
+            let _session_guard = session_guard;
+            TimecatBuilder::default()
+                .set_engine(Self::get_session_engine(limits))
+                .set_output_sink(output_sink)
+                .set_io_reader(io_reader)
+                .set_uci_state_manager(Self::get_session_uci_state_manager(limits))
+                .build()
+                .run();
+            control_stream.shutdown_stream();
+        });
+        Ok(())
+    }
+
+    /// Binds the server described by the arguments, see [`parse_server_args`], and runs it.
+    pub fn serve_from_args(args: &[&str]) -> Result<()> {
+        let (address, limits) = parse_server_args(args)?;
+        let server = Self::bind(&address, limits)?;
+        force_println_info("Serving on", address);
+        server.run()
+    }
+
+    /// Accepts connections forever, running every session in its own thread.
+    pub fn run(&self) -> Result<()> {
+        loop {
+            let result = match &self.listener {
+                ServerListener::Tcp(listener) => listener
+                    .accept()
+                    .and_then(|(stream, _)| self.start_session(stream)),
+                #[cfg(unix)]
+                ServerListener::Unix(listener, _) => listener
+                    .accept()
+                    .and_then(|(stream, _)| self.start_session(stream)),
+            };
+            // Failing to accept a connection, like running out of file descriptors, or a client disconnecting before
+            // its session starts must not bring down the server.
+            if let Err(error) = result {
+                println_info("Failed to start session", error);
+                thread::sleep(SERVER_ACCEPT_RETRY_DELAY);
+            }
+        }
+    }
+}
+
+impl Drop for TimecatServer {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let ServerListener::Unix(_, path) = &self.listener {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Parses the arguments of the serve mode, which are `serve <ADDRESS>` (or `--serve <ADDRESS>`) followed by the
+/// optional `--max-sessions`, `--max-threads`, `--max-hash` (in MB) and `--shared-resources`.
+pub fn parse_server_args(args: &[&str]) -> Result<(ServerAddress, ServerLimits)> {
+    let cli_args = CliArgs::parse(args)?;
+    if cli_args.get_subcommand() != Some("serve") {
+        return Err(TimecatError::MissingCliArgument {
+            name: "serve".to_string(),
+        });
+    }
+    let address = cli_args.get_argument().unwrap_or_default().parse()?;
+    let mut limits = ServerLimits::default();
+    if let Some(max_sessions) = cli_args.parse_option("max-sessions")? {
+        limits.set_max_sessions(max_sessions);
+    }
+    if let Some(max_threads) = cli_args.parse_option("max-threads")? {
+        limits.set_max_threads(max_threads);
+    }
+    if let Some(max_hash_size_in_mb) = cli_args.parse_option("max-hash")? {
+        limits.set_max_hash_size_in_mb(max_hash_size_in_mb);
+    }
+    if cli_args.has_flag("shared-resources") {
+        limits.set_resource_sharing(ResourceSharing::Shared);
+    }
+    Ok((address, limits))
+}
@@ -87,6 +87,8 @@ pub struct MoveSorter {
     killer_moves: [SerdeWrapper<[Option<Move>; NUM_KILLER_MOVES]>; MAX_PLY],
     #[cfg_attr(feature = "serde", serde(with = "SerdeHandler"))]
     history_move_scores: [SerdeWrapper<[MoveWeight; 64]>; 12],
+    #[cfg_attr(feature = "serde", serde(with = "SerdeHandler"))]
+    threat_moves: [Option<Move>; MAX_PLY],
     follow_pv: bool,
     score_pv: bool,
 }
@@ -101,6 +103,7 @@ impl MoveSorter {
             .fill(const { SerdeWrapper::new([None; NUM_KILLER_MOVES]) });
         self.history_move_scores
             .fill(const { SerdeWrapper::new([0; 64]) });
+        self.threat_moves.fill(None);
         self.follow_pv = false;
         self.score_pv = false;
     }
@@ -116,20 +119,35 @@ impl MoveSorter {
         get_item_unchecked!(self.killer_moves, ply).contains(&Some(move_))
     }
 
+    /// Remembers the best reply of the opponent to a null move at the ply, which is what it threatens to play.
+    #[inline]
+    pub fn set_threat_move(&mut self, optional_threat_move: Option<Move>, ply: Ply) {
+        *get_item_unchecked_mut!(self.threat_moves, ply) = optional_threat_move;
+    }
+
+    #[inline]
+    pub fn get_threat_move(&self, ply: Ply) -> Option<Move> {
+        *get_item_unchecked!(self.threat_moves, ply)
+    }
+
     pub fn add_history_move(&mut self, history_move: Move, position: &BoardPosition, depth: Depth) {
         let depth = (depth as MoveWeight).pow(2);
-        let src = history_move.get_source();
         let dest = history_move.get_dest();
-        let piece = position.get_piece_at(src).unwrap();
+        let piece = position
+            .get_moving_piece_type(history_move)
+            .unwrap()
+            .to_colored_piece(position.turn());
         *get_item_unchecked_mut!(self.history_move_scores, piece.to_index(), dest.to_index()) +=
             depth;
     }
 
     #[inline]
     pub fn get_history_score(&self, history_move: Move, position: &BoardPosition) -> MoveWeight {
-        let src = history_move.get_source();
         let dest = history_move.get_dest();
-        let piece = position.get_piece_at(src).unwrap();
+        let piece = position
+            .get_moving_piece_type(history_move)
+            .unwrap()
+            .to_colored_piece(position.turn());
         *get_item_unchecked!(self.history_move_scores, piece.to_index(), dest.to_index())
     }
 
@@ -194,10 +212,7 @@ impl MoveSorter {
     fn mvv_lva(move_: Move, position: &BoardPosition) -> MoveWeight {
         *get_item_unchecked!(
             MVV_LVA,
-            position
-                .get_piece_type_at(move_.get_source())
-                .unwrap()
-                .to_index(),
+            position.get_moving_piece_type(move_).unwrap().to_index(),
             position
                 .get_piece_type_at(move_.get_dest())
                 .unwrap_or(Pawn)
@@ -221,7 +236,8 @@ impl MoveSorter {
         let moving_piece = position.get_piece_type_at(source).unwrap();
         if moving_piece != Pawn {
             let losing_color = !position.get_winning_side().unwrap_or(White);
-            let losing_king_square = position.get_king_square(losing_color);
+            // The losing side can have no king, like white in Horde.
+            let losing_king_square = position.get_king_square(losing_color)?;
             if losing_king_square == source {
                 return Some(-100 * source.distance(Square::E4) as MoveWeight);
             }
@@ -271,6 +287,13 @@ impl MoveSorter {
                 return 500000 - idx as MoveWeight;
             }
         }
+        // moving the piece under threat away
+        if self
+            .get_threat_move(ply)
+            .is_some_and(|threat_move| threat_move.get_dest() == move_.get_source())
+        {
+            return 450000 + self.get_history_score(move_, position);
+        }
         // history
         let history_score = self.get_history_score(move_, position);
         if history_score != 0 {
@@ -279,7 +302,7 @@ impl MoveSorter {
         let move_made_position = position.make_move_new(move_);
         // check
         let checkers = move_made_position.get_checkers();
-        let moving_piece = position.get_piece_type_at(move_.get_source()).unwrap();
+        let moving_piece = position.get_moving_piece_type(move_).unwrap();
         if !checkers.is_empty() {
             return -700000 + 10 * checkers.popcnt() as MoveWeight - moving_piece as MoveWeight;
         }
@@ -405,6 +428,7 @@ impl Default for MoveSorter {
         Self {
             killer_moves: const { [SerdeWrapper::new([None; NUM_KILLER_MOVES]); MAX_PLY] },
             history_move_scores: const { [SerdeWrapper::new([0; 64]); 12] },
+            threat_moves: [None; MAX_PLY],
             follow_pv: false,
             score_pv: false,
         }
@@ -0,0 +1,230 @@
+use super::*;
+
+#[inline]
+fn scale_duration(duration: Duration, factor: f64) -> Duration {
+    Duration::try_from_secs_f64(duration.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+}
+
+/// Splits the clock into a soft (optimum) and a hard (maximum) time limit and scales the soft
+/// limit between iterations depending on how stable the search is.
+///
+/// The time manager never reads a clock by itself, all the methods take the time elapsed since
+/// the start of the search, so it can be driven with arbitrary durations.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct TimeManager {
+    move_overhead: Duration,
+    optimum_time: Duration,
+    maximum_time: Duration,
+    is_fixed_time: bool,
+    last_best_move: Option<Move>,
+    best_move_stability: usize,
+    best_move_changes: f64,
+    previous_score: Option<Score>,
+    score_drop: Score,
+    best_move_nodes_fraction: f64,
+}
+
+impl TimeManager {
+    pub fn new(move_overhead: Duration) -> Self {
+        Self {
+            move_overhead,
+            optimum_time: Duration::MAX,
+            maximum_time: Duration::MAX,
+            is_fixed_time: false,
+            last_best_move: None,
+            best_move_stability: 0,
+            best_move_changes: 0.0,
+            previous_score: None,
+            score_drop: 0,
+            best_move_nodes_fraction: 0.0,
+        }
+    }
+
+    #[inline]
+    pub fn get_move_overhead(&self) -> Duration {
+        self.move_overhead
+    }
+
+    #[inline]
+    pub fn set_move_overhead(&mut self, duration: Duration) {
+        self.move_overhead = duration;
+    }
+
+    #[inline]
+    pub fn get_optimum_time(&self) -> Duration {
+        self.optimum_time
+    }
+
+    #[inline]
+    pub fn get_maximum_time(&self) -> Duration {
+        self.maximum_time
+    }
+
+    #[inline]
+    pub fn is_time_limited(&self) -> bool {
+        self.maximum_time != Duration::MAX
+    }
+
+    /// Whether the search was given a fixed time, which is then used up regardless of the stability of the search.
+    #[inline]
+    pub fn is_fixed_time(&self) -> bool {
+        self.is_fixed_time
+    }
+
+    #[inline]
+    pub fn get_best_move_stability(&self) -> usize {
+        self.best_move_stability
+    }
+
+    pub fn reset_variables(&mut self) {
+        self.optimum_time = Duration::MAX;
+        self.maximum_time = Duration::MAX;
+        self.is_fixed_time = false;
+        self.last_best_move = None;
+        self.best_move_stability = 0;
+        self.best_move_changes = 0.0;
+        self.previous_score = None;
+        self.score_drop = 0;
+        self.best_move_nodes_fraction = 0.0;
+    }
+
+    /// Sets both the limits to the given duration, unless they are already tighter.
+    fn limit_time(&mut self, duration: Duration) {
+        self.optimum_time = self.optimum_time.min(duration);
+        self.maximum_time = self.maximum_time.min(duration);
+    }
+
+    /// Limits the search to the given duration, turning off the scaling of the soft limit so that the whole duration
+    /// is used.
+    pub fn set_fixed_time(&mut self, duration: Duration) {
+        self.limit_time(duration);
+        self.is_fixed_time = true;
+    }
+
+    /// Number of moves the remaining clock time is expected to be spread over in sudden-death.
+    fn get_expected_moves_left(fullmove_number: NumMoves) -> u32 {
+        (SUDDEN_DEATH_MOVES_HORIZON as u32)
+            .saturating_sub(fullmove_number as u32 / 2)
+            .max(SUDDEN_DEATH_MIN_MOVES_LEFT as u32)
+    }
+
+    pub fn set_time_clock(
+        &mut self,
+        time_clock: &TimedGoCommand,
+        turn: Color,
+        fullmove_number: NumMoves,
+    ) {
+        let (self_time, self_inc) = match turn {
+            White => (time_clock.wtime, time_clock.winc),
+            Black => (time_clock.btime, time_clock.binc),
+        };
+        // A control without any moves to go cannot be split, so it is treated as sudden-death.
+        let moves_to_go = time_clock
+            .moves_to_go
+            .filter(|&moves_to_go| moves_to_go > 0);
+        let (optimum_time, maximum_time) = match moves_to_go {
+            // The control ends after this move, so whatever is left can be used, except for a
+            // safety margin against network or GUI lag.
+            Some(1) => {
+                let time = scale_duration(self_time, LAST_MOVE_BEFORE_TIME_CONTROL_RATIO);
+                (time, time)
+            }
+            Some(moves_to_go) => {
+                let moves_to_go = (moves_to_go as u32).min(SUDDEN_DEATH_MOVES_HORIZON as u32);
+                let time_left = self_time.saturating_add(self_inc.saturating_mul(moves_to_go - 1));
+                let optimum_time = time_left / moves_to_go;
+                let maximum_time = scale_duration(optimum_time, MAXIMUM_TIME_RATIO)
+                    .min(scale_duration(self_time, MOVES_TO_GO_MAXIMUM_USAGE_RATIO));
+                (optimum_time, maximum_time)
+            }
+            None => {
+                let moves_left = Self::get_expected_moves_left(fullmove_number);
+                let time_left = self_time.saturating_add(self_inc.saturating_mul(moves_left - 1));
+                let optimum_time = time_left / moves_left;
+                let maximum_time = scale_duration(optimum_time, MAXIMUM_TIME_RATIO)
+                    .min(scale_duration(self_time, SUDDEN_DEATH_MAXIMUM_USAGE_RATIO));
+                (optimum_time, maximum_time)
+            }
+        };
+        // The move overhead is left to the hard limit check, so that it is only counted once.
+        let maximum_time = maximum_time.min(self_time);
+        self.limit_time(maximum_time);
+        self.optimum_time = self.optimum_time.min(optimum_time.min(maximum_time));
+    }
+
+    /// Updates the stability information after each completed iteration. The nodes fraction is
+    /// the fraction of the nodes of the iteration spent on the best root move.
+    pub fn update_on_iteration(
+        &mut self,
+        best_move: Option<Move>,
+        score: Score,
+        best_move_nodes_fraction: f64,
+    ) {
+        self.best_move_changes *= BEST_MOVE_CHANGES_DECAY;
+        if best_move.is_some() && best_move == self.last_best_move {
+            self.best_move_stability += 1;
+        } else {
+            if self.last_best_move.is_some() {
+                self.best_move_changes += 1.0;
+            }
+            self.best_move_stability = 0;
+        }
+        self.last_best_move = best_move;
+        self.score_drop = self
+            .previous_score
+            .map_or(0, |previous_score| previous_score.saturating_sub(score))
+            .max(0);
+        self.previous_score = Some(score);
+        self.best_move_nodes_fraction = best_move_nodes_fraction.clamp(0.0, 1.0);
+    }
+
+    /// The factor by which the optimum time is multiplied, given the current stability of the
+    /// best move and the score.
+    pub fn get_optimum_time_scale(&self) -> f64 {
+        let stability_factor = STABILITY_SCALE_MAX
+            - STABILITY_SCALE_STEP * self.best_move_stability.min(MAX_STABILITY_SCALE_STEPS) as f64;
+        let score_drop_factor = 1.0
+            + SCORE_DROP_SCALE * (self.score_drop as f64 / SCORE_DROP_NORMALIZER as f64).min(1.0);
+        let best_move_changes_factor = 1.0 + BEST_MOVE_CHANGES_SCALE * self.best_move_changes;
+        let nodes_fraction_factor = if self.best_move_nodes_fraction > 0.0 {
+            (NODES_FRACTION_BASE - self.best_move_nodes_fraction) * NODES_FRACTION_SCALE
+        } else {
+            1.0
+        };
+        stability_factor * score_drop_factor * best_move_changes_factor * nodes_fraction_factor
+    }
+
+    /// The soft limit scaled by the stability of the search, never exceeding the hard limit. A fixed time is never
+    /// scaled.
+    pub fn get_scaled_optimum_time(&self) -> Duration {
+        if !self.is_time_limited() {
+            return Duration::MAX;
+        }
+        if self.is_fixed_time {
+            return self.optimum_time;
+        }
+        scale_duration(self.optimum_time, self.get_optimum_time_scale()).min(self.maximum_time)
+    }
+
+    /// Checked between iterations, a new iteration is not started once the soft limit is
+    /// reached.
+    #[inline]
+    pub fn is_soft_limit_reached(&self, time_elapsed: Duration) -> bool {
+        self.is_time_limited() && time_elapsed >= self.get_scaled_optimum_time()
+    }
+
+    /// Checked at every node, the search is aborted once the hard limit is reached, leaving the move overhead for
+    /// sending the move.
+    #[inline]
+    pub fn is_hard_limit_reached(&self, time_elapsed: Duration) -> bool {
+        self.is_time_limited()
+            && time_elapsed.saturating_add(self.move_overhead) >= self.maximum_time
+    }
+}
+
+impl Default for TimeManager {
+    fn default() -> Self {
+        Self::new(TIMECAT_DEFAULTS.move_overhead)
+    }
+}
@@ -51,61 +51,382 @@ impl Default for TranspositionTableData {
     }
 }
 
+/// A packed 10 byte entry.
+///
+/// The lowest bit of `generation_and_flag` marks the entry as occupied, the next two bits store the
+/// bound (or the absence of search data) and the remaining bits store the search generation.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[repr(C, packed(2))]
 pub struct TranspositionTableEntry {
-    optional_data: Option<TranspositionTableData>,
-    best_move: Option<Move>,
+    key: u32,
+    best_move: u16,
+    score: Score,
+    depth: Depth,
+    generation_and_flag: u8,
 }
 
 impl TranspositionTableEntry {
-    fn new(optional_data: Option<TranspositionTableData>, best_move: Option<Move>) -> Self {
+    const OCCUPIED_MASK: u8 = 0b1;
+    const FLAG_SHIFT: u8 = 1;
+    const FLAG_MASK: u8 = 0b11;
+    const NO_DATA_FLAG: u8 = 3;
+    const GENERATION_SHIFT: u8 = 3;
+
+    fn new(
+        key: u32,
+        optional_data: Option<TranspositionTableData>,
+        best_move: Option<Move>,
+        generation: u8,
+    ) -> Self {
+        let data = optional_data.unwrap_or_default();
+        let flag = match optional_data {
+            Some(TranspositionTableData {
+                flag: EntryFlagHash::Exact,
+                ..
+            }) => 0,
+            Some(TranspositionTableData {
+                flag: EntryFlagHash::Alpha,
+                ..
+            }) => 1,
+            Some(TranspositionTableData {
+                flag: EntryFlagHash::Beta,
+                ..
+            }) => 2,
+            None => Self::NO_DATA_FLAG,
+        };
         Self {
-            optional_data,
+            key,
+            best_move: best_move.compress(),
+            score: data.score,
+            depth: data.depth,
+            generation_and_flag: Self::OCCUPIED_MASK
+                | (flag << Self::FLAG_SHIFT)
+                | (generation << Self::GENERATION_SHIFT),
+        }
+    }
+
+    #[inline]
+    const fn is_occupied(self) -> bool {
+        self.generation_and_flag & Self::OCCUPIED_MASK != 0
+    }
+
+    #[inline]
+    const fn get_key(self) -> u32 {
+        self.key
+    }
+
+    #[inline]
+    const fn get_generation(self) -> u8 {
+        self.generation_and_flag >> Self::GENERATION_SHIFT
+    }
+
+    #[inline]
+    fn get_best_move(self) -> Option<Move> {
+        self.best_move.decompress()
+    }
+
+    fn get_optional_data(self) -> Option<TranspositionTableData> {
+        let flag = match (self.generation_and_flag >> Self::FLAG_SHIFT) & Self::FLAG_MASK {
+            0 => EntryFlagHash::Exact,
+            1 => EntryFlagHash::Alpha,
+            2 => EntryFlagHash::Beta,
+            _ => return None,
+        };
+        Some(TranspositionTableData {
+            depth: self.depth,
+            score: self.score,
+            flag,
+        })
+    }
+
+    #[inline]
+    const fn get_relative_age(self, generation: u8) -> u8 {
+        (generation + TRANSPOSITION_TABLE_NUM_GENERATIONS - self.get_generation())
+            % TRANSPOSITION_TABLE_NUM_GENERATIONS
+    }
+
+    fn to_bytes(self) -> [u8; 10] {
+        let mut bytes = [0; 10];
+        bytes[0..4].copy_from_slice(&{ self.key }.to_le_bytes());
+        bytes[4..6].copy_from_slice(&{ self.best_move }.to_le_bytes());
+        bytes[6..8].copy_from_slice(&{ self.score }.to_le_bytes());
+        bytes[8] = self.depth as u8;
+        bytes[9] = self.generation_and_flag;
+        bytes
+    }
+
+    fn from_bytes(bytes: [u8; 10]) -> Self {
+        Self {
+            key: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            best_move: u16::from_le_bytes([bytes[4], bytes[5]]),
+            score: Score::from_le_bytes([bytes[6], bytes[7]]),
+            depth: bytes[8] as Depth,
+            generation_and_flag: bytes[9],
+        }
+    }
+
+    /// The lower the worth, the sooner the entry gets replaced by an entry of another position.
+    fn get_replacement_worth(self, generation: u8) -> i16 {
+        if !self.is_occupied() {
+            return i16::MIN;
+        }
+        let depth = self.get_optional_data().map_or(-1, |data| data.depth);
+        depth as i16 - TRANSPOSITION_TABLE_AGE_WEIGHT * self.get_relative_age(generation) as i16
+    }
+}
+
+/// Lock-free storage of a [`TranspositionTableEntry`] in five 16 bit words.
+///
+/// The two key words are stored xored with the data words, so an entry being written by another thread while being
+/// read is detected, as the recovered key will not match.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default)]
+struct AtomicTranspositionTableEntry {
+    words: [AtomicU16; 5],
+}
+
+impl AtomicTranspositionTableEntry {
+    #[inline]
+    const fn get_data_words(entry: TranspositionTableEntry) -> [u16; 3] {
+        [
+            entry.best_move,
+            entry.score as u16,
+            entry.depth as u8 as u16 | (entry.generation_and_flag as u16) << 8,
+        ]
+    }
+
+    fn load(&self) -> TranspositionTableEntry {
+        let [key_low, key_high, best_move, score, depth_and_flag] =
+            self.words.each_ref().map(|word| word.load(MEMORY_ORDERING));
+        TranspositionTableEntry {
+            key: ((key_high ^ score ^ depth_and_flag) as u32) << 16
+                | (key_low ^ best_move ^ depth_and_flag) as u32,
             best_move,
+            score: score as Score,
+            depth: depth_and_flag as u8 as Depth,
+            generation_and_flag: (depth_and_flag >> 8) as u8,
         }
     }
+
+    fn store(&self, entry: TranspositionTableEntry) {
+        let [best_move, score, depth_and_flag] = Self::get_data_words(entry);
+        let key = { entry.key };
+        let words = [
+            key as u16 ^ best_move ^ depth_and_flag,
+            (key >> 16) as u16 ^ score ^ depth_and_flag,
+            best_move,
+            score,
+            depth_and_flag,
+        ];
+        for (word, value) in self.words.iter().zip(words) {
+            word.store(value, MEMORY_ORDERING);
+        }
+    }
+
+    #[inline]
+    fn clear(&self) {
+        self.store(TranspositionTableEntry::default());
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default)]
+#[repr(C, align(32))]
+pub struct TranspositionTableCluster {
+    entries: [AtomicTranspositionTableEntry; TRANSPOSITION_TABLE_CLUSTER_SIZE],
+}
+
+unsafe impl ZeroInitialized for TranspositionTableCluster {}
+
+impl TranspositionTableCluster {
+    #[inline]
+    fn load_entries(&self) -> [TranspositionTableEntry; TRANSPOSITION_TABLE_CLUSTER_SIZE] {
+        self.entries
+            .each_ref()
+            .map(AtomicTranspositionTableEntry::load)
+    }
+
+    #[inline]
+    fn get_entry(&self, key: u32) -> Option<TranspositionTableEntry> {
+        self.load_entries()
+            .into_iter()
+            .find(|entry| entry.is_occupied() && entry.get_key() == key)
+    }
+
+    fn get_replacement_index(
+        entries: &[TranspositionTableEntry; TRANSPOSITION_TABLE_CLUSTER_SIZE],
+        key: u32,
+        generation: u8,
+    ) -> usize {
+        entries
+            .iter()
+            .position(|entry| entry.is_occupied() && entry.get_key() == key)
+            .unwrap_or_else(|| {
+                entries
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, entry)| entry.get_replacement_worth(generation))
+                    .map(|(index, _)| index)
+                    .unwrap()
+            })
+    }
+}
+
+struct HashFileWriter<W: Write> {
+    writer: W,
+    hasher: Fnv1aHasher,
+}
+
+impl<W: Write> HashFileWriter<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            hasher: Fnv1aHasher::default(),
+        }
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.hasher.write(bytes);
+        self.writer.write_all(bytes)?;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<()> {
+        let checksum = self.hasher.finish();
+        self.writer.write_all(&checksum.to_le_bytes())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+struct HashFileReader<R: Read> {
+    reader: R,
+    hasher: Fnv1aHasher,
 }
 
+impl<R: Read> HashFileReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            hasher: Fnv1aHasher::default(),
+        }
+    }
+
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut bytes = [0; N];
+        self.reader
+            .read_exact(&mut bytes)
+            .map_err(|_| TimecatError::BadHashFile)?;
+        self.hasher.write(&bytes);
+        Ok(bytes)
+    }
+
+    fn finish(mut self) -> Result<()> {
+        let expected_checksum = self.hasher.finish();
+        let checksum = u64::from_le_bytes(self.read_bytes()?);
+        if checksum != expected_checksum {
+            return Err(TimecatError::BadHashFile);
+        }
+        Ok(())
+    }
+}
+
+/// A transposition table without any lock. Resizing and loading replace the table, so [`set_size`](Self::set_size)
+/// and [`load_hash`](Self::load_hash) must only be called between searches, when no other thread uses it.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[repr(transparent)]
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct TranspositionTable {
-    table: CacheTable<TranspositionTableEntry>,
+    table: HugePageBufferCell<TranspositionTableCluster>,
+    size: RwLock<CacheTableSize>,
+    num_threads: AtomicUsize,
+    generation: AtomicU8,
+    #[cfg(feature = "extras")]
+    num_overwrites: AtomicUsize,
+    #[cfg(feature = "extras")]
+    num_collisions: AtomicUsize,
+    #[cfg(feature = "extras")]
+    zero_hit: AtomicUsize,
 }
 
 impl TranspositionTable {
+    #[inline]
+    pub const fn get_cluster_size() -> usize {
+        size_of::<TranspositionTableCluster>()
+    }
+
     #[inline]
     pub fn print_info(&self) {
-        print_cache_table_info("Hash Table", self.table.len(), self.table.get_size());
+        print_cache_table_info("Hash Table", self.len(), self.get_size());
+        println_info("Hash Table Memory", self.get_allocation_kind());
     }
 
     #[inline]
-    fn generate_new_table(cache_table_size: CacheTableSize) -> CacheTable<TranspositionTableEntry> {
-        CacheTable::new(cache_table_size)
+    fn generate_new_table(
+        cache_table_size: CacheTableSize,
+        num_threads: NonZeroUsize,
+    ) -> HugePageBuffer<TranspositionTableCluster> {
+        let num_clusters = cache_table_size
+            .to_num_cells_and_cell_size(Self::get_cluster_size())
+            .0
+            .max(1);
+        HugePageBuffer::new_zeroed(num_clusters, num_threads)
     }
 
     pub fn new(cache_table_size: CacheTableSize) -> Self {
         Self {
-            table: Self::generate_new_table(cache_table_size),
+            table: Self::generate_new_table(cache_table_size, TIMECAT_DEFAULTS.num_threads).into(),
+            size: RwLock::new(cache_table_size),
+            num_threads: AtomicUsize::new(TIMECAT_DEFAULTS.num_threads.get()),
+            generation: AtomicU8::new(0),
+            #[cfg(feature = "extras")]
+            num_overwrites: AtomicUsize::new(0),
+            #[cfg(feature = "extras")]
+            num_collisions: AtomicUsize::new(0),
+            #[cfg(feature = "extras")]
+            zero_hit: AtomicUsize::new(0),
         }
     }
 
+    #[inline]
+    fn get_index(num_clusters: usize, key: u64) -> usize {
+        if num_clusters.is_power_of_two() {
+            key as usize & (num_clusters - 1)
+        } else {
+            key as usize % num_clusters
+        }
+    }
+
+    /// The cluster is chosen using the lower bits of the key, so the upper bits are stored for verification.
+    #[inline]
+    const fn get_entry_key(key: u64) -> u32 {
+        (key >> 32) as u32
+    }
+
+    #[inline]
+    fn get_cluster(table: &[TranspositionTableCluster], key: u64) -> &TranspositionTableCluster {
+        get_item_unchecked!(table, Self::get_index(table.len(), key))
+    }
+
+    #[inline]
+    fn get_entry(&self, key: u64) -> Option<TranspositionTableEntry> {
+        Self::get_cluster(self.table.get(), key).get_entry(Self::get_entry_key(key))
+    }
+
     pub fn read(
         &self,
         key: u64,
         depth: Depth,
         ply: Ply,
     ) -> (Option<(Score, EntryFlagHash)>, Option<Move>) {
-        let tt_entry = match self.table.get(key) {
+        let tt_entry = match self.get_entry(key) {
             Some(entry) => entry,
             None => return (None, None),
         };
-        let best_move = tt_entry.best_move;
-        if tt_entry.optional_data.is_none() {
+        let best_move = tt_entry.get_best_move();
+        let Some(data) = tt_entry.get_optional_data() else {
             return (None, best_move);
-        }
-        let data = tt_entry.optional_data.unwrap();
+        };
         if data.depth < depth {
             return (None, best_move);
         }
@@ -122,7 +443,7 @@ impl TranspositionTable {
 
     #[inline]
     pub fn read_best_move(&self, key: u64) -> Option<Move> {
-        self.table.get(key)?.best_move
+        self.get_entry(key)?.get_best_move()
     }
 
     pub fn write(
@@ -148,50 +469,301 @@ impl TranspositionTable {
                 -mate_score
             };
         }
-        let old_optional_entry = self.table.get(key);
+        let entry_key = Self::get_entry_key(key);
+        let generation = self.get_generation();
+        let cluster = Self::get_cluster(self.table.get(), key);
+        let entries = cluster.load_entries();
+        let replacement_index =
+            TranspositionTableCluster::get_replacement_index(&entries, entry_key, generation);
+        let old_entry = *get_item_unchecked!(entries, replacement_index);
+        let is_same_position = old_entry.is_occupied() && old_entry.get_key() == entry_key;
         let optional_data = if save_score {
-            let old_optional_data = old_optional_entry.and_then(|tt_entry| tt_entry.optional_data);
-            if old_optional_data.map_or(-1, |data| data.depth) < depth {
-                Some(TranspositionTableData { depth, score, flag })
-            } else {
-                old_optional_data
+            let old_optional_data = old_entry.get_optional_data().filter(|_| is_same_position);
+            match old_optional_data {
+                Some(old_data)
+                    if flag != EntryFlagHash::Exact
+                        && old_entry.get_generation() == generation
+                        && old_data.depth
+                            >= depth
+                                .saturating_add(TRANSPOSITION_TABLE_REPLACEMENT_DEPTH_MARGIN) =>
+                {
+                    Some(old_data)
+                }
+                _ => Some(TranspositionTableData { depth, score, flag }),
             }
         } else {
             None
         };
-        self.table.add(
-            key,
-            TranspositionTableEntry::new(
-                optional_data,
-                best_move.or(old_optional_entry.and_then(|tt_entry| tt_entry.best_move)),
-            ),
+        let new_entry = TranspositionTableEntry::new(
+            entry_key,
+            optional_data,
+            best_move.or(if is_same_position {
+                old_entry.get_best_move()
+            } else {
+                None
+            }),
+            generation,
         );
+        get_item_unchecked!(cluster.entries, replacement_index).store(new_entry);
+        #[cfg(feature = "extras")]
+        if is_same_position {
+            if old_entry != new_entry {
+                self.num_overwrites.fetch_add(1, MEMORY_ORDERING);
+            }
+        } else if old_entry.is_occupied() {
+            self.num_collisions.fetch_add(1, MEMORY_ORDERING);
+        }
     }
 
     #[inline]
     pub fn clear_best_moves(&self) {
         self.table
-            .get_table()
-            .write()
-            .unwrap()
-            .iter_mut()
-            .flatten()
-            .for_each(|entry| {
-                entry.get_entry_mut().best_move = None;
+            .get()
+            .iter()
+            .flat_map(|cluster| cluster.entries.iter())
+            .for_each(|atomic_entry| {
+                let mut entry = atomic_entry.load();
+                if entry.is_occupied() {
+                    entry.best_move = None::<Move>.compress();
+                    atomic_entry.store(entry);
+                }
+            });
+    }
+
+    /// Starts a new search, so that the entries of the previous searches get replaced first.
+    #[inline]
+    pub fn increment_generation(&self) {
+        self.generation.store(
+            (self.get_generation() + 1) % TRANSPOSITION_TABLE_NUM_GENERATIONS,
+            MEMORY_ORDERING,
+        );
+    }
+
+    #[inline]
+    pub fn get_generation(&self) -> u8 {
+        self.generation.load(MEMORY_ORDERING)
+    }
+
+    #[inline]
+    pub fn clear(&self) {
+        self.table
+            .get()
+            .for_each_parallel(self.get_num_threads(), |cluster| {
+                cluster
+                    .entries
+                    .iter()
+                    .for_each(AtomicTranspositionTableEntry::clear)
             });
+        self.generation.store(0, MEMORY_ORDERING);
+        self.reset_variables();
+    }
+
+    #[inline]
+    #[cfg(feature = "extras")]
+    pub fn get_num_overwrites(&self) -> usize {
+        self.num_overwrites.load(MEMORY_ORDERING)
+    }
+
+    #[inline]
+    #[cfg(feature = "extras")]
+    pub fn get_num_collisions(&self) -> usize {
+        self.num_collisions.load(MEMORY_ORDERING)
+    }
+
+    #[inline]
+    #[cfg(feature = "extras")]
+    pub fn get_zero_hit(&self) -> usize {
+        self.zero_hit.load(MEMORY_ORDERING)
+    }
+
+    /// Variable needed to be reset per search
+    pub fn reset_variables(&self) {
+        #[cfg(feature = "extras")]
+        {
+            self.num_overwrites.store(0, MEMORY_ORDERING);
+            self.num_collisions.store(0, MEMORY_ORDERING);
+            self.zero_hit.store(0, MEMORY_ORDERING);
+        }
+    }
+
+    /// Percentage of the sampled entries written during the current search.
+    pub fn get_hash_full(&self) -> f64 {
+        let generation = self.get_generation();
+        let table = self.table.get();
+        let sample = &table[..table.len().min(HASH_FULL_SAMPLE_NUM_CLUSTERS)];
+        let num_filled = sample
+            .iter()
+            .flat_map(|cluster| cluster.load_entries())
+            .filter(|entry| entry.is_occupied() && entry.get_generation() == generation)
+            .count();
+        (num_filled as f64 / (sample.len() * TRANSPOSITION_TABLE_CLUSTER_SIZE) as f64) * 100.0
+    }
+
+    /// Number of entries in the table.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.get().len() * TRANSPOSITION_TABLE_CLUSTER_SIZE
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.table
+            .get()
+            .iter()
+            .flat_map(|cluster| cluster.load_entries())
+            .all(|entry| !entry.is_occupied())
+    }
+
+    #[inline]
+    pub fn get_size(&self) -> CacheTableSize {
+        *self.size.read().unwrap()
+    }
+
+    #[inline]
+    pub fn get_num_threads(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.num_threads.load(MEMORY_ORDERING)).unwrap()
+    }
+
+    /// Sets the number of threads used to zero the table on [`clear`](Self::clear), [`set_size`](Self::set_size) and
+    /// [`load_hash`](Self::load_hash).
+    #[inline]
+    pub fn set_num_threads(&self, num_threads: NonZeroUsize) {
+        self.num_threads.store(num_threads.get(), MEMORY_ORDERING);
+    }
+
+    #[inline]
+    pub fn get_allocation_kind(&self) -> AllocationKind {
+        self.table.get().get_allocation_kind()
+    }
+
+    /// Resizes the table. The entries cannot be moved to their new clusters without their full keys, so the table is
+    /// cleared. Must only be called between searches.
+    pub fn set_size(&self, size: CacheTableSize) {
+        *self.size.write().unwrap() = size;
+        // SAFETY: No search is running, so no other thread holds a reference to the table. The old table is dropped
+        // first, so that both never have to fit in memory at the same time.
+        unsafe {
+            self.table.replace(HugePageBuffer::default());
+            self.table
+                .replace(Self::generate_new_table(size, self.get_num_threads()));
+        }
+        self.generation.store(0, MEMORY_ORDERING);
+        self.reset_variables();
     }
 }
 
-impl Default for TranspositionTable {
-    fn default() -> Self {
-        Self::new(TIMECAT_DEFAULTS.t_table_size)
+impl TranspositionTable {
+    /// Size of the header and the checksum.
+    const HASH_FILE_OVERHEAD_SIZE: u64 = 8 + 4 + 8 + 1 + 8 + 8 + 1 + 8;
+    const HASH_FILE_ENTRY_SIZE: u64 = 10;
+
+    /// Saves the table along with its size, so that a long analysis can be resumed later with [`Self::load_hash`].
+    ///
+    /// The file starts with a versioned header containing a fingerprint of the Zobrist keys and ends with a checksum
+    /// of its contents.
+    pub fn save_hash(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer =
+            HashFileWriter::new(std::io::BufWriter::new(fs::File::create(path.as_ref())?));
+        let (size_type, size) = match self.get_size() {
+            CacheTableSize::Max(size) => (0, size),
+            CacheTableSize::Min(size) => (1, size),
+            CacheTableSize::Round(size) => (2, size),
+            CacheTableSize::Exact(size) => (3, size),
+        };
+        writer.write_bytes(&HASH_FILE_MAGIC)?;
+        writer.write_bytes(&HASH_FILE_VERSION.to_le_bytes())?;
+        writer.write_bytes(&Zobrist::get_fingerprint().to_le_bytes())?;
+        writer.write_bytes(&[size_type])?;
+        writer.write_bytes(&(size as u64).to_le_bytes())?;
+        let table = self.table.get();
+        writer.write_bytes(&(table.len() as u64).to_le_bytes())?;
+        writer.write_bytes(&[self.get_generation()])?;
+        for entry in table.iter().flat_map(|cluster| cluster.load_entries()) {
+            writer.write_bytes(&entry.to_bytes())?;
+        }
+        writer.finish()
+    }
+
+    /// Replaces the table with the one saved by [`Self::save_hash`]. The table is left untouched if the file is corrupt or
+    /// incompatible. Must only be called between searches.
+    pub fn load_hash(&self, path: impl AsRef<Path>) -> Result<()> {
+        let file = fs::File::open(path.as_ref())?;
+        let file_size = file.metadata()?.len();
+        let mut reader = HashFileReader::new(BufReader::new(file));
+        if reader.read_bytes()? != HASH_FILE_MAGIC {
+            return Err(TimecatError::BadHashFile);
+        }
+        if u32::from_le_bytes(reader.read_bytes()?) != HASH_FILE_VERSION
+            || u64::from_le_bytes(reader.read_bytes()?) != Zobrist::get_fingerprint()
+        {
+            return Err(TimecatError::IncompatibleHashFile);
+        }
+        let [size_type] = reader.read_bytes()?;
+        let size = u64::from_le_bytes(reader.read_bytes()?) as usize;
+        let size = match size_type {
+            0 => CacheTableSize::Max(size),
+            1 => CacheTableSize::Min(size),
+            2 => CacheTableSize::Round(size),
+            3 => CacheTableSize::Exact(size),
+            _ => return Err(TimecatError::BadHashFile),
+        };
+        let num_clusters = u64::from_le_bytes(reader.read_bytes()?);
+        let [generation] = reader.read_bytes()?;
+        let expected_file_size = num_clusters
+            .checked_mul(TRANSPOSITION_TABLE_CLUSTER_SIZE as u64 * Self::HASH_FILE_ENTRY_SIZE)
+            .and_then(|entries_size| entries_size.checked_add(Self::HASH_FILE_OVERHEAD_SIZE));
+        if num_clusters == 0
+            || generation >= TRANSPOSITION_TABLE_NUM_GENERATIONS
+            || expected_file_size != Some(file_size)
+        {
+            return Err(TimecatError::BadHashFile);
+        }
+        let table = HugePageBuffer::<TranspositionTableCluster>::new_zeroed(
+            num_clusters as usize,
+            self.get_num_threads(),
+        );
+        for entry in table.iter().flat_map(|cluster| cluster.entries.iter()) {
+            entry.store(TranspositionTableEntry::from_bytes(reader.read_bytes()?));
+        }
+        reader.finish()?;
+        // SAFETY: No search is running, so no other thread holds a reference to the table.
+        unsafe { self.table.replace(table) };
+        *self.size.write().unwrap() = size;
+        self.generation.store(generation, MEMORY_ORDERING);
+        self.reset_variables();
+        Ok(())
     }
 }
 
-impl Deref for TranspositionTable {
-    type Target = CacheTable<TranspositionTableEntry>;
+impl Clone for TranspositionTable {
+    fn clone(&self) -> Self {
+        let old_table = self.table.get();
+        let table = HugePageBuffer::<TranspositionTableCluster>::new_zeroed(
+            old_table.len(),
+            self.get_num_threads(),
+        );
+        for (cluster, new_cluster) in old_table.iter().zip(table.iter()) {
+            for (entry, new_entry) in cluster.entries.iter().zip(&new_cluster.entries) {
+                new_entry.store(entry.load());
+            }
+        }
+        Self {
+            table: table.into(),
+            size: RwLock::new(self.get_size()),
+            num_threads: AtomicUsize::new(self.num_threads.load(MEMORY_ORDERING)),
+            generation: AtomicU8::new(self.get_generation()),
+            #[cfg(feature = "extras")]
+            num_overwrites: AtomicUsize::new(self.get_num_overwrites()),
+            #[cfg(feature = "extras")]
+            num_collisions: AtomicUsize::new(self.get_num_collisions()),
+            #[cfg(feature = "extras")]
+            zero_hit: AtomicUsize::new(self.get_zero_hit()),
+        }
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.table
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        Self::new(TIMECAT_DEFAULTS.t_table_size)
     }
 }
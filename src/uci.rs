@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::BTreeMap;
 
 trait IntoSpin {
     fn into_spin(self) -> Spin;
@@ -132,6 +133,22 @@ impl<T: ChessEngine> UCIOption<T> {
         UCIOption::new(name, UCIOptionType::String { default, function })
     }
 
+    fn new_combo(
+        name: &str,
+        default: String,
+        options: Vec<String>,
+        function: fn(&mut T, &str) -> Result<()>,
+    ) -> Self {
+        UCIOption::new(
+            name,
+            UCIOptionType::Combo {
+                default,
+                options,
+                function,
+            },
+        )
+    }
+
     fn set_option(&self, engine: &mut T, value_string: String) -> Result<()> {
         match self.option_type {
             UCIOptionType::Check { function, .. } => {
@@ -163,6 +180,26 @@ impl<T: ChessEngine> UCIOption<T> {
         }
         Ok(())
     }
+
+    #[inline]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn is_button(&self) -> bool {
+        matches!(self.option_type, UCIOptionType::Button { .. })
+    }
+
+    #[inline]
+    pub fn is_check(&self) -> bool {
+        matches!(self.option_type, UCIOptionType::Check { .. })
+    }
+
+    #[inline]
+    pub fn is_spin(&self) -> bool {
+        matches!(self.option_type, UCIOptionType::Spin { .. })
+    }
 }
 
 impl<T: ChessEngine> fmt::Display for UCIOption<T> {
@@ -228,18 +265,24 @@ impl<T: ChessEngine> fmt::Display for UCIOption<T> {
 
 pub struct UCIStateManager<T: ChessEngine> {
     options: Vec<UCIOption<T>>,
+    current_values: RwLock<BTreeMap<String, String>>,
+    config_file_path: Option<PathBuf>,
 }
 
 impl<T: ChessEngine> UCIStateManager<T> {
     pub const fn dummy() -> Self {
         Self {
             options: Vec::new(),
+            current_values: RwLock::new(BTreeMap::new()),
+            config_file_path: None,
         }
     }
 
     fn new() -> Self {
         Self {
             options: get_uci_state_manager(),
+            current_values: RwLock::new(BTreeMap::new()),
+            config_file_path: None,
         }
     }
 
@@ -259,6 +302,47 @@ impl<T: ChessEngine> UCIStateManager<T> {
         &self.options
     }
 
+    /// The value the option was last set to, or [`None`] if it still has its compiled-in default.
+    #[inline]
+    pub fn get_current_value(&self, option: &UCIOption<T>) -> Option<String> {
+        self.current_values
+            .read()
+            .unwrap()
+            .get(&option.name)
+            .cloned()
+    }
+
+    /// The config file the options were loaded from, which is where `saveoptions` writes by default.
+    #[inline]
+    pub fn get_config_file_path(&self) -> Option<&Path> {
+        self.config_file_path.as_deref()
+    }
+
+    #[inline]
+    pub fn set_config_file_path(&mut self, config_file_path: Option<PathBuf>) {
+        self.config_file_path = config_file_path;
+    }
+
+    /// Lowers the maximum value of a spin option, clamping its default to it. Used to keep the sessions of a server
+    /// within its limits.
+    pub fn restrict_spin_option(&mut self, command_name: &str, new_max: Spin) -> Result<()> {
+        let option = self
+            .options
+            .iter_mut()
+            .find(|option| option.name.eq_ignore_ascii_case(command_name))
+            .ok_or(TimecatError::UnknownCommand)?;
+        match &mut option.option_type {
+            UCIOptionType::Spin {
+                default, min, max, ..
+            } => {
+                *max = new_max.clamp(*min, *max);
+                *default = (*default).min(*max);
+                Ok(())
+            }
+            _ => Err(TimecatError::UnknownCommand),
+        }
+    }
+
     pub fn run_command(&self, engine: &mut T, user_input: &str) -> Result<()> {
         let binding = Parser::sanitize_string(user_input);
         let commands = binding.split_whitespace().collect_vec();
@@ -290,9 +374,24 @@ impl<T: ChessEngine> UCIStateManager<T> {
             .skip(1)
             .join(" ");
 
-        self.get_option(&command_name)
-            .ok_or(TimecatError::UnknownCommand)?
-            .set_option(engine, value_string)
+        self.set_option(engine, &command_name, value_string)
+    }
+
+    /// Sets an option by its name or alias, as if `setoption name <name> value <value>` was sent.
+    pub fn set_option(&self, engine: &mut T, name: &str, value_string: String) -> Result<()> {
+        let option = self
+            .get_option(&name.trim().to_lowercase())
+            .ok_or_else(|| TimecatError::UnknownUCIOption {
+                name: name.trim().to_string(),
+            })?;
+        option.set_option(engine, value_string.clone())?;
+        if !option.is_button() {
+            self.current_values
+                .write()
+                .unwrap()
+                .insert(option.name.clone(), value_string);
+        }
+        Ok(())
     }
 }
 
@@ -328,7 +427,9 @@ fn get_uci_state_manager<T: ChessEngine>() -> Vec<UCIOption<T>> {
                     engine.set_transposition_table_size(size);
                     print_uci_info(
                         "Transposition table is set to size to",
-                        size.to_memory_size_in_mb::<TranspositionTableEntry>(),
+                        size.to_memory_size_in_mb_for_cell_size(
+                            TranspositionTable::get_cluster_size(),
+                        ),
                     );
                     Ok(())
                 }
@@ -364,6 +465,31 @@ fn get_uci_state_manager<T: ChessEngine>() -> Vec<UCIOption<T>> {
                 Ok(())
             },
         ),
+        UCIOption::new_string(
+            "EvalFile",
+            INBUILT_EVAL_FILE.to_string(),
+            |engine, nnue_path| {
+                if nnue_path.is_empty() || nnue_path.eq_ignore_ascii_case(INBUILT_EVAL_FILE) {
+                    engine.load_inbuilt_nnue()?;
+                } else {
+                    engine.load_nnue_file(Path::new(nnue_path))?;
+                }
+                print_uci_info("EvalFile is set to", format!("{:?}", nnue_path));
+                Ok(())
+            },
+        ),
+        UCIOption::new_combo(
+            "UCI_Variant",
+            Variant::default().to_string(),
+            ALL_VARIANTS.iter().map(Variant::to_string).collect(),
+            |engine, variant| {
+                let variant = variant.parse()?;
+                engine.get_board_mut().set_variant(variant)?;
+                engine.clear_hash();
+                print_uci_info("UCI_Variant is set to", variant);
+                Ok(())
+            },
+        ),
         // UCIOption::new_check(
         //     "OwnBook",
         //     TIMECAT_DEFAULTS.use_own_book,
@@ -177,46 +177,24 @@ macro_rules! match_interpolate_float {
     };
 }
 
-#[cfg(feature = "wasm")]
+/// Prints through the output sink applied on the current thread, which is the standard output (or the browser
+/// console on wasm) by default.
 #[macro_export]
 macro_rules! print_wasm {
-    () => {
-        gloo::console::log!()
-    };
-    ($($arg:tt)*) => {
-        gloo::console::log!(format!($($arg)*))
-    };
-}
-
-#[cfg(not(feature = "wasm"))]
-#[macro_export]
-macro_rules! print_wasm {
-    () => {
-        print!()
-    };
-    ($($arg:tt)*) => {
-        print!($($arg)*)
-    };
-}
-
-#[cfg(feature = "wasm")]
-#[macro_export]
-macro_rules! println_wasm {
-    () => {
-        gloo::console::log!("\n")
-    };
+    () => {};
     ($($arg:tt)*) => {
-        gloo::console::log!(format!($($arg)*))
+        $crate::write_output_str(&format!($($arg)*))
     };
 }
 
-#[cfg(not(feature = "wasm"))]
+/// Prints a line through the output sink applied on the current thread, which is the standard output (or the browser
+/// console on wasm) by default.
 #[macro_export]
 macro_rules! println_wasm {
     () => {
-        println!()
+        $crate::write_output_line("")
     };
     ($($arg:tt)*) => {
-        println!($($arg)*)
+        $crate::write_output_line(&format!($($arg)*))
     };
 }
@@ -483,6 +483,14 @@ impl Iterator for BitBoard {
     }
 }
 
+impl FromIterator<Square> for BitBoard {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = Square>>(iter: T) -> Self {
+        iter.into_iter()
+            .fold(BitBoard::EMPTY, |bb, square| bb | square.to_bitboard())
+    }
+}
+
 impl fmt::Display for BitBoard {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -1,4 +1,6 @@
 use super::*;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering as AtomicOrdering;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
@@ -77,16 +79,19 @@ impl CacheTableSize {
 
     #[inline]
     pub const fn get_entry_size<T>() -> usize {
-        size_of::<Option<CacheTableEntry<T>>>()
+        size_of::<CacheTableSlot<T>>()
     }
 
     pub fn to_num_entries_and_entry_size<T>(self) -> (usize, usize) {
+        self.to_num_cells_and_cell_size(Self::get_entry_size::<T>())
+    }
+
+    pub fn to_num_cells_and_cell_size(self, cell_size: usize) -> (usize, usize) {
         let mut size = self.unwrap();
-        let entry_size = Self::get_entry_size::<T>();
         size *= 2_usize.pow(20);
-        size /= entry_size;
+        size /= cell_size;
         if self.is_exact() {
-            return (size, entry_size);
+            return (size, cell_size);
         }
         let pow_f64 = (size as f64).log2();
         let pow = match self {
@@ -96,7 +101,7 @@ impl CacheTableSize {
             Self::Exact(_) => unreachable!(),
         } as u32;
         size = 2_usize.pow(pow);
-        (size, entry_size)
+        (size, cell_size)
     }
 
     #[inline]
@@ -106,8 +111,13 @@ impl CacheTableSize {
 
     #[inline]
     pub fn to_memory_size_in_mb<T>(self) -> usize {
-        let (size, entry_size) = self.to_num_entries_and_entry_size::<T>();
-        size * entry_size / 2_usize.pow(20)
+        self.to_memory_size_in_mb_for_cell_size(Self::get_entry_size::<T>())
+    }
+
+    #[inline]
+    pub fn to_memory_size_in_mb_for_cell_size(self, cell_size: usize) -> usize {
+        let (size, cell_size) = self.to_num_cells_and_cell_size(cell_size);
+        size * cell_size / 2_usize.pow(20)
     }
 }
 
@@ -122,6 +132,94 @@ impl fmt::Display for CacheTableSize {
         write!(f, "{} MB", self.unwrap())
     }
 }
+/// A slot made of two atomic words, the entry packed into one and the hash xored with it into the other. An entry
+/// torn by concurrent writes is detected on reading as its recovered hash does not match, so no lock is needed.
+struct CacheTableSlot<T> {
+    key: AtomicU64,
+    data: AtomicU64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+// A zero key and zero data recover a zero hash, which marks an empty slot.
+unsafe impl<T: Copy> ZeroInitialized for CacheTableSlot<T> {}
+
+impl<T: Copy> CacheTableSlot<T> {
+    #[inline]
+    fn pack(entry: T) -> u64 {
+        const {
+            assert!(
+                size_of::<T>() <= size_of::<u64>(),
+                "Cache table entries must fit in 8 bytes"
+            )
+        };
+        let mut data = 0;
+        unsafe { std::ptr::write_unaligned((&raw mut data).cast::<T>(), entry) };
+        data
+    }
+
+    /// Only called on data written by [`pack`](Self::pack).
+    #[inline]
+    fn unpack(data: u64) -> T {
+        unsafe { std::ptr::read_unaligned((&raw const data).cast::<T>()) }
+    }
+
+    /// The key is loaded first with acquire ordering, so the data is never older than the write of the key.
+    #[inline]
+    fn load_words(&self) -> (u64, u64) {
+        let key = self.key.load(AtomicOrdering::Acquire);
+        (key, self.data.load(MEMORY_ORDERING))
+    }
+
+    #[inline]
+    fn get(&self, hash: NonZeroU64) -> Option<T> {
+        let (key, data) = self.load_words();
+        (key ^ data == hash.get()).then(|| Self::unpack(data))
+    }
+
+    #[inline]
+    fn load(&self) -> Option<CacheTableEntry<T>> {
+        let (key, data) = self.load_words();
+        let hash = NonZeroU64::new(key ^ data)?;
+        Some(CacheTableEntry::new(hash, Self::unpack(data)))
+    }
+
+    #[inline]
+    fn store(&self, entry: Option<CacheTableEntry<T>>) {
+        let (hash, data) =
+            entry.map_or((0, 0), |entry| (entry.hash.get(), Self::pack(entry.entry)));
+        self.data.store(data, MEMORY_ORDERING);
+        self.key.store(hash ^ data, AtomicOrdering::Release);
+    }
+}
+
+impl<T> Debug for CacheTableSlot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CacheTableSlot")
+            .field("key", &self.key)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Copy + Serialize> Serialize for CacheTableSlot<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.load().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Copy + Deserialize<'de>> Deserialize<'de> for CacheTableSlot<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let slot = Self {
+            key: AtomicU64::new(0),
+            data: AtomicU64::new(0),
+            _marker: PhantomData,
+        };
+        slot.store(Option::<CacheTableEntry<T>>::deserialize(deserializer)?);
+        Ok(slot)
+    }
+}
 
 #[cfg(feature = "extras")]
 macro_rules! update_variables {
@@ -140,11 +238,14 @@ macro_rules! update_variables {
     };
 }
 
+/// A hash table without any lock, so that reads and writes from many threads do not contend. Resizing replaces the
+/// table, so [`set_size`](Self::set_size) must only be called between searches, when no other thread uses it.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default)]
-pub struct CacheTable<T> {
-    table: RwLock<Box<[Option<CacheTableEntry<T>>]>>,
+#[derive(Debug)]
+pub struct CacheTable<T: Copy> {
+    table: HugePageBufferCell<CacheTableSlot<T>>,
     size: RwLock<CacheTableSize>,
+    num_threads: AtomicUsize,
     mask: AtomicUsize,
     is_safe_to_do_bitwise_and: AtomicBool,
     num_cells_filled: AtomicUsize,
@@ -158,8 +259,11 @@ pub struct CacheTable<T> {
 
 impl<T: Copy + PartialEq> CacheTable<T> {
     #[inline]
-    fn generate_table(size: CacheTableSize) -> Box<[Option<CacheTableEntry<T>>]> {
-        vec![None; size.to_num_entries::<T>()].into_boxed_slice()
+    fn generate_table(
+        size: CacheTableSize,
+        num_threads: NonZeroUsize,
+    ) -> HugePageBuffer<CacheTableSlot<T>> {
+        HugePageBuffer::new_zeroed(size.to_num_entries::<T>(), num_threads)
     }
 
     #[inline]
@@ -168,7 +272,7 @@ impl<T: Copy + PartialEq> CacheTable<T> {
     }
 
     #[inline]
-    const fn into_inner(table: &[Option<CacheTableEntry<T>>]) -> usize {
+    const fn into_inner(table: &[CacheTableSlot<T>]) -> usize {
         if Self::is_safe_to_do_bitwise_and(table.len()) {
             table.len() - 1
         } else {
@@ -177,7 +281,7 @@ impl<T: Copy + PartialEq> CacheTable<T> {
     }
 
     #[inline]
-    fn reset_mask(&self, table: &[Option<CacheTableEntry<T>>]) {
+    fn reset_mask(&self, table: &[CacheTableSlot<T>]) {
         self.mask.store(Self::into_inner(table), MEMORY_ORDERING);
         self.is_safe_to_do_bitwise_and.store(
             Self::is_safe_to_do_bitwise_and(table.len()),
@@ -187,8 +291,9 @@ impl<T: Copy + PartialEq> CacheTable<T> {
 
     pub fn new(size: CacheTableSize) -> CacheTable<T> {
         let cache_table = CacheTable {
-            table: RwLock::new(Self::generate_table(size)),
+            table: Self::generate_table(size, TIMECAT_DEFAULTS.num_threads).into(),
             size: RwLock::new(size),
+            num_threads: AtomicUsize::new(TIMECAT_DEFAULTS.num_threads.get()),
             mask: Default::default(),
             is_safe_to_do_bitwise_and: Default::default(),
             num_cells_filled: AtomicUsize::new(0),
@@ -199,7 +304,7 @@ impl<T: Copy + PartialEq> CacheTable<T> {
             #[cfg(feature = "extras")]
             zero_hit: AtomicUsize::new(0),
         };
-        cache_table.reset_mask(&cache_table.table.read().unwrap());
+        cache_table.reset_mask(cache_table.table.get());
         cache_table
     }
 
@@ -215,50 +320,30 @@ impl<T: Copy + PartialEq> CacheTable<T> {
     #[inline]
     pub fn get(&self, hash: u64) -> Option<T> {
         let hash = NonZeroU64::new(hash).unwrap_or(DEFAULT_HASH);
-        let entry = (*get_item_unchecked!(self.table.read().unwrap(), self.get_index(hash.get())))?;
-        if entry.hash == hash {
-            Some(entry.entry)
-        } else {
-            None
-        }
+        get_item_unchecked!(self.table.get(), self.get_index(hash.get())).get(hash)
     }
 
     #[inline]
     pub fn add(&self, hash: u64, entry: T) {
-        let hash = NonZeroU64::new(hash).unwrap_or(DEFAULT_HASH);
-        let mut table = self.table.write().unwrap();
-        let e = get_item_unchecked_mut!(table, self.get_index(hash.get()));
-        #[cfg(not(feature = "extras"))]
-        if e.is_none() {
-            self.num_cells_filled.fetch_add(1, MEMORY_ORDERING);
-        }
-        #[cfg(feature = "extras")]
-        let e_copy = *e;
-        *e = Some(CacheTableEntry { hash, entry });
-        drop(table);
-        #[cfg(feature = "extras")]
-        update_variables!(self, e_copy, hash, entry);
+        self.replace_if(hash, entry, |_| true);
     }
 
     #[inline]
     pub fn replace_if<F: Fn(T) -> bool>(&self, hash: u64, entry: T, replace: F) {
         let hash = NonZeroU64::new(hash).unwrap_or(DEFAULT_HASH);
-        let mut table = self.table.write().unwrap();
-        let e = get_item_unchecked_mut!(table, self.get_index(hash.get()));
-        let to_replace = if let Some(entry) = e {
-            replace(entry.entry)
+        let slot = get_item_unchecked!(self.table.get(), self.get_index(hash.get()));
+        let e_copy = slot.load();
+        let to_replace = if let Some(e) = e_copy {
+            replace(e.entry)
         } else {
             true
         };
         if to_replace {
             #[cfg(not(feature = "extras"))]
-            if e.is_none() {
+            if e_copy.is_none() {
                 self.num_cells_filled.fetch_add(1, MEMORY_ORDERING);
             }
-            #[cfg(feature = "extras")]
-            let e_copy = *e;
-            *e = Some(CacheTableEntry { hash, entry });
-            drop(table);
+            slot.store(Some(CacheTableEntry { hash, entry }));
             #[cfg(feature = "extras")]
             update_variables!(self, e_copy, hash, entry);
         }
@@ -266,14 +351,26 @@ impl<T: Copy + PartialEq> CacheTable<T> {
 
     #[inline]
     pub fn clear(&self) {
-        self.table.write().unwrap().fill(None);
+        self.table
+            .get()
+            .for_each_parallel(self.get_num_threads(), |slot| slot.store(None));
         self.num_cells_filled.store(0, MEMORY_ORDERING);
         self.reset_variables()
     }
 
+    /// A copy of the slots of the table, as the entries are stored packed.
+    pub fn get_table(&self) -> Box<[Option<CacheTableEntry<T>>]> {
+        self.table.get().iter().map(CacheTableSlot::load).collect()
+    }
+
+    /// Collects the filled slots, which is meant to be used only between searches.
     #[inline]
-    pub const fn get_table(&self) -> &RwLock<Box<[Option<CacheTableEntry<T>>]>> {
-        &self.table
+    pub fn get_entries(&self) -> Vec<CacheTableEntry<T>> {
+        self.table
+            .get()
+            .iter()
+            .filter_map(CacheTableSlot::load)
+            .collect()
     }
 
     #[inline]
@@ -339,7 +436,7 @@ impl<T: Copy + PartialEq> CacheTable<T> {
 
     #[inline]
     pub fn len(&self) -> usize {
-        self.table.read().unwrap().len()
+        self.table.get().len()
     }
 
     #[inline]
@@ -352,13 +449,36 @@ impl<T: Copy + PartialEq> CacheTable<T> {
         *self.size.read().unwrap()
     }
 
+    #[inline]
+    pub fn get_num_threads(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.num_threads.load(MEMORY_ORDERING)).unwrap()
+    }
+
+    /// Sets the number of threads used to zero the table on [`clear`](Self::clear) and
+    /// [`set_size`](Self::set_size).
+    #[inline]
+    pub fn set_num_threads(&self, num_threads: NonZeroUsize) {
+        self.num_threads.store(num_threads.get(), MEMORY_ORDERING);
+    }
+
+    #[inline]
+    pub fn get_allocation_kind(&self) -> AllocationKind {
+        self.table.get().get_allocation_kind()
+    }
+
+    /// Resizes the table keeping its entries. Must only be called between searches.
     pub fn set_size(&self, size: CacheTableSize) {
+        let current_entries = self.get_entries();
         *self.size.write().unwrap() = size;
-        let current_table_copy = self.table.read().unwrap().clone();
-        *self.table.write().unwrap() = Self::generate_table(size);
-        self.reset_mask(&current_table_copy);
+        // SAFETY: No search is running, so no other thread holds a reference to the table.
+        unsafe {
+            self.table
+                .replace(Self::generate_table(size, self.get_num_threads()))
+        };
+        self.reset_mask(self.table.get());
+        self.reset_num_cells_filled();
         self.reset_variables();
-        for entry in current_table_copy.iter().flatten() {
+        for entry in current_entries {
             self.add(entry.hash.get(), entry.entry);
         }
     }
@@ -366,9 +486,16 @@ impl<T: Copy + PartialEq> CacheTable<T> {
 
 impl<T: Copy + PartialEq> Clone for CacheTable<T> {
     fn clone(&self) -> Self {
+        let table = self.table.get();
+        let new_table =
+            HugePageBuffer::<CacheTableSlot<T>>::new_zeroed(table.len(), self.get_num_threads());
+        for (slot, new_slot) in table.iter().zip(new_table.iter()) {
+            new_slot.store(slot.load());
+        }
         CacheTable {
-            table: RwLock::new(self.table.read().unwrap().clone()),
+            table: new_table.into(),
             size: RwLock::new(self.get_size()),
+            num_threads: AtomicUsize::new(self.num_threads.load(MEMORY_ORDERING)),
             mask: AtomicUsize::new(self.mask.load(MEMORY_ORDERING)),
             is_safe_to_do_bitwise_and: AtomicBool::new(
                 self.is_safe_to_do_bitwise_and.load(MEMORY_ORDERING),
@@ -383,3 +510,9 @@ impl<T: Copy + PartialEq> Clone for CacheTable<T> {
         }
     }
 }
+
+impl<T: Copy + PartialEq> Default for CacheTable<T> {
+    fn default() -> Self {
+        Self::new(CacheTableSize::default())
+    }
+}
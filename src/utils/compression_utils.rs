@@ -34,7 +34,9 @@ impl Compress for Move {
         let mut compressed_move = 0;
         compressed_move ^= self.get_source().compress() << 6;
         compressed_move ^= self.get_dest().compress();
-        compressed_move ^= (self.get_promotion().compress() as Self::CompressedItem) << 12;
+        // A drop keeps the dropped piece in the bits of the promotion.
+        compressed_move ^=
+            (self.get_promotion().or(self.get_drop()).compress() as Self::CompressedItem) << 12;
         compressed_move
     }
 }
@@ -45,13 +45,33 @@ pub trait ClippedRelu<InputType, OutputType, const N: usize> {
 pub trait StringifyScore {
     fn stringify_score_console(self) -> String;
     fn stringify_score_uci(self) -> String;
-    fn stringify_score(self) -> String;
+    fn stringify_score_with_config(self, config: &TimecatConfig) -> String;
+
+    #[inline]
+    fn stringify_score(self) -> String
+    where
+        Self: Sized,
+    {
+        self.stringify_score_with_config(&TimecatConfig::get_current())
+    }
 }
 
 pub trait StringifyMove {
     fn uci(self) -> String;
     fn algebraic(self, position: &BoardPosition, long: bool) -> Result<String>;
-    fn stringify_move(self, position: &BoardPosition) -> Result<String>;
+    fn stringify_move_with_config(
+        self,
+        position: &BoardPosition,
+        config: &TimecatConfig,
+    ) -> Result<String>;
+
+    #[inline]
+    fn stringify_move(self, position: &BoardPosition) -> Result<String>
+    where
+        Self: Sized,
+    {
+        self.stringify_move_with_config(position, &TimecatConfig::get_current())
+    }
 
     fn san(self, position: &BoardPosition) -> Result<String>
     where
@@ -111,11 +131,32 @@ pub trait PositionEvaluation: Clone + Send + 'static {
     #[inline]
     fn print_info(&self) {}
 
+    /// Replaces the network with the one in the NNUE file, for evaluators which use one.
+    #[inline]
+    #[expect(unused_variables)]
+    fn load_nnue_file(&mut self, path: &Path, position: &BoardPosition) -> Result<()> {
+        Err(TimecatError::NotImplemented)
+    }
+
+    /// Replaces the network with the one embedded in the binary, for evaluators which use one.
+    #[inline]
+    #[expect(unused_variables)]
+    fn load_inbuilt_nnue(&mut self, position: &BoardPosition) -> Result<()> {
+        Err(TimecatError::NotImplemented)
+    }
+
     #[inline]
     fn evaluate_flipped(&mut self, position: &BoardPosition) -> Score {
         position.score_flipped(self.evaluate(position))
     }
 
+    /// The terms for the goals of the variant from the point of view of white, which the search adds to the
+    /// evaluation when not playing standard chess.
+    #[inline]
+    fn evaluate_variant_terms(&mut self, position: &BoardPosition) -> Score {
+        position.get_variant().evaluate_terms(position)
+    }
+
     #[inline]
     fn evaluate_checkmate_in(&mut self, mate_distance: Ply) -> Score {
         if CHECKMATE_SCORE as Ply > mate_distance {
@@ -130,6 +171,23 @@ pub trait PositionEvaluation: Clone + Send + 'static {
         -self.evaluate_checkmate_in(mate_distance)
     }
 
+    /// The score for the side to move if the game has ended by a rule of the variant, which is scored like a
+    /// checkmate at the same distance, or [`None`] if the rules of the variant let the game go on.
+    #[inline]
+    fn evaluate_variant_terminal_in(
+        &mut self,
+        position: &BoardPosition,
+        mate_distance: Ply,
+    ) -> Option<Score> {
+        Some(match position.get_variant().get_status(position)? {
+            BoardStatus::VariantWin(color) if color == position.turn() => {
+                self.evaluate_checkmate_in(mate_distance)
+            }
+            BoardStatus::VariantWin(_) => self.evaluate_checkmated_in(mate_distance),
+            _ => self.evaluate_draw(),
+        })
+    }
+
     #[inline]
     fn evaluate_draw(&mut self) -> Score {
         0
@@ -139,6 +197,10 @@ pub trait PositionEvaluation: Clone + Send + 'static {
 pub trait ChessEngine {
     type IoReader;
 
+    fn get_config(&self) -> &TimecatConfig;
+    fn get_config_mut(&mut self) -> &mut TimecatConfig;
+    fn get_output_sink(&self) -> &Arc<dyn OutputSink>;
+    fn set_output_sink(&mut self, output_sink: Arc<dyn OutputSink>);
     fn get_board(&self) -> &Board;
     fn get_board_mut(&mut self) -> &mut Board;
     fn set_transposition_table_size(&self, size: CacheTableSize);
@@ -157,12 +219,46 @@ pub trait ChessEngine {
     #[inline]
     fn print_info(&self) {}
 
+    #[inline]
+    #[expect(unused_variables)]
+    fn save_hash(&self, path: &Path) -> Result<()> {
+        Err(TimecatError::NotImplemented)
+    }
+
+    #[inline]
+    #[expect(unused_variables)]
+    fn load_hash(&self, path: &Path) -> Result<()> {
+        Err(TimecatError::NotImplemented)
+    }
+
+    #[inline]
+    #[expect(unused_variables)]
+    fn load_nnue_file(&mut self, path: &Path) -> Result<()> {
+        Err(TimecatError::NotImplemented)
+    }
+
+    #[inline]
+    fn load_inbuilt_nnue(&mut self) -> Result<()> {
+        Err(TimecatError::NotImplemented)
+    }
+
+    /// Waits for the next line of input, for commands which interact with the user like `play`. Returns `None` if
+    /// the engine has no input.
+    #[inline]
+    fn read_line(&self) -> Option<String> {
+        None
+    }
+
     #[inline]
     #[expect(unused_variables)]
     fn set_optional_io_reader(&mut self, optional_io_reader: Self::IoReader) {}
 
     #[inline]
     fn get_opening_book_weighted_move(&self) -> Option<WeightedMove> {
+        // The books are made of standard chess games.
+        if self.get_board().get_variant() != Variant::Standard {
+            return None;
+        }
         self.get_opening_book()?
             .get_best_weighted_move(self.get_board())
             .filter(|WeightedMove { move_, .. }| self.get_board().is_legal(move_))
@@ -180,6 +276,45 @@ pub trait ChessEngine {
         self.go(config, true)
     }
 
+    /// Searches the current position and the one where the side to move passes, whose best reply is the threat of the
+    /// other side. The score delta is how much passing loses compared to the search of the position itself.
+    fn search_threat(&mut self, config: &SearchConfig) -> Result<Threat> {
+        let fen = self.get_board().get_fen();
+        let score = self
+            .go_quiet(config)
+            .get_score_flipped()
+            .ok_or_else(|| TimecatError::BestMoveNotFound { fen: fen.clone() })?;
+        self.get_board_mut().push(ValidOrNullMove::NullMove)?;
+        let search_info = self.go_quiet(config);
+        self.get_board_mut().pop();
+        let (Some(threat_move), Some(threat_score)) =
+            (search_info.get_best_move(), search_info.get_score_flipped())
+        else {
+            return Err(TimecatError::BestMoveNotFound { fen });
+        };
+        let threat_score = -threat_score;
+        Ok(Threat::new(
+            search_info.get_position().to_owned(),
+            threat_move,
+            threat_score,
+            score.saturating_sub(threat_score),
+        ))
+    }
+
+    /// Runs `f` with the output settings and the output sink of the engine applied on the current thread.
+    #[inline]
+    fn run_with_output_settings<R>(&self, f: impl FnOnce() -> R) -> R {
+        with_output_settings(*self.get_config(), self.get_output_sink().clone(), f)
+    }
+
+    fn with_output_sink(mut self, output_sink: Arc<dyn OutputSink>) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_output_sink(output_sink);
+        self
+    }
+
     fn with_io_reader(mut self, optional_io_reader: Self::IoReader) -> Self
     where
         Self: Sized,
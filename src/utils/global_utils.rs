@@ -6,7 +6,8 @@ pub fn identity_function<T>(item: T) -> T {
 }
 
 pub fn print_uci_info<T: fmt::Display>(message: &str, info: impl Into<Option<T>>) {
-    if !GLOBAL_TIMECAT_STATE.is_in_debug_mode() {
+    let config = TimecatConfig::get_current();
+    if !config.is_in_debug_mode() {
         return;
     }
     let mut to_print = if let Some(info_message) = info.into() {
@@ -18,7 +19,7 @@ pub fn print_uci_info<T: fmt::Display>(message: &str, info: impl Into<Option<T>>
     } else {
         message.colorize(SUCCESS_MESSAGE_STYLE)
     };
-    if GLOBAL_TIMECAT_STATE.is_in_uci_mode() {
+    if config.is_in_uci_mode() {
         to_print = format!("{} {to_print}", "info string".colorize(INFO_MESSAGE_STYLE))
     }
     println_wasm!("{to_print}");
@@ -40,6 +41,113 @@ pub struct TimecatDefaults {
     pub chess960_mode: bool,
 }
 
+thread_local! {
+    static CURRENT_TIMECAT_CONFIG: Cell<Option<TimecatConfig>> = const { Cell::new(None) };
+}
+
+/// Restores the previously active config when [`TimecatConfig::apply`] returns or unwinds.
+struct TimecatConfigGuard(Option<TimecatConfig>);
+
+impl Drop for TimecatConfigGuard {
+    fn drop(&mut self) {
+        CURRENT_TIMECAT_CONFIG.set(self.0);
+    }
+}
+
+/// Output settings of a single [`Timecat`] instance or engine, so that several of them can live in one process with
+/// different settings. [`GLOBAL_TIMECAT_STATE`] only provides the defaults of new configs.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimecatConfig {
+    #[cfg(feature = "colored")]
+    colored: bool,
+    console_mode: bool,
+    long_algebraic_notation: bool,
+    debug_mode: bool,
+}
+
+impl Default for TimecatConfig {
+    fn default() -> Self {
+        GLOBAL_TIMECAT_STATE.get_config()
+    }
+}
+
+impl TimecatConfig {
+    /// Returns the config applied on the current thread through [`Self::apply`], or the global default if there is none.
+    #[inline]
+    pub fn get_current() -> Self {
+        CURRENT_TIMECAT_CONFIG
+            .get()
+            .unwrap_or_else(|| GLOBAL_TIMECAT_STATE.get_config())
+    }
+
+    /// Runs `f` with this config used by all the formatting and printing code on the current thread.
+    pub fn apply<R>(self, f: impl FnOnce() -> R) -> R {
+        let _guard = TimecatConfigGuard(CURRENT_TIMECAT_CONFIG.replace(Some(self)));
+        f()
+    }
+
+    #[inline]
+    #[cfg(feature = "colored")]
+    pub fn is_colored_output(&self) -> bool {
+        self.colored
+    }
+
+    #[inline]
+    #[cfg(not(feature = "colored"))]
+    pub fn is_colored_output(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    #[cfg(feature = "colored")]
+    pub fn set_colored_output(&mut self, b: bool) {
+        self.colored = b;
+    }
+
+    #[inline]
+    pub fn is_in_console_mode(&self) -> bool {
+        self.console_mode
+    }
+
+    #[inline]
+    pub fn is_in_uci_mode(&self) -> bool {
+        !self.is_in_console_mode()
+    }
+
+    /// Console mode also turns on the debug mode, as all the information is meant for the user.
+    #[inline]
+    pub fn set_console_mode(&mut self, b: bool) {
+        self.console_mode = b;
+        self.debug_mode = b;
+    }
+
+    #[inline]
+    pub fn set_uci_mode(&mut self, b: bool) {
+        self.set_console_mode(!b);
+    }
+
+    #[inline]
+    pub fn use_long_algebraic_notation(&self) -> bool {
+        self.long_algebraic_notation
+    }
+
+    #[inline]
+    pub fn set_long_algebraic_notation(&mut self, b: bool) {
+        self.long_algebraic_notation = b;
+    }
+
+    #[inline]
+    pub fn is_in_debug_mode(&self) -> bool {
+        self.debug_mode
+    }
+
+    #[inline]
+    pub fn set_debug_mode(&mut self, b: bool) {
+        self.debug_mode = b;
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlobalTimecatState {
@@ -67,6 +175,29 @@ impl GlobalTimecatState {
         }
     }
 
+    /// Returns the default config for new [`Timecat`] instances and engines.
+    pub fn get_config(&self) -> TimecatConfig {
+        TimecatConfig {
+            #[cfg(feature = "colored")]
+            colored: self.is_colored_output(),
+            console_mode: self.is_in_console_mode(),
+            long_algebraic_notation: self.use_long_algebraic_notation(),
+            debug_mode: self.is_in_debug_mode(),
+        }
+    }
+
+    pub fn set_config(&self, config: TimecatConfig) {
+        #[cfg(feature = "colored")]
+        self._colored
+            .store(config.is_colored_output(), MEMORY_ORDERING);
+        self._console_mode
+            .store(config.is_in_console_mode(), MEMORY_ORDERING);
+        self._long_algebraic_notation
+            .store(config.use_long_algebraic_notation(), MEMORY_ORDERING);
+        self._debug_mode
+            .store(config.is_in_debug_mode(), MEMORY_ORDERING);
+    }
+
     #[inline]
     #[cfg(feature = "colored")]
     pub fn is_colored_output(&self) -> bool {
@@ -75,7 +206,7 @@ impl GlobalTimecatState {
 
     #[inline]
     #[cfg(not(feature = "colored"))]
-    pub fn is_colored(&self) -> bool {
+    pub fn is_colored_output(&self) -> bool {
         false
     }
 
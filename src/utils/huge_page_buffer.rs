@@ -0,0 +1,332 @@
+use super::*;
+use std::alloc::{self, Layout};
+use std::cell::UnsafeCell;
+use std::ptr::NonNull;
+
+/// Marker for the cells of a [`HugePageBuffer`].
+///
+/// # Safety
+///
+/// A value made up of only zero bytes must be a valid empty cell and the type must not need to be dropped, as the buffer
+/// is allocated and cleared by writing zero bytes.
+pub unsafe trait ZeroInitialized {}
+
+unsafe impl ZeroInitialized for AtomicU8 {}
+unsafe impl ZeroInitialized for AtomicU16 {}
+unsafe impl ZeroInitialized for AtomicU64 {}
+unsafe impl ZeroInitialized for AtomicUsize {}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocationKind {
+    /// Allocated through the global allocator.
+    Global,
+    /// Mapped on huge page boundaries with the kernel advised to back it with transparent huge pages.
+    TransparentHugePages,
+    /// Backed by explicitly reserved huge pages.
+    HugeTlb,
+}
+
+impl fmt::Display for AllocationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Global => write!(f, "Global Allocator"),
+            Self::TransparentHugePages => write!(f, "Transparent Huge Pages"),
+            Self::HugeTlb => write!(f, "Huge Pages"),
+        }
+    }
+}
+
+/// A fixed length zero initialized buffer for hash tables. On Linux large buffers are backed by huge pages when
+/// available to reduce TLB misses, falling back to the global allocator otherwise.
+pub struct HugePageBuffer<T: ZeroInitialized> {
+    ptr: NonNull<T>,
+    len: usize,
+    mapping_ptr: *mut u8,
+    mapping_size: usize,
+    allocation_kind: AllocationKind,
+}
+
+unsafe impl<T: ZeroInitialized + Send> Send for HugePageBuffer<T> {}
+unsafe impl<T: ZeroInitialized + Sync> Sync for HugePageBuffer<T> {}
+
+impl<T: ZeroInitialized> HugePageBuffer<T> {
+    #[inline]
+    fn get_global_layout(len: usize) -> Layout {
+        Layout::array::<T>(len)
+            .and_then(|layout| layout.align_to(CACHE_LINE_SIZE))
+            .expect("Hash table size overflowed")
+    }
+
+    fn allocate_global(len: usize) -> Self {
+        let layout = Self::get_global_layout(len);
+        let ptr = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            let ptr = unsafe { alloc::alloc_zeroed(layout) };
+            NonNull::new(ptr.cast()).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+        };
+        Self {
+            ptr,
+            len,
+            mapping_ptr: ptr.as_ptr().cast(),
+            mapping_size: layout.size(),
+            allocation_kind: AllocationKind::Global,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn map_anonymous(size: usize, flags: libc::c_int) -> Option<*mut u8> {
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | flags,
+                -1,
+                0,
+            )
+        };
+        (ptr != libc::MAP_FAILED).then_some(ptr.cast())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn allocate_huge_pages(len: usize, size: usize) -> Option<Self> {
+        let mapping_size = size.checked_next_multiple_of(HUGE_PAGE_SIZE)?;
+        if let Some(mapping_ptr) = Self::map_anonymous(mapping_size, libc::MAP_HUGETLB) {
+            return Some(Self {
+                ptr: NonNull::new(mapping_ptr.cast())?,
+                len,
+                mapping_ptr,
+                mapping_size,
+                allocation_kind: AllocationKind::HugeTlb,
+            });
+        }
+        // Mapping one extra huge page, so that the buffer can start on a huge page boundary.
+        let mapping_size = mapping_size.checked_add(HUGE_PAGE_SIZE)?;
+        let mapping_ptr = Self::map_anonymous(mapping_size, 0)?;
+        let offset = mapping_ptr.align_offset(HUGE_PAGE_SIZE);
+        let ptr = unsafe { mapping_ptr.add(offset) };
+        // The advice is only a hint, so the mapping is usable even if it fails.
+        unsafe { libc::madvise(ptr.cast(), mapping_size - offset, libc::MADV_HUGEPAGE) };
+        Some(Self {
+            ptr: NonNull::new(ptr.cast())?,
+            len,
+            mapping_ptr,
+            mapping_size,
+            allocation_kind: AllocationKind::TransparentHugePages,
+        })
+    }
+
+    fn allocate(len: usize) -> Self {
+        #[cfg(target_os = "linux")]
+        {
+            let size = Self::get_global_layout(len).size();
+            if size >= HUGE_PAGE_SIZE {
+                if let Some(buffer) = Self::allocate_huge_pages(len, size) {
+                    return buffer;
+                }
+            }
+        }
+        Self::allocate_global(len)
+    }
+
+    /// Allocates a buffer of `len` zeroed cells. With more than one thread, the memory is touched by that many threads
+    /// in parallel, which speeds up the page faults of large tables.
+    pub fn new_zeroed(len: usize, num_threads: NonZeroUsize) -> Self {
+        let mut buffer = Self::allocate(len);
+        if num_threads.get() > 1 {
+            buffer.zero(num_threads);
+        }
+        buffer
+    }
+
+    #[inline]
+    pub fn get_allocation_kind(&self) -> AllocationKind {
+        self.allocation_kind
+    }
+
+    #[inline]
+    pub fn is_huge_page_backed(&self) -> bool {
+        self.allocation_kind != AllocationKind::Global
+    }
+
+    #[inline]
+    fn get_chunk_len(&self, num_threads: NonZeroUsize) -> usize {
+        let num_threads = if self.len * size_of::<T>() < PARALLEL_ZEROING_MIN_SIZE {
+            1
+        } else {
+            num_threads.get()
+        };
+        self.len.div_ceil(num_threads).max(1)
+    }
+
+    /// Overwrites every cell with zero bytes, splitting the work across `num_threads` threads. Only bytes are handed
+    /// to the threads, so the cells need not be [`Send`].
+    pub fn zero(&mut self, num_threads: NonZeroUsize) {
+        let chunk_len = self.get_chunk_len(num_threads) * size_of::<T>();
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(
+                self.ptr.as_ptr().cast::<u8>(),
+                self.len * size_of::<T>(),
+            )
+        };
+        thread::scope(|scope| {
+            for chunk in bytes.chunks_mut(chunk_len.max(1)) {
+                scope.spawn(move || chunk.fill(0));
+            }
+        });
+    }
+
+    /// Calls `f` on every cell, splitting the work across `num_threads` threads. Used for clearing through atomic
+    /// cells while the buffer is shared.
+    pub fn for_each_parallel(&self, num_threads: NonZeroUsize, f: impl Fn(&T) + Sync)
+    where
+        T: Sync,
+    {
+        let chunk_len = self.get_chunk_len(num_threads);
+        let f = &f;
+        thread::scope(|scope| {
+            for chunk in self.chunks(chunk_len) {
+                scope.spawn(move || chunk.iter().for_each(f));
+            }
+        });
+    }
+}
+
+impl<T: ZeroInitialized> Deref for HugePageBuffer<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: ZeroInitialized> DerefMut for HugePageBuffer<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: ZeroInitialized> Drop for HugePageBuffer<T> {
+    fn drop(&mut self) {
+        match self.allocation_kind {
+            AllocationKind::Global => {
+                if self.mapping_size != 0 {
+                    unsafe {
+                        alloc::dealloc(self.mapping_ptr, Self::get_global_layout(self.len));
+                    }
+                }
+            }
+            #[cfg(target_os = "linux")]
+            AllocationKind::TransparentHugePages | AllocationKind::HugeTlb => unsafe {
+                libc::munmap(self.mapping_ptr.cast(), self.mapping_size);
+            },
+            #[cfg(not(target_os = "linux"))]
+            AllocationKind::TransparentHugePages | AllocationKind::HugeTlb => unreachable!(),
+        }
+    }
+}
+
+impl<T: ZeroInitialized> Default for HugePageBuffer<T> {
+    fn default() -> Self {
+        Self::allocate_global(0)
+    }
+}
+
+impl<T: ZeroInitialized> Debug for HugePageBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HugePageBuffer")
+            .field("len", &self.len)
+            .field("allocation_kind", &self.allocation_kind)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ZeroInitialized + Serialize> Serialize for HugePageBuffer<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: ZeroInitialized + Deserialize<'de>> Deserialize<'de> for HugePageBuffer<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let cells = Vec::<T>::deserialize(deserializer)?;
+        let mut buffer = Self::allocate(cells.len());
+        for (cell, value) in buffer.iter_mut().zip(cells) {
+            // The zeroed cell needs no drop, so it is overwritten in place.
+            unsafe { std::ptr::write(cell, value) };
+        }
+        Ok(buffer)
+    }
+}
+
+/// Holds the [`HugePageBuffer`] of a table shared between the searching threads, so that it can be read without a
+/// lock. Replacing the buffer is only sound while no other thread uses the table, so tables only do it between
+/// searches.
+pub struct HugePageBufferCell<T: ZeroInitialized> {
+    buffer: UnsafeCell<HugePageBuffer<T>>,
+}
+
+unsafe impl<T: ZeroInitialized + Send> Send for HugePageBufferCell<T> {}
+unsafe impl<T: ZeroInitialized + Send + Sync> Sync for HugePageBufferCell<T> {}
+
+impl<T: ZeroInitialized> HugePageBufferCell<T> {
+    #[inline]
+    pub const fn new(buffer: HugePageBuffer<T>) -> Self {
+        Self {
+            buffer: UnsafeCell::new(buffer),
+        }
+    }
+
+    #[inline]
+    pub fn get(&self) -> &HugePageBuffer<T> {
+        unsafe { &*self.buffer.get() }
+    }
+
+    /// Replaces the buffer, dropping the old one.
+    ///
+    /// # Safety
+    ///
+    /// No reference returned by [`get`](Self::get) may be alive, i.e. no other thread may be using the table.
+    #[inline]
+    pub unsafe fn replace(&self, buffer: HugePageBuffer<T>) {
+        *self.buffer.get() = buffer;
+    }
+}
+
+impl<T: ZeroInitialized> From<HugePageBuffer<T>> for HugePageBufferCell<T> {
+    fn from(buffer: HugePageBuffer<T>) -> Self {
+        Self::new(buffer)
+    }
+}
+
+impl<T: ZeroInitialized> Default for HugePageBufferCell<T> {
+    fn default() -> Self {
+        Self::new(HugePageBuffer::default())
+    }
+}
+
+impl<T: ZeroInitialized> Debug for HugePageBufferCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ZeroInitialized + Serialize> Serialize for HugePageBufferCell<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: ZeroInitialized + Deserialize<'de>> Deserialize<'de> for HugePageBufferCell<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        HugePageBuffer::deserialize(deserializer).map(Self::new)
+    }
+}
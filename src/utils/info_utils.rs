@@ -1,12 +1,13 @@
 use super::*;
 
 pub fn format_info<T: fmt::Display>(desc: &str, info: T, add_info_string: bool) -> String {
+    let config = TimecatConfig::get_current();
     let mut desc = desc.trim().trim_end_matches(':').to_string();
-    if GLOBAL_TIMECAT_STATE.is_in_uci_mode() {
+    if config.is_in_uci_mode() {
         desc = desc.to_lowercase();
     }
     desc = desc.colorize(INFO_MESSAGE_STYLE);
-    if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+    if config.is_in_console_mode() {
         format!("{desc}: {info}")
     } else {
         let mut formatted_info = format!("{desc} {info}",);
@@ -23,7 +24,7 @@ pub fn force_println_info<T: fmt::Display>(desc: &str, info: T) {
 
 #[inline]
 pub fn println_info<T: fmt::Display>(desc: &str, info: T) {
-    if GLOBAL_TIMECAT_STATE.is_in_debug_mode() {
+    if TimecatConfig::get_current().is_in_debug_mode() {
         force_println_info(desc, info);
     }
 }
@@ -45,7 +46,7 @@ pub fn print_cache_table_info(
     let mut to_print = format!(
         "{name} initialization complete with {table_len} entries taking {table_size} space."
     );
-    if GLOBAL_TIMECAT_STATE.is_in_uci_mode() {
+    if TimecatConfig::get_current().is_in_uci_mode() {
         to_print = "info string ".to_string() + to_print.trim();
     }
     println_wasm!("{}", to_print.colorize(INFO_MESSAGE_STYLE));
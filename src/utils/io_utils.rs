@@ -1,5 +1,5 @@
 use super::*;
-use std::io::{self, Write};
+use std::io::{self, BufRead, Write};
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::Mutex;
 
@@ -9,7 +9,7 @@ pub fn print_line<T: fmt::Display>(line: T) {
         return;
     }
     print_wasm!("{to_print}");
-    io::stdout().flush().unwrap();
+    flush_output();
 }
 
 pub fn get_input<T: fmt::Display>(q: T, io_reader: &IoReader) -> String {
@@ -75,32 +75,164 @@ impl<T: Debug> From<T> for CustomDebug<T> {
     }
 }
 
-#[derive(Clone, Debug)]
+type InputSource = Box<dyn BufRead + Send>;
+
+/// Flags of a running search, which are set by the reader as soon as a stop or quit command arrives.
+#[derive(Debug)]
+struct SearchInterruptFlags {
+    stop_command: Arc<AtomicBool>,
+    terminate: Arc<AtomicBool>,
+}
+
+/// Unregisters the search from the [`IoReader`] when dropped.
+#[must_use = "The search is unregistered as soon as the guard is dropped."]
+pub struct SearchInterruptGuard {
+    search_interrupt_flags: Arc<Mutex<Option<SearchInterruptFlags>>>,
+}
+
+impl Drop for SearchInterruptGuard {
+    fn drop(&mut self) {
+        *self.search_interrupt_flags.lock().unwrap() = None;
+    }
+}
+
+/// Queue of the commands sent to the engine.
+///
+/// Commands are either read in parallel from an input source, which is the standard input by default but can be any
+/// [`BufRead`], or pushed directly with [`IoReader::push_command`]. While a search is registered, stop and quit
+/// commands are delivered to it immediately instead of being queued.
+#[derive(Clone)]
 pub struct IoReader {
     sender: Sender<String>,
     receiver: Arc<Mutex<Receiver<String>>>,
+    source: Arc<Mutex<Option<InputSource>>>,
+    quit_at_end_of_input: bool,
+    search_interrupt_flags: Arc<Mutex<Option<SearchInterruptFlags>>>,
 }
 
 impl IoReader {
-    pub fn new() -> Self {
+    fn new_with_optional_source(source: Option<InputSource>, quit_at_end_of_input: bool) -> Self {
         let (sender, receiver) = channel();
         Self {
             sender,
             receiver: Mutex::new(receiver).into(),
+            source: Mutex::new(source).into(),
+            quit_at_end_of_input,
+            search_interrupt_flags: Default::default(),
         }
     }
 
+    /// Creates a reader of the standard input.
+    pub fn new() -> Self {
+        Self::new_with_optional_source(Some(Box::new(BufReader::new(io::stdin()))), false)
+    }
+
+    /// Creates a reader of any input source, like a socket or a script file.
+    pub fn from_reader(reader: impl BufRead + Send + 'static) -> Self {
+        Self::new_with_optional_source(Some(Box::new(reader)), false)
+    }
+
+    /// Creates a reader of any input source which sends a quit command at the end of the input, like a socket closed
+    /// by the client.
+    pub fn from_reader_quitting_at_end(reader: impl BufRead + Send + 'static) -> Self {
+        Self::new_with_optional_source(Some(Box::new(reader)), true)
+    }
+
+    /// Creates a reader without any input source, which only receives the commands pushed with
+    /// [`Self::push_command`].
+    pub fn new_queue() -> Self {
+        Self::new_with_optional_source(None, false)
+    }
+
+    /// Creates a queue already containing the given commands.
+    pub fn from_commands<S: Into<String>>(commands: impl IntoIterator<Item = S>) -> Self {
+        let io_reader = Self::new_queue();
+        for command in commands {
+            io_reader.push_command(command);
+        }
+        io_reader
+    }
+
+    /// Delivers the command to the registered search if it is a stop or quit command, returning whether it was
+    /// consumed. Once the search is stopped, the commands are queued again, so that the ones sent right after a stop
+    /// are run after the search.
+    fn interrupt_search(flags: &SearchInterruptFlags, command: &str) -> bool {
+        if flags.stop_command.load(MEMORY_ORDERING) {
+            return false;
+        }
+        match command.trim().to_lowercase().as_str() {
+            "stop" => flags.stop_command.store(true, MEMORY_ORDERING),
+            "quit" | "exit" => {
+                flags.stop_command.store(true, MEMORY_ORDERING);
+                flags.terminate.store(true, MEMORY_ORDERING);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Sends a command to the engine, as if it was read from the input source.
+    pub fn push_command(&self, command: impl Into<String>) {
+        let command = command.into();
+        // The lock is held while sending, so that the commands stay in order while a search is being registered.
+        let search_interrupt_flags = self.search_interrupt_flags.lock().unwrap();
+        if let Some(flags) = search_interrupt_flags.as_ref() {
+            if Self::interrupt_search(flags, &command) {
+                return;
+            }
+        }
+        let _ = self.sender.send(command);
+    }
+
+    /// Starts reading the input source in a new thread, which stops at the end of the input. The source is read only
+    /// once, so without any source left to read the thread finishes right away.
     pub fn start_reader_in_parallel(&self) -> thread::JoinHandle<()> {
-        let sender = self.sender.clone();
-        thread::spawn(move || loop {
-            let mut user_input = String::new();
-            std::io::stdin()
-                .read_line(&mut user_input)
-                .expect("Failed to read line!");
-            let _ = sender.send(user_input);
+        let optional_source = self.source.lock().unwrap().take();
+        let io_reader = self.clone();
+        thread::spawn(move || {
+            let Some(mut source) = optional_source else {
+                return;
+            };
+            loop {
+                let mut user_input = String::new();
+                match source.read_line(&mut user_input) {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => io_reader.push_command(user_input),
+                }
+            }
+            if io_reader.quit_at_end_of_input {
+                io_reader.push_command("quit");
+            }
         })
     }
 
+    /// Registers a running search, so that stop and quit commands set the given flags as soon as they arrive. The
+    /// ones already waiting in the queue are delivered too, while the other commands are kept for after the search.
+    pub fn register_search(
+        &self,
+        stop_command: Arc<AtomicBool>,
+        terminate: Arc<AtomicBool>,
+    ) -> SearchInterruptGuard {
+        let flags = SearchInterruptFlags {
+            stop_command,
+            terminate,
+        };
+        let mut search_interrupt_flags = self.search_interrupt_flags.lock().unwrap();
+        // If another thread is waiting for a command, the queue is empty anyway.
+        if let Ok(receiver) = self.receiver.try_lock() {
+            let pending_commands = receiver.try_iter().collect_vec();
+            for command in pending_commands {
+                if !Self::interrupt_search(&flags, &command) {
+                    let _ = self.sender.send(command);
+                }
+            }
+        }
+        *search_interrupt_flags = Some(flags);
+        SearchInterruptGuard {
+            search_interrupt_flags: self.search_interrupt_flags.clone(),
+        }
+    }
+
     pub fn read_line_once(&self) -> Option<String> {
         self.receiver
             .lock()
@@ -109,12 +241,28 @@ impl IoReader {
             .ok()
     }
 
+    /// Returns the next command if there is one, without waiting.
+    pub fn try_read_line(&self) -> Option<String> {
+        self.receiver.lock().unwrap().try_recv().ok()
+    }
+
+    /// Waits for the next command.
     pub fn read_line(&self) -> String {
-        loop {
-            if let Some(input) = self.read_line_once() {
-                return input;
-            }
-        }
+        // The reader owns a sender itself, so the channel can never be disconnected.
+        self.receiver.lock().unwrap().recv().unwrap()
+    }
+}
+
+impl Debug for IoReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IoReader")
+            .field("has_unread_source", &self.source.lock().unwrap().is_some())
+            .field("quit_at_end_of_input", &self.quit_at_end_of_input)
+            .field(
+                "is_search_registered",
+                &self.search_interrupt_flags.lock().unwrap().is_some(),
+            )
+            .finish()
     }
 }
 
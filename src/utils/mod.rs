@@ -6,11 +6,14 @@ pub mod compression_utils;
 pub mod engine_utils;
 pub mod extension_traits;
 pub mod files;
+pub mod fnv_hasher;
 pub mod global_utils;
+pub mod huge_page_buffer;
 pub mod identity_hashmap;
 pub mod info_utils;
 pub mod io_utils;
 pub mod moves;
+pub mod output_sink;
 pub mod piece;
 pub mod psqt;
 pub mod pv_utils;
@@ -20,6 +23,7 @@ pub mod search_utils;
 pub mod serde_extension;
 pub mod square;
 pub mod string_utils;
+pub mod time_control;
 pub mod time_utils;
 
 use super::*;
@@ -31,11 +35,14 @@ pub use compression_utils::*;
 pub use engine_utils::*;
 pub use extension_traits::*;
 pub use files::*;
+pub use fnv_hasher::*;
 pub use global_utils::*;
+pub use huge_page_buffer::*;
 pub use identity_hashmap::*;
 pub use info_utils::*;
 pub use io_utils::*;
 pub use moves::*;
+pub use output_sink::*;
 pub use piece::*;
 pub use psqt::*;
 pub use pv_utils::*;
@@ -45,4 +52,5 @@ pub use search_utils::*;
 pub use serde_extension::*;
 pub use square::*;
 pub use string_utils::*;
+pub use time_control::*;
 pub use time_utils::*;
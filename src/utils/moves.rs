@@ -1,5 +1,7 @@
 use super::*;
 
+/// A move of a piece from the source to the destination, or a drop of a piece from the pocket in variants like
+/// Crazyhouse. A drop has the same source and destination and keeps the dropped piece in place of the promotion.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
 pub struct Move {
@@ -26,6 +28,12 @@ impl Move {
         Ok(Self::new_unchecked(source, dest, promotion))
     }
 
+    #[inline]
+    pub const fn new_drop(piece_type: PieceType, square: Square) -> Self {
+        Self::new_unchecked(square, square, Some(piece_type))
+    }
+
+    /// The square the piece moves from, which is the destination for drops.
     #[inline]
     pub const fn get_source(&self) -> Square {
         self.source
@@ -38,7 +46,26 @@ impl Move {
 
     #[inline]
     pub const fn get_promotion(&self) -> Option<PieceType> {
-        self.promotion
+        if self.is_drop() {
+            None
+        } else {
+            self.promotion
+        }
+    }
+
+    #[inline]
+    pub const fn is_drop(&self) -> bool {
+        self.source.to_int() == self.dest.to_int()
+    }
+
+    /// The piece dropped from the pocket, or [`None`] if the move is not a drop.
+    #[inline]
+    pub const fn get_drop(&self) -> Option<PieceType> {
+        if self.is_drop() {
+            self.promotion
+        } else {
+            None
+        }
     }
 
     #[inline]
@@ -48,7 +75,11 @@ impl Move {
 
     pub fn from_san(position: &BoardPosition, san: &str) -> Result<Self> {
         // TODO: Make the logic better
-        let san = san.trim().replace('0', "O");
+        let mut san = san.trim().replace('0', "O");
+        // The letter of pawn drops is often left out.
+        if san.starts_with('@') {
+            san.insert(0, 'P');
+        }
         for move_ in position.generate_legal_moves() {
             if move_.san(position).unwrap() == san {
                 return Ok(move_);
@@ -73,6 +104,14 @@ impl Move {
         let source = self.get_source();
         let dest = self.get_dest();
 
+        // Drops.
+        if let Some(piece_type) = self.get_drop() {
+            return Ok(format!(
+                "{}@{dest}",
+                piece_type.to_colored_piece_string(White)
+            ));
+        }
+
         // Castling.
         if position.is_castling(self) {
             return if dest.get_file() < source.get_file() {
@@ -200,6 +239,17 @@ impl FromStr for Move {
         if s.len() > 6 {
             return Err(error.clone());
         }
+        if let Some((piece_type, square)) = s.split_once('@') {
+            let piece_type = match piece_type.to_lowercase().as_str() {
+                "p" => Pawn,
+                "n" => Knight,
+                "b" => Bishop,
+                "r" => Rook,
+                "q" => Queen,
+                _ => return Err(error.clone()),
+            };
+            return Ok(Self::new_drop(piece_type, Square::from_str(square)?));
+        }
         let source = Square::from_str(s.get(0..2).ok_or(error.clone())?)?;
         let dest = Square::from_str(s.get(2..4).ok_or(error.clone())?)?;
 
@@ -210,6 +260,8 @@ impl FromStr for Move {
                 'r' => Rook,
                 'n' => Knight,
                 'b' => Bishop,
+                // Promoting to a king is legal in Antichess.
+                'k' => King,
                 _ => return Err(error.clone()),
             });
         }
@@ -220,6 +272,14 @@ impl FromStr for Move {
 
 impl fmt::Display for Move {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(piece_type) = self.get_drop() {
+            return write!(
+                f,
+                "{}@{}",
+                piece_type.to_colored_piece_string(White),
+                self.dest
+            );
+        }
         match self.promotion {
             Some(piece) => write!(f, "{}{}{}", self.source, self.dest, piece),
             None => write!(f, "{}{}", self.source, self.dest),
@@ -292,7 +352,7 @@ impl ValidOrNullMove {
 
     #[inline]
     pub fn get_promotion(&self) -> Option<PieceType> {
-        self.into_inner()?.promotion
+        self.into_inner()?.get_promotion()
     }
 
     pub fn from_san(position: &BoardPosition, san: &str) -> Result<Self> {
@@ -0,0 +1,189 @@
+use super::*;
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::sync::Mutex;
+
+/// Destination of everything printed by the engine, like UCI responses, search info and console messages.
+///
+/// The sink is owned by the engine and applied on the current thread while the engine runs, so library users can route
+/// the output to a socket, a buffer, a logger or a test harness instead of the standard output.
+pub trait OutputSink: Send + Sync {
+    /// Writes the text as it is, without appending a new line.
+    fn write_str(&self, text: &str);
+
+    fn write_line(&self, line: &str) {
+        self.write_str(&format!("{line}\n"));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Writes to the standard output, or to the browser console on wasm.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdoutSink;
+
+#[cfg(not(feature = "wasm"))]
+impl OutputSink for StdoutSink {
+    #[inline]
+    fn write_str(&self, text: &str) {
+        print!("{text}");
+    }
+
+    #[inline]
+    fn write_line(&self, line: &str) {
+        println!("{line}");
+    }
+
+    #[inline]
+    fn flush(&self) {
+        let _ = io::stdout().flush();
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl OutputSink for StdoutSink {
+    #[inline]
+    fn write_str(&self, text: &str) {
+        gloo::console::log!(text);
+    }
+
+    #[inline]
+    fn write_line(&self, line: &str) {
+        if line.is_empty() {
+            gloo::console::log!("\n");
+        } else {
+            gloo::console::log!(line);
+        }
+    }
+}
+
+/// Collects the output in memory, mainly to inspect it in tests.
+#[derive(Debug, Default)]
+pub struct BufferSink {
+    buffer: Mutex<String>,
+}
+
+impl BufferSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_output(&self) -> String {
+        self.buffer.lock().unwrap().clone()
+    }
+
+    pub fn get_lines(&self) -> Vec<String> {
+        self.buffer
+            .lock()
+            .unwrap()
+            .lines()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    /// Returns the output collected so far and empties the buffer.
+    pub fn take_output(&self) -> String {
+        std::mem::take(&mut *self.buffer.lock().unwrap())
+    }
+
+    pub fn clear(&self) {
+        self.buffer.lock().unwrap().clear();
+    }
+}
+
+impl OutputSink for BufferSink {
+    #[inline]
+    fn write_str(&self, text: &str) {
+        self.buffer.lock().unwrap().push_str(text);
+    }
+}
+
+/// Writes to any [`Write`] implementation, like a file or a socket. Write errors are ignored, as a disconnected client
+/// must not bring down the engine.
+#[derive(Debug)]
+pub struct WriterSink<W: Write + Send> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> WriterSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner().unwrap()
+    }
+}
+
+impl<W: Write + Send> OutputSink for WriterSink<W> {
+    #[inline]
+    fn write_str(&self, text: &str) {
+        let _ = self.writer.lock().unwrap().write_all(text.as_bytes());
+    }
+
+    #[inline]
+    fn flush(&self) {
+        let _ = self.writer.lock().unwrap().flush();
+    }
+}
+
+static DEFAULT_OUTPUT_SINK: LazyLock<Arc<dyn OutputSink>> = LazyLock::new(|| Arc::new(StdoutSink));
+
+thread_local! {
+    static CURRENT_OUTPUT_SINK: RefCell<Option<Arc<dyn OutputSink>>> = const { RefCell::new(None) };
+}
+
+/// Restores the previously active sink when [`with_output_sink`] returns or unwinds.
+struct OutputSinkGuard(Option<Arc<dyn OutputSink>>);
+
+impl Drop for OutputSinkGuard {
+    fn drop(&mut self) {
+        CURRENT_OUTPUT_SINK.set(self.0.take());
+    }
+}
+
+#[inline]
+pub fn get_default_output_sink() -> Arc<dyn OutputSink> {
+    DEFAULT_OUTPUT_SINK.clone()
+}
+
+/// Returns the sink applied on the current thread through [`with_output_sink`], or the standard output if there is
+/// none.
+pub fn get_current_output_sink() -> Arc<dyn OutputSink> {
+    CURRENT_OUTPUT_SINK
+        .with_borrow(|sink| sink.clone())
+        .unwrap_or_else(get_default_output_sink)
+}
+
+/// Runs `f` with all the output of the current thread going to `sink`.
+pub fn with_output_sink<R>(sink: Arc<dyn OutputSink>, f: impl FnOnce() -> R) -> R {
+    let _guard = OutputSinkGuard(CURRENT_OUTPUT_SINK.replace(Some(sink)));
+    f()
+}
+
+/// Runs `f` with both the output settings and the output sink of an engine.
+#[inline]
+pub fn with_output_settings<R>(
+    config: TimecatConfig,
+    sink: Arc<dyn OutputSink>,
+    f: impl FnOnce() -> R,
+) -> R {
+    with_output_sink(sink, || config.apply(f))
+}
+
+#[inline]
+pub fn write_output_str(text: &str) {
+    get_current_output_sink().write_str(text);
+}
+
+#[inline]
+pub fn write_output_line(line: &str) {
+    get_current_output_sink().write_line(line);
+}
+
+#[inline]
+pub fn flush_output() {
+    get_current_output_sink().flush();
+}
@@ -5,7 +5,10 @@ pub fn extract_pv_from_t_table(
     transposition_table: &TranspositionTable,
 ) -> Vec<Move> {
     let mut pv = Vec::new();
-    let best_move = transposition_table.read_best_move(position.get_hash());
+    // Entries can be overwritten by other threads at any time, so the move is verified before playing it.
+    let best_move = transposition_table
+        .read_best_move(position.get_hash())
+        .filter(|best_move| position.is_legal(best_move));
     if let Some(best_move) = best_move {
         pv.push(best_move);
         pv.append(&mut extract_pv_from_t_table(
@@ -51,12 +54,9 @@ pub fn get_pv_as_lan(position: &BoardPosition, pv: &[Move]) -> String {
 
 #[inline]
 pub fn get_pv_string(position: &BoardPosition, pv: &[Move]) -> String {
-    if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
-        get_pv_as_algebraic(
-            position,
-            pv,
-            GLOBAL_TIMECAT_STATE.use_long_algebraic_notation(),
-        )
+    let config = TimecatConfig::get_current();
+    if config.is_in_console_mode() {
+        get_pv_as_algebraic(position, pv, config.use_long_algebraic_notation())
     } else {
         get_pv_as_uci(pv)
     }
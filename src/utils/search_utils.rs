@@ -499,6 +499,12 @@ impl SearchInfo {
         }
     }
 
+    /// The position the search started from.
+    #[inline]
+    pub fn get_position(&self) -> &BoardPosition {
+        &self.position
+    }
+
     #[inline]
     pub fn get_current_depth(&self) -> Option<Depth> {
         self.current_depth
@@ -566,12 +572,21 @@ impl SearchInfo {
         ))
     }
 
+    #[inline]
     pub fn print_info(&self) {
+        self.print_info_with_config(&TimecatConfig::get_current());
+    }
+
+    pub fn print_info_with_config(&self, config: &TimecatConfig) {
+        config.apply(|| self.print_info_helper(config));
+    }
+
+    fn print_info_helper(&self, config: &TimecatConfig) {
         let hashfull_string = self.hash_full.map(|hash_full| {
-            if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+            if config.is_in_console_mode() {
                 format!("{:.2}%", hash_full)
             } else {
-                (hash_full.round() as u8).to_string()
+                ((hash_full * 10.0).round() as u16).to_string()
             }
         });
         let outputs = [
@@ -598,7 +613,8 @@ impl SearchInfo {
     }
 
     pub fn print_warning_message(&self, mut alpha: Score, mut beta: Score) {
-        if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+        let config = TimecatConfig::get_current();
+        if config.is_in_console_mode() {
             alpha = self.position.score_flipped(alpha);
             beta = self.position.score_flipped(beta);
         }
@@ -607,7 +623,7 @@ impl SearchInfo {
             if let Some(current_depth) = self.current_depth { current_depth.to_string() } else { STRINGIFY_NONE.to_string() },
             alpha.stringify(),
             beta.stringify(),
-            if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
+            if config.is_in_console_mode() {
                 self.get_score()
             } else {
                 self.get_score_flipped()
@@ -618,6 +634,69 @@ impl SearchInfo {
     }
 }
 
+/// What the side not to move would play if the side to move passed, as found by [`ChessEngine::search_threat`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Threat {
+    position: BoardPosition,
+    move_: Move,
+    score: Score,
+    score_delta: Score,
+}
+
+impl Threat {
+    pub fn new(position: BoardPosition, move_: Move, score: Score, score_delta: Score) -> Self {
+        Self {
+            position,
+            move_,
+            score,
+            score_delta,
+        }
+    }
+
+    /// The position after passing, where the threatening side is to move.
+    #[inline]
+    pub fn get_position(&self) -> &BoardPosition {
+        &self.position
+    }
+
+    #[inline]
+    pub fn get_move(&self) -> Move {
+        self.move_
+    }
+
+    /// The score after passing, from the side of the threatened player.
+    #[inline]
+    pub fn get_score(&self) -> Score {
+        self.score
+    }
+
+    /// How much the threatened player loses by passing, which is large for a real threat.
+    #[inline]
+    pub fn get_score_delta(&self) -> Score {
+        self.score_delta
+    }
+
+    /// Whether the threatening side mates if the threat is not answered.
+    #[inline]
+    pub fn is_mate_threat(&self) -> bool {
+        is_checkmate(self.score) && self.score < 0
+    }
+}
+
+impl fmt::Display for Threat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} threatens {}",
+            self.position.turn(),
+            self.move_
+                .san(&self.position)
+                .unwrap_or_else(|_| self.move_.to_string())
+        )
+    }
+}
+
 impl<P: PositionEvaluation> From<&Searcher<P>> for SearchInfo {
     fn from(searcher: &Searcher<P>) -> Self {
         #[cfg(feature = "extras")]
@@ -13,6 +13,22 @@ pub fn simplify_fen(fen: &str) -> String {
     remove_double_spaces_and_trim(fen)
 }
 
+/// Number of single character insertions, deletions or substitutions turning `a` into `b`.
+pub fn get_edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect_vec();
+    let mut previous_row = (0..=b.len()).collect_vec();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current_row = vec![i + 1; b.len() + 1];
+        for (j, &b_char) in b.iter().enumerate() {
+            current_row[j + 1] = (previous_row[j] + usize::from(a_char != b_char))
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        previous_row = current_row;
+    }
+    previous_row[b.len()]
+}
+
 pub fn flip_board_fen(fen: &str) -> Result<String> {
     // TODO: ep square not flipped.
     let fen = remove_double_spaces_and_trim(fen);
@@ -36,7 +52,7 @@ pub fn flip_board_fen(fen: &str) -> Result<String> {
 impl<T: ToString> CustomColorize for T {
     fn colorize(&self, style_functions: &[ColoredStringFunction]) -> String {
         let self_string = self.to_string();
-        if style_functions.is_empty() || !GLOBAL_TIMECAT_STATE.is_colored_output() {
+        if style_functions.is_empty() || !TimecatConfig::get_current().is_colored_output() {
             return self_string;
         }
         let mut colorized_string = self_string.into();
@@ -96,10 +112,9 @@ impl StringifyScore for Score {
         format!("cp {}", (self as i32 * 100) / PAWN_VALUE as i32)
     }
 
-    #[inline]
-    fn stringify_score(self) -> String {
-        if GLOBAL_TIMECAT_STATE.is_in_console_mode() {
-            self.stringify_score_console()
+    fn stringify_score_with_config(self, config: &TimecatConfig) -> String {
+        if config.is_in_console_mode() {
+            config.apply(|| self.stringify_score_console())
         } else {
             self.stringify_score_uci()
         }
@@ -129,8 +144,12 @@ impl StringifyMove for Move {
         Ok(self.algebraic_and_new_position(position, long)?.0)
     }
 
-    fn stringify_move(self, position: &BoardPosition) -> Result<String> {
-        Some(self).stringify_move(position)
+    fn stringify_move_with_config(
+        self,
+        position: &BoardPosition,
+        config: &TimecatConfig,
+    ) -> Result<String> {
+        Some(self).stringify_move_with_config(position, config)
     }
 }
 
@@ -149,9 +168,13 @@ impl StringifyMove for Option<Move> {
         }
     }
 
-    fn stringify_move(self, position: &BoardPosition) -> Result<String> {
-        match GLOBAL_TIMECAT_STATE.is_in_console_mode() {
-            true => self.algebraic(position, GLOBAL_TIMECAT_STATE.use_long_algebraic_notation()),
+    fn stringify_move_with_config(
+        self,
+        position: &BoardPosition,
+        config: &TimecatConfig,
+    ) -> Result<String> {
+        match config.is_in_console_mode() {
+            true => self.algebraic(position, config.use_long_algebraic_notation()),
             false => Ok(self.uci()),
         }
     }
@@ -165,7 +188,7 @@ impl StringifyHash for u64 {
 
 impl Stringify for Duration {
     fn stringify(&self) -> String {
-        if GLOBAL_TIMECAT_STATE.is_in_uci_mode() {
+        if TimecatConfig::get_current().is_in_uci_mode() {
             return self.as_millis().to_string();
         }
         if self < &Duration::from_secs(1) {
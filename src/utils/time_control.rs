@@ -0,0 +1,379 @@
+use super::*;
+
+fn parse_seconds(s: &str) -> Option<Duration> {
+    let secs = s.parse::<f64>().ok()?;
+    if !secs.is_finite() || secs < 0.0 {
+        return None;
+    }
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+fn format_seconds(duration: Duration) -> String {
+    duration.as_secs_f64().to_string()
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Delay {
+    /// The clock only starts running after the delay has passed.
+    Simple(Duration),
+    /// After the move, the time used is given back, up to the delay.
+    Bronstein(Duration),
+}
+
+impl Delay {
+    #[inline]
+    pub const fn get_duration(self) -> Duration {
+        match self {
+            Self::Simple(duration) | Self::Bronstein(duration) => duration,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct TimeControlPeriod {
+    moves: Option<NumMoves>,
+    base_time: Duration,
+    increment: Duration,
+    delay: Option<Delay>,
+}
+
+impl TimeControlPeriod {
+    #[inline]
+    pub const fn new(
+        moves: Option<NumMoves>,
+        base_time: Duration,
+        increment: Duration,
+        delay: Option<Delay>,
+    ) -> Self {
+        Self {
+            moves,
+            base_time,
+            increment,
+            delay,
+        }
+    }
+
+    #[inline]
+    pub const fn get_moves(&self) -> Option<NumMoves> {
+        self.moves
+    }
+
+    #[inline]
+    pub const fn get_base_time(&self) -> Duration {
+        self.base_time
+    }
+
+    #[inline]
+    pub const fn get_increment(&self) -> Duration {
+        self.increment
+    }
+
+    #[inline]
+    pub const fn get_delay(&self) -> Option<Delay> {
+        self.delay
+    }
+}
+
+impl fmt::Display for TimeControlPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(moves) = self.moves {
+            write!(f, "{}/", moves)?;
+        }
+        write!(f, "{}", format_seconds(self.base_time))?;
+        if !self.increment.is_zero() {
+            write!(f, "+{}", format_seconds(self.increment))?;
+        }
+        match self.delay {
+            Some(Delay::Simple(delay)) => write!(f, "d{}", format_seconds(delay)),
+            Some(Delay::Bronstein(delay)) => write!(f, "b{}", format_seconds(delay)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for TimeControlPeriod {
+    type Err = TimecatError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let error = || TimecatError::InvalidTimeControlString { s: s.to_string() };
+        let lowercase = s.trim().to_lowercase();
+        let (moves, rest) = match lowercase.split_once('/') {
+            Some((moves, rest)) => (Some(moves.parse().map_err(|_| error())?), rest),
+            None => (None, lowercase.as_str()),
+        };
+        if moves == Some(0) {
+            return Err(error());
+        }
+        let (rest, delay) = if let Some((rest, delay)) = rest.split_once('d') {
+            (
+                rest,
+                Some(Delay::Simple(parse_seconds(delay).ok_or_else(error)?)),
+            )
+        } else if let Some((rest, delay)) = rest.split_once('b') {
+            (
+                rest,
+                Some(Delay::Bronstein(parse_seconds(delay).ok_or_else(error)?)),
+            )
+        } else {
+            (rest, None)
+        };
+        let (base_time, increment) = match rest.split_once('+') {
+            Some((base_time, increment)) => (
+                parse_seconds(base_time).ok_or_else(error)?,
+                parse_seconds(increment).ok_or_else(error)?,
+            ),
+            None => (parse_seconds(rest).ok_or_else(error)?, Duration::ZERO),
+        };
+        Ok(Self::new(moves, base_time, increment, delay))
+    }
+}
+
+/// A clock model for a whole game, written and parsed in the PGN `TimeControl` tag syntax.
+///
+/// Periods are separated by `:` and look like `[moves/]seconds[+increment]`, so `40/5400+30:1800+30`
+/// is 40 moves in 90 minutes followed by 30 minutes for the rest of the game, both with a 30 second
+/// increment. A period may end with `d<seconds>` for a simple delay or `b<seconds>` for a Bronstein
+/// delay. `*seconds` is an hourglass, `st=seconds` is a fixed time per move and `-` means no time
+/// control at all.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum TimeControl {
+    Unlimited,
+    MoveTime(Duration),
+    Hourglass(Duration),
+    Periods(Vec<TimeControlPeriod>),
+}
+
+impl TimeControl {
+    #[inline]
+    pub fn new_sudden_death(base_time: Duration, increment: Duration) -> Self {
+        Self::Periods(vec![TimeControlPeriod::new(
+            None, base_time, increment, None,
+        )])
+    }
+
+    #[inline]
+    pub fn new_periods(periods: Vec<TimeControlPeriod>) -> Result<Self> {
+        if periods.is_empty()
+            || periods[..periods.len() - 1]
+                .iter()
+                .any(|period| period.get_moves().is_none())
+        {
+            return Err(TimecatError::InvalidTimeControlString {
+                s: periods.iter().join(":"),
+            });
+        }
+        Ok(Self::Periods(periods))
+    }
+
+    #[inline]
+    pub fn is_unlimited(&self) -> bool {
+        *self == Self::Unlimited
+    }
+
+    #[inline]
+    pub fn get_periods(&self) -> &[TimeControlPeriod] {
+        match self {
+            Self::Periods(periods) => periods,
+            _ => &[],
+        }
+    }
+
+    /// Returns the period with the given index, repeating the last period once all of them are used up.
+    pub fn get_period(&self, index: usize) -> Option<&TimeControlPeriod> {
+        let periods = self.get_periods();
+        periods.get(index).or(periods.last())
+    }
+}
+
+impl fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unlimited => write!(f, "-"),
+            Self::MoveTime(duration) => write!(f, "st={}", format_seconds(*duration)),
+            Self::Hourglass(duration) => write!(f, "*{}", format_seconds(*duration)),
+            Self::Periods(periods) => write!(f, "{}", periods.iter().join(":")),
+        }
+    }
+}
+
+impl FromStr for TimeControl {
+    type Err = TimecatError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let error = || TimecatError::InvalidTimeControlString { s: s.to_string() };
+        let trimmed = s.trim().to_lowercase();
+        if trimmed == "-" {
+            return Ok(Self::Unlimited);
+        }
+        if let Some(duration) = trimmed.strip_prefix('*') {
+            return Ok(Self::Hourglass(parse_seconds(duration).ok_or_else(error)?));
+        }
+        if let Some(duration) = trimmed.strip_prefix("st=") {
+            return Ok(Self::MoveTime(parse_seconds(duration).ok_or_else(error)?));
+        }
+        let periods = trimmed
+            .split(':')
+            .map(TimeControlPeriod::from_str)
+            .collect::<Result<Vec<_>>>()
+            .map_err(|_| error())?;
+        Self::new_periods(periods).map_err(|_| error())
+    }
+}
+
+/// Keeps both clocks of a game running under a [`TimeControl`] and detects when a player flags.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct GameClock {
+    time_control: TimeControl,
+    remaining_times: [Duration; NUM_COLORS],
+    period_indices: [usize; NUM_COLORS],
+    moves_played_in_period: [NumMoves; NUM_COLORS],
+    flagged_color: Option<Color>,
+}
+
+impl GameClock {
+    /// Fails if the time control has no periods, which the variant allows to be built directly.
+    pub fn new(time_control: TimeControl) -> Result<Self> {
+        let initial_time = match &time_control {
+            TimeControl::Unlimited => Duration::MAX,
+            &TimeControl::MoveTime(duration) | &TimeControl::Hourglass(duration) => duration,
+            TimeControl::Periods(periods) => periods
+                .first()
+                .ok_or_else(|| TimecatError::InvalidTimeControlString {
+                    s: time_control.to_string(),
+                })?
+                .get_base_time(),
+        };
+        Ok(Self {
+            time_control,
+            remaining_times: [initial_time; NUM_COLORS],
+            period_indices: [0; NUM_COLORS],
+            moves_played_in_period: [0; NUM_COLORS],
+            flagged_color: None,
+        })
+    }
+
+    #[inline]
+    pub fn get_time_control(&self) -> &TimeControl {
+        &self.time_control
+    }
+
+    #[inline]
+    pub fn get_remaining_time(&self, color: Color) -> Duration {
+        self.remaining_times[color.to_index()]
+    }
+
+    #[inline]
+    pub fn get_flagged_color(&self) -> Option<Color> {
+        self.flagged_color
+    }
+
+    #[inline]
+    pub fn is_flagged(&self, color: Color) -> bool {
+        self.flagged_color == Some(color)
+    }
+
+    #[inline]
+    pub fn get_current_period(&self, color: Color) -> Option<&TimeControlPeriod> {
+        self.time_control
+            .get_period(self.period_indices[color.to_index()])
+    }
+
+    pub fn get_moves_to_go(&self, color: Color) -> Option<NumMoves> {
+        let moves = self.get_current_period(color)?.get_moves()?;
+        Some(moves - self.moves_played_in_period[color.to_index()])
+    }
+
+    /// The time the player can spend on the next move before flagging, including a simple delay.
+    pub fn get_available_time(&self, color: Color) -> Duration {
+        let remaining_time = self.get_remaining_time(color);
+        match self
+            .get_current_period(color)
+            .and_then(|period| period.get_delay())
+        {
+            Some(Delay::Simple(delay)) => remaining_time.saturating_add(delay),
+            _ => remaining_time,
+        }
+    }
+
+    /// The delays are left out, as a simple delay is already part of the available time and a Bronstein delay only
+    /// gives back the time actually used.
+    fn get_effective_increment(&self, color: Color) -> Duration {
+        self.get_current_period(color)
+            .map_or(Duration::ZERO, |period| period.get_increment())
+    }
+
+    /// Converts the clocks into the search budget for the side to move, or `None` if there is no
+    /// time control.
+    pub fn get_go_command(&self, turn: Color) -> Option<GoCommand> {
+        match self.time_control {
+            TimeControl::Unlimited => None,
+            TimeControl::MoveTime(duration) => Some(GoCommand::from_movetime(duration)),
+            TimeControl::Hourglass(_) | TimeControl::Periods(_) => {
+                Some(GoCommand::from_time_clock(
+                    self.get_available_time(White),
+                    self.get_available_time(Black),
+                    self.get_effective_increment(White),
+                    self.get_effective_increment(Black),
+                    self.get_moves_to_go(turn),
+                ))
+            }
+        }
+    }
+
+    /// Charges `time_taken` to the clock of `color` and returns `false` if the player has flagged.
+    pub fn update(&mut self, color: Color, time_taken: Duration) -> bool {
+        if self.flagged_color.is_some() {
+            return false;
+        }
+        let index = color.to_index();
+        let charged_time = match self.time_control {
+            TimeControl::Unlimited => return true,
+            TimeControl::MoveTime(duration) => {
+                if time_taken > duration {
+                    self.flagged_color = Some(color);
+                    return false;
+                }
+                return true;
+            }
+            TimeControl::Hourglass(_) => time_taken,
+            TimeControl::Periods(_) => match self
+                .get_current_period(color)
+                .and_then(|period| period.get_delay())
+            {
+                Some(Delay::Simple(delay)) => time_taken.saturating_sub(delay),
+                _ => time_taken,
+            },
+        };
+        let Some(remaining_time) = self.remaining_times[index].checked_sub(charged_time) else {
+            self.remaining_times[index] = Duration::ZERO;
+            self.flagged_color = Some(color);
+            return false;
+        };
+        self.remaining_times[index] = remaining_time;
+        if let TimeControl::Hourglass(_) = self.time_control {
+            let opponent_index = (!color).to_index();
+            self.remaining_times[opponent_index] =
+                self.remaining_times[opponent_index].saturating_add(time_taken);
+            return true;
+        }
+        let period = self.get_current_period(color).unwrap().to_owned();
+        let mut refund = period.get_increment();
+        if let Some(Delay::Bronstein(delay)) = period.get_delay() {
+            refund = refund.saturating_add(time_taken.min(delay));
+        }
+        self.remaining_times[index] = self.remaining_times[index].saturating_add(refund);
+        self.moves_played_in_period[index] += 1;
+        if Some(self.moves_played_in_period[index]) == period.get_moves() {
+            self.moves_played_in_period[index] = 0;
+            self.period_indices[index] += 1;
+            let next_base_time = self.get_current_period(color).unwrap().get_base_time();
+            self.remaining_times[index] =
+                self.remaining_times[index].saturating_add(next_base_time);
+        }
+        true
+    }
+}
@@ -0,0 +1,120 @@
+use timecat::*;
+
+#[test]
+fn test_cache_table_get_and_add() {
+    let cache_table = CacheTable::<i16>::new(CacheTableSize::Exact(1));
+    assert!(cache_table.is_empty());
+    cache_table.add(12345, -42);
+    assert_eq!(cache_table.get(12345), Some(-42));
+    assert_eq!(cache_table.get(54321), None);
+    assert_eq!(cache_table.get_num_cells_filled(), 1);
+    cache_table.add(12345, 42);
+    assert_eq!(cache_table.get(12345), Some(42));
+    assert_eq!(cache_table.get_num_cells_filled(), 1);
+    cache_table.clear();
+    assert!(cache_table.is_empty());
+    assert_eq!(cache_table.get(12345), None);
+}
+
+#[test]
+fn test_cache_table_zero_hash() {
+    let cache_table = CacheTable::<u64>::new(CacheTableSize::Exact(1));
+    cache_table.add(0, u64::MAX);
+    assert_eq!(cache_table.get(0), Some(u64::MAX));
+}
+
+#[test]
+fn test_cache_table_replace_if() {
+    let cache_table = CacheTable::<u8>::new(CacheTableSize::Exact(1));
+    cache_table.replace_if(7, 10, |_| false);
+    assert_eq!(cache_table.get(7), Some(10));
+    cache_table.replace_if(7, 5, |old| old < 5);
+    assert_eq!(cache_table.get(7), Some(10));
+    cache_table.replace_if(7, 20, |old| old < 20);
+    assert_eq!(cache_table.get(7), Some(20));
+}
+
+#[test]
+fn test_cache_table_set_size_keeps_entries() {
+    let cache_table = CacheTable::<u32>::new(CacheTableSize::Exact(1));
+    for hash in 1..=100 {
+        cache_table.add(hash, hash as u32);
+    }
+    cache_table.set_size(CacheTableSize::Exact(2));
+    assert_eq!(cache_table.get_size(), CacheTableSize::Exact(2));
+    for hash in 1..=100 {
+        assert_eq!(cache_table.get(hash), Some(hash as u32));
+    }
+}
+
+#[test]
+fn test_cache_table_concurrent_access() {
+    let cache_table = CacheTable::<u64>::new(CacheTableSize::Exact(1));
+    let hash =
+        |thread_id: u64, index: u64| (thread_id << 32 | index).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    thread::scope(|scope| {
+        for thread_id in 0..8 {
+            let cache_table = &cache_table;
+            scope.spawn(move || {
+                for index in 0..10_000 {
+                    let hash = hash(thread_id, index);
+                    cache_table.add(hash, hash.rotate_left(17));
+                    // Torn writes from other threads must never be returned as valid entries.
+                    if let Some(data) = cache_table.get(hash) {
+                        assert_eq!(data, hash.rotate_left(17));
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_huge_page_buffer_zeroing() {
+    let num_threads = NonZeroUsize::new(4).unwrap();
+    // Large enough to be backed by huge pages on Linux and to be zeroed in parallel.
+    let len = 4 * PARALLEL_ZEROING_MIN_SIZE / size_of::<AtomicU64>();
+    let mut buffer = HugePageBuffer::<AtomicU64>::new_zeroed(len, num_threads);
+    assert_eq!(buffer.len(), len);
+    #[cfg(target_os = "linux")]
+    assert!(buffer.is_huge_page_backed());
+    assert!(buffer.iter().all(|cell| cell.load(MEMORY_ORDERING) == 0));
+    buffer.for_each_parallel(num_threads, |cell| cell.store(u64::MAX, MEMORY_ORDERING));
+    assert!(buffer
+        .iter()
+        .all(|cell| cell.load(MEMORY_ORDERING) == u64::MAX));
+    buffer.zero(num_threads);
+    assert!(buffer.iter().all(|cell| cell.load(MEMORY_ORDERING) == 0));
+    let small_buffer = HugePageBuffer::<AtomicU64>::new_zeroed(10, num_threads);
+    assert_eq!(small_buffer.get_allocation_kind(), AllocationKind::Global);
+}
+
+#[test]
+fn test_cache_table_parallel_clear() {
+    let cache_table = CacheTable::<u64>::new(CacheTableSize::Exact(64));
+    cache_table.set_num_threads(NonZeroUsize::new(4).unwrap());
+    for hash in 1..=1000 {
+        cache_table.add(hash, hash);
+    }
+    cache_table.clear();
+    assert!(cache_table.is_empty());
+    assert!((1..=1000).all(|hash| cache_table.get(hash).is_none()));
+}
+
+#[test]
+fn test_cache_table_with_compound_entries() {
+    let cache_table = CacheTable::<(Score, Option<Move>)>::new(CacheTableSize::Exact(1));
+    let entry = (-42, Some(Move::from_str("e2e4").unwrap()));
+    cache_table.add(12345, entry);
+    assert_eq!(cache_table.get(12345), Some(entry));
+    let table = cache_table.get_table();
+    assert_eq!(table.len(), cache_table.len());
+    assert_eq!(
+        table
+            .iter()
+            .flatten()
+            .map(|entry| entry.get_entry())
+            .collect_vec(),
+        [entry]
+    );
+}
@@ -0,0 +1,163 @@
+use timecat::*;
+
+fn run_with_args(args: &[&str]) -> (Timecat<Engine>, String) {
+    let buffer = Arc::new(BufferSink::new());
+    #[cfg_attr(not(feature = "colored"), expect(unused_mut))]
+    let mut config = TimecatConfig::default();
+    #[cfg(feature = "colored")]
+    config.set_colored_output(false);
+    let mut timecat = TimecatBuilder::<Engine>::default()
+        .set_config(config)
+        .set_output_sink(buffer.clone())
+        .set_io_reader(IoReader::new_queue())
+        .parse_args(args)
+        .build();
+    timecat.run();
+    (timecat, buffer.get_output())
+}
+
+#[test]
+fn test_cli_parse_errors() {
+    assert!(matches!(
+        CliArgs::parse(&["timecat", "--unknown"]),
+        Err(TimecatError::UnknownCliOption { .. })
+    ));
+    assert!(matches!(
+        CliArgs::parse(&["timecat", "fly"]),
+        Err(TimecatError::UnknownSubcommand { .. })
+    ));
+    assert!(matches!(
+        CliArgs::parse(&["timecat", "perft", "3", "--depth", "2"]),
+        Err(TimecatError::CliOptionNotApplicable { .. })
+    ));
+    assert!(matches!(
+        CliArgs::parse(&["timecat", "--threads"]),
+        Err(TimecatError::MissingCliValue { .. })
+    ));
+    assert!(matches!(
+        CliArgs::parse(&["timecat", "perft"]),
+        Err(TimecatError::MissingCliArgument { .. })
+    ));
+    assert!(matches!(
+        CliArgs::parse(&["timecat", "bench", "extra"]),
+        Err(TimecatError::UnexpectedCliArgument { .. })
+    ));
+    assert!(matches!(
+        CliArgs::parse(&["timecat", "--threads=two"])
+            .unwrap()
+            .to_user_commands(),
+        Err(TimecatError::InvalidCliValue { .. })
+    ));
+}
+
+#[test]
+fn test_cli_options_and_arguments() {
+    let cli_args = CliArgs::parse(&[
+        "timecat",
+        "analyse",
+        "--threads=2",
+        "8/8/8/4k3/8/8/4K3/8",
+        "w",
+        "-",
+        "-",
+        "0",
+        "1",
+        "-d",
+        "3",
+    ])
+    .unwrap();
+    assert_eq!(cli_args.get_subcommand(), Some("analyse"));
+    assert_eq!(
+        cli_args.get_argument(),
+        Some("8/8/8/4k3/8/8/4K3/8 w - - 0 1")
+    );
+    assert_eq!(cli_args.parse_option::<usize>("threads").unwrap(), Some(2));
+    assert_eq!(cli_args.get_option("depth"), Some("3"));
+    let cli_args = CliArgs::parse(&["timecat", "-c", "go", "depth", "1", "--no-color"]).unwrap();
+    assert_eq!(cli_args.get_option("command"), Some("go depth 1"));
+    assert!(cli_args.has_flag("no-color"));
+}
+
+#[test]
+fn test_cli_help_lists_everything() {
+    let help = generate_cli_help();
+    for subcommand in CLI_SUBCOMMANDS {
+        assert!(help.contains(subcommand.get_name()));
+    }
+    for option in CLI_OPTIONS {
+        assert!(help.contains(&format!("--{}", option.get_long())));
+    }
+}
+
+#[test]
+fn test_cli_perft() {
+    let (timecat, output) = run_with_args(&[
+        "timecat",
+        "perft",
+        "2",
+        "--fen",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    ]);
+    assert!(timecat.get_engine().terminate());
+    assert!(output.contains("Position Count: 400"));
+}
+
+#[test]
+fn test_cli_unquoted_fen() {
+    let cli_args = CliArgs::parse(&[
+        "timecat",
+        "perft",
+        "--fen",
+        "8/8/8/4k3/8/8/4K3/8",
+        "w",
+        "-",
+        "-",
+        "0",
+        "1",
+        "2",
+    ])
+    .unwrap();
+    assert_eq!(
+        cli_args.get_option("fen"),
+        Some("8/8/8/4k3/8/8/4K3/8 w - - 0 1")
+    );
+    assert_eq!(cli_args.get_argument(), Some("2"));
+    let (timecat, output) = run_with_args(&[
+        "timecat",
+        "perft",
+        "2",
+        "--fen",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR",
+        "w",
+        "KQkq",
+        "-",
+        "0",
+        "1",
+    ]);
+    assert!(timecat.get_engine().terminate());
+    assert!(output.contains("Position Count: 400"));
+}
+
+#[test]
+fn test_cli_analyse_pgn() {
+    let (timecat, output) =
+        run_with_args(&["timecat", "analyse", "1. e4 e5 2. Nf3 Nc6", "--depth", "2"]);
+    assert_eq!(
+        timecat.get_engine().get_board().get_fen(),
+        "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3"
+    );
+    assert!(!output.is_empty());
+}
+
+#[test]
+fn test_board_from_pgn() {
+    let board = Board::from_pgn(
+        "[Event \"Test\"]\n[Result \"*\"]\n\n1. e4 {best by test} e5 (1... c5 2. Nf3) 2. Nf3! $1 Nc6?! *",
+    )
+    .unwrap();
+    assert_eq!(board.get_ply(), 4);
+    let board =
+        Board::from_pgn("[FEN \"8/8/8/4k3/8/8/4K3/7R w - - 0 1\"]\n\n1. Rh5+ Kd4 1/2-1/2").unwrap();
+    assert_eq!(board.get_fen(), "8/8/8/7R/3k4/8/4K3/8 w - - 2 2");
+    assert!(Board::from_pgn("1. e4 e4").is_err());
+}
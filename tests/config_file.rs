@@ -0,0 +1,134 @@
+#![cfg(feature = "config_file")]
+
+use timecat::*;
+
+const CONFIG_TOML: &str = r#"
+profile = "blitz"
+
+[options]
+Threads = 2
+Hash = 8
+
+[profiles.analysis]
+threads = 3
+
+[profiles.blitz]
+"Move Overhead" = 50
+"#;
+
+fn get_temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("timecat-test-{}-{name}", std::process::id()))
+}
+
+fn get_uci_config() -> TimecatConfig {
+    let mut config = TimecatConfig::default();
+    config.set_uci_mode(true);
+    #[cfg(feature = "colored")]
+    config.set_colored_output(false);
+    config
+}
+
+fn build_timecat(config_file_path: &Path, optional_profile: Option<&str>) -> Timecat<Engine> {
+    let mut builder = TimecatBuilder::<Engine>::default()
+        .set_config(get_uci_config())
+        .set_output_sink(Arc::new(BufferSink::new()))
+        .set_config_file(config_file_path);
+    if let Some(profile) = optional_profile {
+        builder = builder.set_profile(profile);
+    }
+    builder.build()
+}
+
+#[test]
+fn test_profiles_override_common_options() {
+    let config_file =
+        EngineConfigFile::from_str_with_format(CONFIG_TOML, ConfigFileFormat::Toml).unwrap();
+    let options = config_file.get_resolved_options(None).unwrap();
+    assert_eq!(options["threads"], UCIOptionValue::Spin(2));
+    assert_eq!(options["move overhead"], UCIOptionValue::Spin(50));
+    let options = config_file.get_resolved_options(Some("analysis")).unwrap();
+    assert_eq!(options["threads"], UCIOptionValue::Spin(3));
+    assert!(!options.contains_key("move overhead"));
+    assert!(config_file.get_resolved_options(Some("bullet")).is_err());
+}
+
+#[test]
+fn test_toml_and_json_round_trip() {
+    let config_file =
+        EngineConfigFile::from_str_with_format(CONFIG_TOML, ConfigFileFormat::Toml).unwrap();
+    for format in [ConfigFileFormat::Toml, ConfigFileFormat::Json] {
+        let s = config_file.to_string_with_format(format).unwrap();
+        assert_eq!(
+            EngineConfigFile::from_str_with_format(&s, format).unwrap(),
+            config_file
+        );
+    }
+    assert_eq!(
+        ConfigFileFormat::from_path(Path::new("timecat.JSON")),
+        ConfigFileFormat::Json
+    );
+    assert!(EngineConfigFile::from_str_with_format("[options", ConfigFileFormat::Toml).is_err());
+}
+
+#[test]
+fn test_config_file_is_applied_and_saved() {
+    let path = get_temp_path("applied.toml");
+    std::fs::write(&path, CONFIG_TOML).unwrap();
+    let mut timecat = build_timecat(&path, Some("analysis"));
+    assert_eq!(timecat.get_engine().get_num_threads(), 3);
+    timecat
+        .run_uci_command("setoption name Hash value 4")
+        .unwrap();
+    timecat
+        .run_uci_command("saveoptions profile analysis")
+        .unwrap();
+    let config_file = EngineConfigFile::load(&path).unwrap();
+    let analysis_options = config_file.get_profile_options("analysis").unwrap();
+    assert_eq!(analysis_options["Threads"], UCIOptionValue::Spin(3));
+    assert_eq!(analysis_options["Hash"], UCIOptionValue::Spin(4));
+    // The differently spelled option is replaced instead of duplicated.
+    assert!(!analysis_options.contains_key("threads"));
+    // The rest of the file is kept.
+    assert_eq!(config_file.get_profile(), Some("blitz"));
+    assert_eq!(config_file.get_options()["Hash"], UCIOptionValue::Spin(8));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_save_options_to_new_json_file() {
+    let path = get_temp_path("saved.json");
+    let _ = std::fs::remove_file(&path);
+    let mut timecat = TimecatBuilder::<Engine>::default()
+        .set_config(get_uci_config())
+        .set_output_sink(Arc::new(BufferSink::new()))
+        .build();
+    timecat
+        .run_uci_command("setoption name Threads value 2")
+        .unwrap();
+    timecat
+        .run_uci_command(&format!("saveoptions {}", path.display()))
+        .unwrap();
+    let config_file = EngineConfigFile::load(&path).unwrap();
+    assert_eq!(config_file.get_options().len(), 1);
+    assert_eq!(
+        config_file.get_options()["Threads"],
+        UCIOptionValue::Spin(2)
+    );
+    let timecat = build_timecat(&path, None);
+    assert_eq!(timecat.get_engine().get_num_threads(), 2);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_bad_config_file_is_reported() {
+    let path = get_temp_path("bad.toml");
+    std::fs::write(&path, "[options]\nUnknown = 1\n").unwrap();
+    let buffer = Arc::new(BufferSink::new());
+    TimecatBuilder::<Engine>::default()
+        .set_config(get_uci_config())
+        .set_output_sink(buffer.clone())
+        .set_config_file(&path)
+        .build();
+    assert!(buffer.get_output().contains("Unknown UCI option"));
+    std::fs::remove_file(&path).unwrap();
+}
@@ -5,3 +5,112 @@ fn test_go_infinite() {
     let mut engine = Engine::from_fen("8/3R4/p5kp/P1p3p1/6P1/8/6P1/Q6K w - - 0 47").unwrap();
     let _ = engine.go_verbose(&SearchConfig::new_infinite());
 }
+
+fn engine_with_extensions(fen: &str, singular: bool, check: bool, multicut: bool) -> Engine {
+    let mut engine = Engine::from_fen(fen).unwrap();
+    let properties = engine.get_properties_mut();
+    properties.set_using_singular_extensions(singular);
+    properties.set_using_check_extensions(check);
+    properties.set_using_multicut(multicut);
+    engine
+}
+
+#[test]
+fn test_search_extensions_find_mate() {
+    let fen = "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4";
+    for (singular, check, multicut) in (0..8).map(|i| (i & 1 != 0, i & 2 != 0, i & 4 != 0)) {
+        let mut engine = engine_with_extensions(fen, singular, check, multicut);
+        let search_info = engine.go_quiet(&SearchConfig::new_depth(4));
+        assert_eq!(
+            search_info.get_best_move(),
+            Some(Move::from_san(engine.get_board(), "Qxf7#").unwrap()),
+            "Failed to find mate with singular extensions {singular}, check extensions {check} and multicut {multicut}",
+        );
+        assert!(is_checkmate(search_info.get_score().unwrap()));
+    }
+}
+
+#[test]
+fn test_search_extensions_at_singular_depth() {
+    let fen = "r1bq1rk1/ppp2ppp/2np1n2/2b1p3/2B1P3/2NP1N2/PPP2PPP/R1BQ1RK1 w - - 0 7";
+    for enabled in [false, true] {
+        let mut engine = engine_with_extensions(fen, enabled, enabled, enabled);
+        let search_info =
+            engine.go_quiet(&SearchConfig::new_depth(SINGULAR_EXTENSION_MIN_DEPTH + 1));
+        let best_move = search_info.get_best_move().unwrap();
+        assert!(engine.get_board().is_legal(&best_move));
+    }
+    // The verification searches and the extended hash moves change the tree, which they only do when they trigger.
+    let [num_nodes_without, num_nodes_with] = [false, true].map(|singular| {
+        engine_with_extensions(fen, singular, false, false)
+            .go_quiet(&SearchConfig::new_depth(SINGULAR_EXTENSION_MIN_DEPTH + 1))
+            .get_num_nodes_searched()
+            .unwrap()
+    });
+    assert_ne!(num_nodes_without, num_nodes_with);
+}
+
+#[test]
+fn test_quiescence_checks_see_back_rank_mate() {
+    let search = |using_quiescence_checks| {
+        let mut engine = Engine::from_fen("3r2k1/1p3ppp/8/8/8/1Q6/5PPP/6K1 w - - 0 1").unwrap();
+        engine
+            .get_properties_mut()
+            .set_using_quiescence_checks(using_quiescence_checks);
+        let search_info = engine.go_quiet(&SearchConfig::new_depth(1));
+        let greedy_move = Move::from_san(engine.get_board(), "Qxb7").unwrap();
+        (
+            search_info.get_best_move() == Some(greedy_move),
+            search_info.get_score(),
+            search_info.get_num_nodes_searched().unwrap(),
+        )
+    };
+    let (plays_greedy_move, score, num_nodes) = search(true);
+    let (_, score_without_checks, num_nodes_without_checks) = search(false);
+    assert!(!plays_greedy_move);
+    // Without the checks, the capture looks safe and scores higher.
+    assert!(score < score_without_checks);
+    // The checks are searched on top of the captures.
+    assert!(num_nodes > num_nodes_without_checks);
+}
+
+#[test]
+fn test_search_threat() {
+    let mut engine =
+        Engine::from_fen("r1b1k1nr/pppp1ppp/2n5/2b1p3/4P2q/2N5/PPPP1PPP/R1BQKBNR w KQkq - 0 4")
+            .unwrap();
+    let fen = engine.get_board().get_fen();
+    let threat = engine.search_threat(&SearchConfig::new_depth(3)).unwrap();
+    assert_eq!(threat.get_move(), Move::from_uci("h4f2").unwrap());
+    assert!(threat.is_mate_threat());
+    assert!(threat.get_score_delta() > 0);
+    assert_eq!(threat.to_string(), "Black threatens Qxf2#");
+    assert_eq!(engine.get_board().get_fen(), fen);
+
+    // Passing is not possible in check
+    let mut engine = Engine::from_fen("4k3/8/8/8/8/8/8/r3K3 w - - 0 1").unwrap();
+    assert!(matches!(
+        engine.search_threat(&SearchConfig::new_depth(2)),
+        Err(TimecatError::NullMoveInCheck { .. })
+    ));
+}
+
+#[test]
+fn test_eval_file_inbuilt_value() {
+    let mut timecat = TimecatBuilder::<Engine>::default()
+        .set_output_sink(Arc::new(BufferSink::new()))
+        .build();
+    for user_input in [
+        "setoption name EvalFile value Inbuilt",
+        "setoption name EvalFile value",
+    ] {
+        let result = timecat.run_uci_command(user_input);
+        #[cfg(feature = "inbuilt_nnue")]
+        result.unwrap();
+        #[cfg(not(feature = "inbuilt_nnue"))]
+        assert!(matches!(
+            result,
+            Err(TimecatError::FeatureNotEnabled { .. })
+        ));
+    }
+}
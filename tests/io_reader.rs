@@ -0,0 +1,88 @@
+use std::io::Cursor;
+use timecat::*;
+
+fn get_uci_config() -> TimecatConfig {
+    let mut config = TimecatConfig::default();
+    config.set_uci_mode(true);
+    #[cfg(feature = "colored")]
+    config.set_colored_output(false);
+    config
+}
+
+fn run_timecat(io_reader: IoReader) -> Vec<String> {
+    let buffer = Arc::new(BufferSink::new());
+    TimecatBuilder::<Engine>::default()
+        .set_config(get_uci_config())
+        .set_output_sink(buffer.clone())
+        .set_io_reader(io_reader)
+        .build()
+        .run();
+    buffer.get_lines()
+}
+
+#[test]
+fn test_commands_are_read_from_queue() {
+    let io_reader = IoReader::from_commands(["isready", "quit"]);
+    let lines = run_timecat(io_reader);
+    assert!(lines.iter().any(|line| line == "readyok"));
+}
+
+#[test]
+fn test_commands_are_read_from_any_buf_read() {
+    let io_reader = IoReader::from_reader_quitting_at_end(Cursor::new("isready\ngo depth 2\n"));
+    let lines = run_timecat(io_reader);
+    assert!(lines.iter().any(|line| line == "readyok"));
+    assert!(lines.iter().any(|line| line.starts_with("bestmove ")));
+}
+
+#[test]
+fn test_end_of_input_quits_only_when_asked() {
+    let io_reader = IoReader::from_reader(Cursor::new("isready\n"));
+    io_reader.start_reader_in_parallel().join().unwrap();
+    assert_eq!(io_reader.try_read_line().as_deref(), Some("isready\n"));
+    assert_eq!(io_reader.try_read_line(), None);
+    // The source is read only once.
+    io_reader.start_reader_in_parallel().join().unwrap();
+    assert_eq!(io_reader.try_read_line(), None);
+    let io_reader = IoReader::from_reader_quitting_at_end(Cursor::new("isready\n"));
+    io_reader.start_reader_in_parallel().join().unwrap();
+    assert_eq!(io_reader.try_read_line().as_deref(), Some("isready\n"));
+    assert_eq!(io_reader.try_read_line().as_deref(), Some("quit"));
+}
+
+#[test]
+fn test_stop_interrupts_infinite_search() {
+    let io_reader = IoReader::from_reader(Cursor::new("go infinite\nstop\nisready\nquit\n"));
+    let lines = run_timecat(io_reader);
+    let bestmove_index = lines
+        .iter()
+        .position(|line| line.starts_with("bestmove "))
+        .unwrap();
+    let readyok_index = lines.iter().position(|line| line == "readyok").unwrap();
+    assert!(bestmove_index < readyok_index);
+}
+
+#[test]
+fn test_stop_is_delivered_while_searching() {
+    let io_reader = IoReader::new_queue();
+    let stopper = {
+        let io_reader = io_reader.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(200));
+            io_reader.push_command("stop");
+            io_reader.push_command("quit");
+        })
+    };
+    io_reader.push_command("go infinite");
+    let lines = run_timecat(io_reader);
+    stopper.join().unwrap();
+    assert!(lines.last().unwrap().starts_with("bestmove "));
+}
+
+#[test]
+fn test_quit_terminates_search() {
+    let io_reader = IoReader::from_commands(["go infinite", "quit", "isready"]);
+    let lines = run_timecat(io_reader);
+    assert!(lines.iter().any(|line| line.starts_with("bestmove ")));
+    assert!(!lines.iter().any(|line| line == "readyok"));
+}
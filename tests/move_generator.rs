@@ -246,3 +246,177 @@ fn test_masked_move_generator() {
         expected.into_iter().collect()
     );
 }
+
+const PSEUDO_LEGAL_TEST_FENS: [&str; 6] = [
+    STARTING_POSITION_FEN,
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    "8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1",
+];
+
+#[test]
+fn test_pseudo_legal_perft_cross_check() {
+    for (fen, depth, expected_result) in [
+        (PSEUDO_LEGAL_TEST_FENS[0], 4, 197281),
+        (PSEUDO_LEGAL_TEST_FENS[1], 3, 97862),
+        (PSEUDO_LEGAL_TEST_FENS[2], 4, 43238),
+        (PSEUDO_LEGAL_TEST_FENS[3], 3, 9467),
+        (PSEUDO_LEGAL_TEST_FENS[4], 3, 62379),
+    ] {
+        let position = BoardPosition::from_str(fen).unwrap();
+        assert_eq!(
+            MoveGenerator::perft_cross_check(&position, depth).unwrap(),
+            expected_result,
+            "Cross check failed in position {fen}"
+        );
+        assert_eq!(
+            MoveGenerator::perft_test_pseudo_legal(&position, depth),
+            expected_result,
+            "Pseudo-legal perft failed in position {fen}"
+        );
+    }
+}
+
+#[test]
+fn test_is_legal_matches_move_generator() {
+    let promotions = [
+        None,
+        Some(Knight),
+        Some(Bishop),
+        Some(Rook),
+        Some(Queen),
+        Some(Pawn),
+        Some(King),
+    ];
+    for fen in PSEUDO_LEGAL_TEST_FENS {
+        let mut position = BoardPosition::from_str(fen).unwrap();
+        // The positions a few plies deep cover checks, pins and en passant more thoroughly.
+        for move_index in 0..4 {
+            let legal_moves: HashSet<Move> = position.generate_legal_moves().collect();
+            let pseudo_legal_moves: HashSet<Move> =
+                position.generate_pseudo_legal_moves().collect();
+            assert!(legal_moves.is_subset(&pseudo_legal_moves));
+            for source in ALL_SQUARES {
+                for dest in ALL_SQUARES {
+                    for promotion in promotions {
+                        let Ok(move_) = Move::new(source, dest, promotion) else {
+                            continue;
+                        };
+                        assert_eq!(
+                            position.is_pseudo_legal(&move_),
+                            pseudo_legal_moves.contains(&move_),
+                            "is_pseudo_legal failed for {move_} in position {}",
+                            position.get_fen()
+                        );
+                        assert_eq!(
+                            position.is_legal(&move_),
+                            legal_moves.contains(&move_),
+                            "is_legal failed for {move_} in position {}",
+                            position.get_fen()
+                        );
+                    }
+                }
+            }
+            let Some(move_) = position
+                .generate_legal_moves()
+                .nth(move_index * 7 % legal_moves.len().max(1))
+            else {
+                break;
+            };
+            position = position.make_move_new(move_);
+        }
+    }
+}
+
+#[test]
+fn test_quiet_check_promotion_and_evasion_generators() {
+    let fens = [
+        PSEUDO_LEGAL_TEST_FENS[1],
+        PSEUDO_LEGAL_TEST_FENS[3],
+        PSEUDO_LEGAL_TEST_FENS[4],
+        // Discovered checks by a pawn, a knight and the king, and castling with a rook check.
+        "4k3/8/8/4N3/8/4R3/3P4/B3K2R w K - 0 1",
+        "5k2/8/8/8/8/8/8/4K2R w K - 0 1",
+        "r3k3/1P6/8/8/8/8/8/R3K2R b KQq - 0 1",
+        "4k3/8/8/8/1b6/8/3P4/4K3 w - - 0 1",
+        "4k3/8/8/8/1b6/8/8/4K1N1 w - - 0 1",
+    ];
+    for fen in fens {
+        let position = BoardPosition::from_str(fen).unwrap();
+        let legal_moves = position.generate_legal_moves().collect_vec();
+        let expected_quiet_checks: HashSet<Move> = legal_moves
+            .iter()
+            .copied()
+            .filter(|&move_| {
+                !position.is_capture(move_)
+                    && move_.get_promotion().is_none()
+                    && position.gives_check(move_)
+            })
+            .collect();
+        let expected_quiet_promotions: HashSet<Move> = legal_moves
+            .iter()
+            .copied()
+            .filter(|&move_| !position.is_capture(move_) && move_.get_promotion().is_some())
+            .collect();
+        let expected_evasions: HashSet<Move> = if position.is_check() {
+            legal_moves.iter().copied().collect()
+        } else {
+            HashSet::new()
+        };
+        assert_eq!(
+            position
+                .generate_legal_quiet_checks()
+                .collect::<HashSet<_>>(),
+            expected_quiet_checks,
+            "Quiet checks failed in position {fen}"
+        );
+        assert_eq!(
+            position
+                .generate_legal_quiet_promotions()
+                .collect::<HashSet<_>>(),
+            expected_quiet_promotions,
+            "Quiet promotions failed in position {fen}"
+        );
+        assert_eq!(
+            position.generate_legal_evasions().collect::<HashSet<_>>(),
+            expected_evasions,
+            "Evasions failed in position {fen}"
+        );
+    }
+}
+
+fn get_variant_position(variant: Variant, fen: &str) -> BoardPosition {
+    let mut position_builder = BoardPositionBuilder::from_str(fen).unwrap();
+    position_builder.variant(variant);
+    position_builder.try_into().unwrap()
+}
+
+#[test]
+fn test_variant_perft() {
+    for (variant, fen, depth, expected_result) in [
+        (Variant::Atomic, STARTING_POSITION_FEN, 4, 197326),
+        (Variant::Antichess, ANTICHESS_STARTING_FEN, 4, 153299),
+        (Variant::Antichess, "8/1p6/8/8/8/8/P7/8 w - - 0 1", 3, 4),
+        (Variant::Horde, HORDE_STARTING_FEN, 5, 265223),
+        (
+            Variant::Horde,
+            "4k3/8/8/8/8/8/8/PPPPPPPP w - - 0 1",
+            3,
+            1240,
+        ),
+    ] {
+        let position = get_variant_position(variant, fen);
+        assert_eq!(
+            MoveGenerator::perft_test(&position, depth),
+            expected_result,
+            "{variant} perft failed in position {fen}"
+        );
+        assert_eq!(
+            MoveGenerator::perft_cross_check(&position, depth.min(3)).unwrap(),
+            MoveGenerator::perft_test(&position, depth.min(3)),
+            "{variant} cross check failed in position {fen}"
+        );
+    }
+}
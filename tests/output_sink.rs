@@ -0,0 +1,72 @@
+use timecat::*;
+
+fn get_uci_config() -> TimecatConfig {
+    let mut config = TimecatConfig::default();
+    config.set_uci_mode(true);
+    #[cfg(feature = "colored")]
+    config.set_colored_output(false);
+    config
+}
+
+fn get_timecat_with_buffer() -> (Timecat<Engine>, Arc<BufferSink>) {
+    let buffer = Arc::new(BufferSink::new());
+    let timecat = TimecatBuilder::<Engine>::default()
+        .set_config(get_uci_config())
+        .set_output_sink(buffer.clone())
+        .build();
+    (timecat, buffer)
+}
+
+#[test]
+fn test_uci_handshake_is_captured() {
+    let (mut timecat, buffer) = get_timecat_with_buffer();
+    timecat.run_uci_command("uci").unwrap();
+    let lines = buffer.get_lines();
+    assert_eq!(
+        lines.first().unwrap(),
+        &format!("id name {}", get_engine_version())
+    );
+    assert_eq!(lines.last().unwrap(), "uciok");
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("option name Hash")));
+    buffer.clear();
+    timecat.run_uci_command("isready").unwrap();
+    assert_eq!(buffer.take_output(), "readyok\n");
+    assert!(buffer.get_output().is_empty());
+}
+
+#[test]
+fn test_search_output_is_captured() {
+    let (mut timecat, buffer) = get_timecat_with_buffer();
+    timecat.run_uci_command("go depth 3").unwrap();
+    let lines = buffer.get_lines();
+    assert!(lines.iter().any(|line| line.starts_with("info depth 3")));
+    assert!(lines.last().unwrap().starts_with("bestmove "));
+}
+
+#[test]
+fn test_instances_have_separate_sinks() {
+    let (mut first_timecat, first_buffer) = get_timecat_with_buffer();
+    let (mut second_timecat, second_buffer) = get_timecat_with_buffer();
+    first_timecat.run_uci_command("isready").unwrap();
+    assert_eq!(first_buffer.get_output(), "readyok\n");
+    assert!(second_buffer.get_output().is_empty());
+    second_timecat.run_uci_command("isready").unwrap();
+    assert_eq!(first_buffer.get_lines().len(), 1);
+    assert_eq!(second_buffer.get_output(), "readyok\n");
+}
+
+#[test]
+fn test_writer_sink_and_scoping() {
+    let writer_sink = Arc::new(WriterSink::new(Vec::new()));
+    let buffer = Arc::new(BufferSink::new());
+    with_output_sink(writer_sink.clone(), || {
+        println_wasm!("first");
+        with_output_sink(buffer.clone(), || println_wasm!("second"));
+        print_wasm!("third");
+    });
+    assert_eq!(buffer.get_output(), "second\n");
+    let writer_sink = Arc::try_unwrap(writer_sink).ok().unwrap();
+    assert_eq!(writer_sink.into_inner(), b"first\nthird");
+}
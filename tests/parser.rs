@@ -0,0 +1,105 @@
+use timecat::*;
+
+#[test]
+fn test_help_lists_every_command() {
+    let help = UserCommand::generate_help_message(None);
+    for command_info in COMMAND_REGISTRY {
+        assert!(help.contains(command_info.get_name()));
+    }
+    assert_eq!(
+        Parser::parse_command("help").unwrap(),
+        vec![UserCommand::Help(None)]
+    );
+}
+
+#[test]
+fn test_help_for_single_command() {
+    assert_eq!(
+        Parser::parse_command("help d").unwrap(),
+        vec![UserCommand::Help(Some("display".to_string()))]
+    );
+    let details = UserCommand::generate_help_message(Some("go"));
+    assert!(details.starts_with("Usage: go [depth <DEPTH>]"));
+    assert!(details.contains("go perft <DEPTH>"));
+    assert!(details.contains("movetime <MS>"));
+    assert!(matches!(
+        Parser::parse_command("help gi"),
+        Err(TimecatError::UnknownHelpCommand { .. })
+    ));
+    assert!(Parser::parse_command("help gi")
+        .unwrap_err()
+        .to_string()
+        .contains("did you mean \"go\"?"));
+}
+
+#[test]
+fn test_unknown_command_hints() {
+    let error = Parser::parse_command("setoptoin name Hash value 16").unwrap_err();
+    let message = error.stringify_with_optional_raw_input(Some("setoptoin name Hash value 16"));
+    assert!(message.contains("Did you mean \"setoption\"?"));
+    let error = Parser::parse_command("pop 1 2").unwrap_err();
+    let message = error.stringify_with_optional_raw_input(Some("pop 1 2"));
+    assert!(message.contains("Usage: pop [<COUNT>]"));
+    let message = TimecatError::UnknownCommand.stringify_with_optional_raw_input(Some("xyzzy"));
+    assert!(!message.contains("Did you mean"));
+    assert_eq!(
+        CommandInfo::suggest("ucinewgmae").unwrap().get_name(),
+        "ucinewgame"
+    );
+}
+
+#[test]
+fn test_command_completions() {
+    assert_eq!(
+        Parser::get_completions("se"),
+        vec!["selfplay", "set", "setoption"]
+    );
+    assert_eq!(
+        Parser::get_completions("save"),
+        vec!["save_hash", "save_options", "savehash", "saveoptions"]
+    );
+    assert!(Parser::get_completions("go depth").is_empty());
+    assert_eq!(Parser::get_completions("").len(), {
+        COMMAND_REGISTRY
+            .iter()
+            .map(|command_info| command_info.get_aliases().len() + 1)
+            .sum::<usize>()
+    });
+}
+
+#[test]
+fn test_parse_perft_cross_check() {
+    assert_eq!(
+        Parser::parse_command("perft 3 check").unwrap(),
+        vec![UserCommand::PerftCrossCheck(3)]
+    );
+    assert_eq!(
+        Parser::parse_command("go perft 2").unwrap(),
+        vec![UserCommand::Perft(2)]
+    );
+    assert!(Parser::parse_command("perft 3 fast").is_err());
+}
+
+#[test]
+fn test_path_arguments_are_not_sanitized() {
+    assert_eq!(
+        Parser::parse_command(r"save_hash C:\hashes\game 1, part 2.bin").unwrap(),
+        vec![UserCommand::SaveHash(
+            r"C:\hashes\game 1, part 2.bin".to_string()
+        )]
+    );
+    assert_eq!(
+        Parser::parse_command(r"loadhash C:\hash.bin && isready").unwrap(),
+        vec![
+            UserCommand::LoadHash(r"C:\hash.bin".to_string()),
+            UserCommand::IsReady
+        ]
+    );
+    assert_eq!(
+        Parser::parse_command(r"saveoptions profile analysis D:\timecat,config.toml").unwrap(),
+        vec![UserCommand::SaveOptions {
+            optional_profile: Some("analysis".to_string()),
+            optional_path: Some(r"D:\timecat,config.toml".to_string()),
+        }]
+    );
+}
@@ -0,0 +1,119 @@
+use timecat::*;
+
+fn get_engine(fen: &str, commands: &[&str]) -> (Engine, Arc<BufferSink>) {
+    let buffer = Arc::new(BufferSink::new());
+    let mut engine = Engine::from_fen(fen)
+        .unwrap()
+        .with_io_reader(IoReader::from_commands(commands.iter().copied()))
+        .with_output_sink(buffer.clone());
+    engine.get_config_mut().set_console_mode(true);
+    #[cfg(feature = "colored")]
+    engine.get_config_mut().set_colored_output(false);
+    (engine, buffer)
+}
+
+fn get_pgn_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "timecat_play_test_{name}_{}.pgn",
+        std::process::id()
+    ))
+}
+
+#[test]
+fn test_play_until_checkmate() {
+    let (mut engine, buffer) = get_engine(
+        "6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1",
+        &["undo", "Ra9", "draw", "a1a8"],
+    );
+    let pgn_path = get_pgn_path("checkmate");
+    play(&mut engine, White, None, Some(&pgn_path)).unwrap();
+    let pgn = fs::read_to_string(&pgn_path).unwrap();
+    fs::remove_file(&pgn_path).unwrap();
+    assert!(pgn.contains("[Result \"1-0\"]"));
+    assert!(pgn.contains("[Termination \"checkmate\"]"));
+    assert!(pgn.contains("[FEN \"6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1\"]"));
+    assert!(pgn.trim_end().ends_with("1. Ra8# 1-0"));
+    let output = buffer.get_output();
+    assert!(output.contains("There is no move of yours to take back!"));
+    assert!(output.contains("A draw can only be claimed"));
+}
+
+#[test]
+fn test_play_with_clock_undo_and_resign() {
+    let (mut engine, _) = get_engine(STARTING_POSITION_FEN, &["e4", "undo", "resign"]);
+    let pgn_path = get_pgn_path("resign");
+    let time_control: TimeControl = "10+0".parse().unwrap();
+    play(&mut engine, White, Some(&time_control), Some(&pgn_path)).unwrap();
+    let pgn = fs::read_to_string(&pgn_path).unwrap();
+    fs::remove_file(&pgn_path).unwrap();
+    assert!(engine.get_board().has_empty_stack());
+    assert!(pgn.contains("[Result \"0-1\"]"));
+    assert!(pgn.contains("[Termination \"White resigned\"]"));
+    assert!(pgn.contains(&format!("[TimeControl \"{time_control}\"]")));
+}
+
+#[test]
+fn test_play_undo_restores_the_clocks() {
+    let (mut engine, buffer) = get_engine(STARTING_POSITION_FEN, &["e4", "undo", "abort"]);
+    let time_control = "10+0".parse().unwrap();
+    play(&mut engine, White, Some(&time_control), None).unwrap();
+    let output = buffer.get_output();
+    let clock_lines = output
+        .lines()
+        .filter(|line| line.contains("Clock"))
+        .collect_vec();
+    let full_time = Duration::from_secs(10).stringify();
+    assert!(clock_lines[clock_lines.len() - 2..]
+        .iter()
+        .all(|line| line.ends_with(&full_time)));
+    assert!(!output.contains("Game saved to"));
+}
+
+#[test]
+fn test_play_as_black_and_abort() {
+    let (mut engine, _) = get_engine(STARTING_POSITION_FEN, &["abort"]);
+    let pgn_path = get_pgn_path("abort");
+    let time_control = "10+0".parse().unwrap();
+    play(&mut engine, Black, Some(&time_control), Some(&pgn_path)).unwrap();
+    let pgn = fs::read_to_string(&pgn_path).unwrap();
+    fs::remove_file(&pgn_path).unwrap();
+    assert_eq!(engine.get_board().get_ply(), 1);
+    assert!(pgn.contains("[Result \"*\"]"));
+    assert!(pgn.contains(&format!("[White \"{}\"]", get_engine_version())));
+    assert!(pgn.trim_end().ends_with('*'));
+}
+
+#[test]
+fn test_parse_play_command() {
+    assert_eq!(
+        Parser::parse_command("play black 5+3").unwrap(),
+        vec![UserCommand::Play {
+            human_color: Black,
+            optional_time_control: Some("5+3".parse().unwrap()),
+            optional_pgn_path: None,
+        }]
+    );
+    assert_eq!(
+        Parser::parse_command("play").unwrap(),
+        vec![UserCommand::Play {
+            human_color: White,
+            optional_time_control: None,
+            optional_pgn_path: None,
+        }]
+    );
+    assert_eq!(
+        Parser::parse_command("play black 60+1 save Games/Game 1.pgn").unwrap(),
+        vec![UserCommand::Play {
+            human_color: Black,
+            optional_time_control: Some("60+1".parse().unwrap()),
+            optional_pgn_path: Some("Games/Game 1.pgn".to_string()),
+        }]
+    );
+    match Parser::parse_command("play save").unwrap().as_slice() {
+        [UserCommand::Play {
+            optional_pgn_path: Some(pgn_path),
+            ..
+        }] => assert!(pgn_path.starts_with(PLAY_PGN_FILE_PREFIX)),
+        user_commands => panic!("Unexpected commands {user_commands:?}"),
+    }
+}
@@ -3,7 +3,6 @@ use timecat::*;
 #[rustfmt::skip]
 #[test]
 fn position_is_sane() {
-    // TODO: Add some fens to test wrong fens
     let mut board = Board::default();
     let fens = [
         (STARTING_POSITION_FEN, true),
@@ -25,6 +24,13 @@ fn position_is_sane() {
         ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQq - 0 1", true),
         ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w Kkq - 0 1", true),
         ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w Qkq - 0 1", true),
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1", false),
+        ("rnbqkbnr/ppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", false),
+        ("8/8/8/8/8/8/8/4K3 w - - 0 1", false),
+        ("4k3/8/8/8/8/8/8/4R1K1 w - - 0 1", false),
+        ("8/8/8/8/8/8/3k4/4K3 w - - 0 1", false),
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w KQkq - 0 1", false),
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1", false),
     ];
 
     for (fen, expected_value) in fens {
@@ -35,3 +41,170 @@ fn position_is_sane() {
         );
     }
 }
+
+#[rustfmt::skip]
+#[test]
+fn position_issues() {
+    let cases = [
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1", vec![PositionIssue::WrongRankCount { num_ranks: 7 }]),
+        ("rnbqkbnr/ppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", vec![PositionIssue::WrongRankLength { rank: Rank::Seventh }]),
+        ("8/8/8/8/8/8/8/4K3 w - - 0 1", vec![PositionIssue::MissingKing { color: Black }]),
+        ("4k3/8/8/8/8/8/8/3KK3 w - - 0 1", vec![PositionIssue::TooManyKings { color: White }]),
+        ("4k3/8/8/8/8/PPPPPPPP/P7/4K3 w - - 0 1", vec![PositionIssue::TooManyPawns { color: White }]),
+        ("4k3/8/8/8/8/NNNNNNNN/NNNNNNNN/K7 w - - 0 1", vec![PositionIssue::TooManyPieces { color: White }]),
+        ("P3k3/8/8/8/8/8/8/4K3 w - - 0 1", vec![PositionIssue::PawnsOnBackRank]),
+        ("4k3/8/8/8/8/8/8/4R1K1 w - - 0 1", vec![PositionIssue::OpponentInCheck]),
+        ("8/8/8/8/8/8/3k4/4K3 w - - 0 1", vec![PositionIssue::KingsAdjacent]),
+        ("4k3/8/3N4/1B6/8/8/8/4R1K1 b - - 0 1", vec![PositionIssue::TooManyCheckers]),
+        ("4k3/8/3N1N2/8/8/8/8/6K1 b - - 0 1", vec![PositionIssue::ImpossibleCheck]),
+        ("8/8/8/R3k3/8/8/8/4R1K1 b - - 0 1", vec![PositionIssue::ImpossibleCheck]),
+        ("1B6/8/8/4k3/8/8/1B6/6K1 b - - 0 1", vec![PositionIssue::ImpossibleCheck]),
+        ("4Q3/8/8/4k3/8/8/8/4R1K1 b - - 0 1", vec![PositionIssue::ImpossibleCheck]),
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w KQkq - 0 1", vec![PositionIssue::InvalidCastlingRights { color: White }]),
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1", vec![PositionIssue::InvalidEnPassant]),
+        (
+            "rnbqkbn1/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w KQkq e3 0 1",
+            vec![
+                PositionIssue::InvalidCastlingRights { color: White },
+                PositionIssue::InvalidCastlingRights { color: Black },
+                PositionIssue::InvalidEnPassant,
+            ],
+        ),
+    ];
+
+    for (fen, expected_issues) in cases {
+        match BoardPosition::from_fen(fen) {
+            Err(TimecatError::InvalidPosition { issues, .. }) => {
+                assert_eq!(issues, expected_issues, "Unexpected issues for position {fen}")
+            }
+            result => panic!("Expected the issues {expected_issues:?} for position {fen}, got {result:?}"),
+        }
+    }
+
+    // double checks a single move can give
+    for fen in ["R3k3/8/8/8/8/8/8/4R1K1 b - - 0 1", "4k3/8/2B5/8/4R3/8/8/6K1 b - - 0 1"] {
+        assert!(BoardPosition::from_fen(fen).is_ok(), "Expected position {fen} to be valid");
+    }
+}
+
+#[test]
+fn lenient_fen() {
+    let position = BoardPosition::from_fen_lenient(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w KQkq e6 0 1",
+    )
+    .unwrap();
+    assert_eq!(
+        position.get_fen(),
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w Qkq - 0 1"
+    );
+    assert!(position.validate().is_empty());
+
+    let board = Board::from_fen_lenient("r3k3/8/8/8/8/8/8/4K2R w KQkq - 0 1").unwrap();
+    assert_eq!(board.get_fen(), "r3k3/8/8/8/8/8/8/4K2R w Kq - 0 1");
+
+    // The issues with the pieces cannot be repaired
+    assert!(matches!(
+        BoardPosition::from_fen_lenient("8/8/8/8/8/8/8/4K3 w - - 0 1"),
+        Err(TimecatError::InvalidPosition { .. })
+    ));
+}
+
+#[test]
+fn symmetry_squares() {
+    for symmetry in ALL_SYMMETRIES {
+        for square in ALL_SQUARES {
+            let transformed_square = symmetry.transform_square(square);
+            assert_eq!(
+                symmetry.transform_bitboard(square.to_bitboard()),
+                transformed_square.to_bitboard(),
+                "Bitboard and square of {symmetry:?} disagree on {square}"
+            );
+            assert_eq!(
+                symmetry.inverse().transform_square(transformed_square),
+                square
+            );
+        }
+    }
+    assert_eq!(Symmetry::Rotate90.transform_square(Square::A1), Square::A8);
+    assert_eq!(Symmetry::Rotate270.transform_square(Square::A1), Square::H1);
+    assert_eq!(
+        Symmetry::FlipAntiDiagonal.transform_square(Square::A1),
+        Square::H8
+    );
+}
+
+#[test]
+fn position_symmetries() {
+    let get_valid_symmetries = |fen| {
+        BoardPosition::from_fen(fen)
+            .unwrap()
+            .get_valid_symmetries()
+            .collect_vec()
+    };
+    assert_eq!(
+        get_valid_symmetries(STARTING_POSITION_FEN),
+        [Symmetry::Identity]
+    );
+    assert_eq!(
+        get_valid_symmetries("8/8/4k3/8/8/8/P7/K7 w - - 0 1"),
+        [Symmetry::Identity, Symmetry::FlipHorizontal]
+    );
+    assert_eq!(
+        get_valid_symmetries("8/8/8/4k3/8/1Q6/8/K7 w - - 0 1"),
+        ALL_SYMMETRIES
+    );
+
+    let position = BoardPosition::from_fen("8/8/8/4k3/8/1Q6/8/K7 w - - 0 1").unwrap();
+    assert_eq!(
+        position.transform(Symmetry::Rotate90).unwrap().get_fen(),
+        "K7/2Q5/8/8/4k3/8/8/8 w - - 0 1"
+    );
+    let position =
+        BoardPosition::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3")
+            .unwrap();
+    assert_eq!(position.transform(Symmetry::FlipHorizontal), None);
+    let mirror = position.mirror().unwrap();
+    assert_eq!(
+        mirror.get_fen(),
+        "rnbqkbnr/pppp1ppp/8/8/3PpP2/8/PPP1P1PP/RNBQKBNR b KQkq f3 0 3"
+    );
+    assert_eq!(mirror.mirror().unwrap(), position);
+}
+
+#[test]
+fn canonical_form() {
+    for fen in [
+        "8/8/8/4k3/8/1Q6/8/K7 w - - 0 1",
+        "8/8/4k3/8/8/8/P7/K7 w - - 0 1",
+        "r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1",
+    ] {
+        let position = BoardPosition::from_fen(fen).unwrap();
+        let canonical_form = position.get_canonical_form(false);
+        let canonical_form_with_mirror = position.get_canonical_form(true);
+        for symmetry in position.get_valid_symmetries() {
+            let transformed_position = position.transform(symmetry).unwrap();
+            assert_eq!(
+                transformed_position.get_canonical_form(false),
+                canonical_form
+            );
+            assert_eq!(
+                transformed_position.get_canonical_form(true),
+                canonical_form_with_mirror
+            );
+            assert_eq!(
+                transformed_position
+                    .mirror()
+                    .unwrap()
+                    .get_canonical_form(true),
+                canonical_form_with_mirror
+            );
+        }
+    }
+    // The canonical form is one of the transformed positions
+    let position = BoardPosition::from_fen("8/8/4k3/8/8/8/P7/K7 w - - 0 1").unwrap();
+    assert!([Symmetry::Identity, Symmetry::FlipHorizontal]
+        .into_iter()
+        .any(
+            |symmetry| position.transform(symmetry).unwrap() == position.get_canonical_form(false)
+        ));
+}
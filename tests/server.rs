@@ -0,0 +1,178 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use timecat::*;
+
+fn start_server(limits: ServerLimits) -> Arc<TimecatServer> {
+    let address = "127.0.0.1:0".parse().unwrap();
+    let server = Arc::new(TimecatServer::bind(&address, limits).unwrap());
+    let server_clone = server.clone();
+    std::thread::spawn(move || {
+        let _ = server_clone.run();
+    });
+    server
+}
+
+fn connect(server: &TimecatServer) -> (TcpStream, BufReader<TcpStream>) {
+    let stream = TcpStream::connect(server.get_local_tcp_address().unwrap()).unwrap();
+    let reader = BufReader::new(stream.try_clone().unwrap());
+    (stream, reader)
+}
+
+fn read_until(reader: &mut impl BufRead, predicate: impl Fn(&str) -> bool) -> Vec<String> {
+    let mut lines = vec![];
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap() == 0 {
+            return lines;
+        }
+        let line = line.trim().to_string();
+        let is_last = predicate(&line);
+        lines.push(line);
+        if is_last {
+            return lines;
+        }
+    }
+}
+
+fn wait_for_num_active_sessions(server: &TimecatServer, num_sessions: usize) {
+    let start = Instant::now();
+    while server.get_num_active_sessions() != num_sessions {
+        assert!(start.elapsed() < Duration::from_secs(10));
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn get_limits(max_sessions: usize, max_threads: usize, max_hash_size_in_mb: usize) -> ServerLimits {
+    let mut limits = ServerLimits::default();
+    limits.set_max_sessions(max_sessions.try_into().unwrap());
+    limits.set_max_threads(max_threads.try_into().unwrap());
+    limits.set_max_hash_size_in_mb(max_hash_size_in_mb.try_into().unwrap());
+    limits
+}
+
+#[test]
+fn test_sessions_are_independent() {
+    let server = start_server(get_limits(2, 2, 32));
+    let (mut first_stream, mut first_reader) = connect(&server);
+    let (mut second_stream, mut second_reader) = connect(&server);
+    writeln!(first_stream, "position startpos moves e2e4\ngo depth 3").unwrap();
+    writeln!(second_stream, "isready").unwrap();
+    let second_lines = read_until(&mut second_reader, |line| line == "readyok");
+    assert!(!second_lines.iter().any(|line| line.starts_with("bestmove")));
+    let first_lines = read_until(&mut first_reader, |line| line.starts_with("bestmove"));
+    assert!(first_lines
+        .iter()
+        .any(|line| line.starts_with("info depth 3")));
+    assert_eq!(server.get_num_active_sessions(), 2);
+}
+
+#[test]
+fn test_partitioned_limits_are_advertised() {
+    let server = start_server(get_limits(2, 8, 64));
+    let (mut stream, mut reader) = connect(&server);
+    writeln!(stream, "uci").unwrap();
+    let lines = read_until(&mut reader, |line| line == "uciok");
+    let threads_option = lines
+        .iter()
+        .find(|line| line.starts_with("option name Threads"))
+        .unwrap();
+    assert!(threads_option.ends_with("max 4"));
+    let hash_option = lines
+        .iter()
+        .find(|line| line.starts_with("option name Hash"))
+        .unwrap();
+    assert!(hash_option.ends_with("max 32"));
+    writeln!(stream, "setoption name Threads value 5\nisready").unwrap();
+    let lines = read_until(&mut reader, |line| line == "readyok");
+    assert!(lines
+        .iter()
+        .any(|line| line.contains("must be from 1 to 4")));
+}
+
+#[test]
+fn test_shared_limits_are_advertised() {
+    let mut limits = get_limits(2, 8, 64);
+    limits.set_resource_sharing(ResourceSharing::Shared);
+    assert_eq!(limits.get_session_max_threads().get(), 8);
+    assert_eq!(limits.get_session_max_hash_size_in_mb().get(), 32);
+    let server = start_server(limits);
+    let (mut stream, mut reader) = connect(&server);
+    writeln!(stream, "uci").unwrap();
+    let lines = read_until(&mut reader, |line| line == "uciok");
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("option name Threads") && line.ends_with("max 8")));
+}
+
+#[test]
+fn test_sessions_are_cleaned_up() {
+    let server = start_server(get_limits(1, 1, 16));
+    let (mut stream, mut reader) = connect(&server);
+    writeln!(stream, "isready").unwrap();
+    read_until(&mut reader, |line| line == "readyok");
+    wait_for_num_active_sessions(&server, 1);
+    let (_, mut rejected_reader) = connect(&server);
+    let lines = read_until(&mut rejected_reader, |_| false);
+    assert!(lines[0].contains("Server is full"));
+    // Disconnecting in the middle of a search ends the session.
+    writeln!(stream, "go infinite").unwrap();
+    drop(stream);
+    drop(reader);
+    wait_for_num_active_sessions(&server, 0);
+    let (mut stream, mut reader) = connect(&server);
+    writeln!(stream, "quit").unwrap();
+    read_until(&mut reader, |_| false);
+    wait_for_num_active_sessions(&server, 0);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_unix_socket_session() {
+    use std::os::unix::net::UnixStream;
+
+    let path = std::env::temp_dir().join(format!("timecat-test-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let address: ServerAddress = format!("unix:{}", path.display()).parse().unwrap();
+    let server = Arc::new(TimecatServer::bind(&address, ServerLimits::default()).unwrap());
+    let server_clone = server.clone();
+    std::thread::spawn(move || {
+        let _ = server_clone.run();
+    });
+    let mut stream = UnixStream::connect(&path).unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    writeln!(stream, "isready").unwrap();
+    let lines = read_until(&mut reader, |line| line == "readyok");
+    assert_eq!(lines.last().unwrap(), "readyok");
+}
+
+#[test]
+fn test_parse_server_args() {
+    let (address, limits) = parse_server_args(&[
+        "timecat",
+        "serve",
+        "127.0.0.1:8080",
+        "--max-sessions",
+        "3",
+        "--max-threads",
+        "12",
+        "--shared-resources",
+    ])
+    .unwrap();
+    assert_eq!(address, ServerAddress::Tcp("127.0.0.1:8080".to_string()));
+    assert_eq!(limits.get_max_sessions().get(), 3);
+    assert_eq!(limits.get_max_threads().get(), 12);
+    assert_eq!(limits.get_resource_sharing(), ResourceSharing::Shared);
+    let (address, limits) = parse_server_args(&[
+        "timecat",
+        "--serve",
+        "127.0.0.1:8080",
+        "--max-sessions",
+        "2",
+    ])
+    .unwrap();
+    assert_eq!(address, ServerAddress::Tcp("127.0.0.1:8080".to_string()));
+    assert_eq!(limits.get_max_sessions().get(), 2);
+    assert!(parse_server_args(&["timecat", "perft", "2", "--serve", "127.0.0.1:8080"]).is_err());
+    assert!(parse_server_args(&["timecat", "serve", "8080"]).is_err());
+    assert!(parse_server_args(&["timecat", "serve", "localhost:1", "--max-hash", "0"]).is_err());
+}
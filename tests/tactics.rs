@@ -0,0 +1,95 @@
+use timecat::*;
+
+fn get_motifs(fen: &str) -> Vec<TacticalMotif> {
+    BoardPosition::from_fen(fen)
+        .unwrap()
+        .get_tactical_motifs(White)
+}
+
+#[test]
+fn test_forks_and_hanging_pieces() {
+    let motifs = get_motifs("2r1k3/8/3N4/8/8/8/8/4K3 b - - 0 1");
+    assert!(motifs.contains(&TacticalMotif::Fork {
+        color: White,
+        attacker: Square::D6,
+        targets: Square::C8.to_bitboard() | Square::E8.to_bitboard(),
+    }));
+    assert!(motifs.contains(&TacticalMotif::HangingPiece {
+        color: White,
+        square: Square::C8,
+    }));
+
+    // A defended piece is hanging only when attacked by a cheaper piece
+    let motifs = get_motifs("k2q4/8/8/8/3n3n/6P1/8/K2R4 w - - 0 1");
+    assert!(motifs.contains(&TacticalMotif::HangingPiece {
+        color: White,
+        square: Square::H4,
+    }));
+    assert!(!motifs.contains(&TacticalMotif::HangingPiece {
+        color: White,
+        square: Square::D4,
+    }));
+}
+
+#[test]
+fn test_line_motifs() {
+    assert!(
+        get_motifs("4k3/4n3/8/8/8/8/8/4RK2 w - - 0 1").contains(&TacticalMotif::Pin {
+            color: White,
+            pinner: Square::E1,
+            pinned: Square::E7,
+            target: Square::E8,
+        })
+    );
+    assert!(
+        get_motifs("7q/8/8/4k3/8/8/1B6/K7 b - - 0 1").contains(&TacticalMotif::Skewer {
+            color: White,
+            attacker: Square::B2,
+            front: Square::E5,
+            behind: Square::H8,
+        })
+    );
+    assert!(get_motifs("4k3/8/8/8/8/8/4N3/K3R3 w - - 0 1").contains(
+        &TacticalMotif::DiscoveredAttack {
+            color: White,
+            attacker: Square::E1,
+            blocker: Square::E2,
+            target: Square::E8,
+        }
+    ));
+    let motifs = get_motifs("4k3/4r3/8/8/8/8/4R3/K3Q3 w - - 0 1");
+    assert!(motifs.contains(&TacticalMotif::XRayAttack {
+        color: White,
+        attacker: Square::E1,
+        through: Square::E2,
+        target: Square::E7,
+    }));
+    assert!(!motifs
+        .iter()
+        .any(|motif| matches!(motif, TacticalMotif::DiscoveredAttack { .. })));
+}
+
+#[test]
+fn test_overloaded_defenders() {
+    let motifs = get_motifs("k2q4/8/8/8/3n3n/6P1/8/K2R4 w - - 0 1");
+    assert!(motifs.contains(&TacticalMotif::OverloadedDefender {
+        color: White,
+        defender: Square::D8,
+        defended: Square::D4.to_bitboard() | Square::H4.to_bitboard(),
+    }));
+    assert!(motifs.iter().all(|motif| motif.get_color() == White));
+}
+
+#[test]
+fn test_motifs_created_by_move() {
+    let position = BoardPosition::from_fen("2r1k3/8/8/8/4N3/8/8/4K3 w - - 0 1").unwrap();
+    let motifs = position.get_tactical_motifs_created_by(Move::from_uci("e4d6").unwrap());
+    assert!(motifs.contains(&TacticalMotif::Fork {
+        color: White,
+        attacker: Square::D6,
+        targets: Square::C8.to_bitboard() | Square::E8.to_bitboard(),
+    }));
+    assert!(position
+        .get_tactical_motifs_created_by(Move::from_uci("e1d1").unwrap())
+        .is_empty());
+}
@@ -0,0 +1,222 @@
+use timecat::*;
+
+#[test]
+fn test_time_control_parsing_round_trip() {
+    for time_control in [
+        "-",
+        "300",
+        "180+2",
+        "40/5400+30:1800+30",
+        "40/7200:20/3600:900",
+        "300d5",
+        "900+10b5",
+        "*180",
+        "st=5",
+        "0.5+0.1",
+    ] {
+        assert_eq!(
+            TimeControl::from_str(time_control).unwrap().to_string(),
+            time_control
+        );
+    }
+}
+
+#[test]
+fn test_time_control_parsing() {
+    assert_eq!(TimeControl::from_str("-").unwrap(), TimeControl::Unlimited);
+    assert_eq!(
+        TimeControl::from_str("*60").unwrap(),
+        TimeControl::Hourglass(Duration::from_secs(60))
+    );
+    assert_eq!(
+        TimeControl::from_str("180+2").unwrap(),
+        TimeControl::new_sudden_death(Duration::from_secs(180), Duration::from_secs(2))
+    );
+    let time_control = TimeControl::from_str("40/5400+30:900D5").unwrap();
+    let periods = time_control.get_periods();
+    assert_eq!(periods.len(), 2);
+    assert_eq!(periods[0].get_moves(), Some(40));
+    assert_eq!(periods[0].get_base_time(), Duration::from_secs(5400));
+    assert_eq!(periods[0].get_increment(), Duration::from_secs(30));
+    assert_eq!(periods[0].get_delay(), None);
+    assert_eq!(periods[1].get_moves(), None);
+    assert_eq!(
+        periods[1].get_delay(),
+        Some(Delay::Simple(Duration::from_secs(5)))
+    );
+    for invalid_time_control in [
+        "",
+        "?",
+        "abc",
+        "40/",
+        "0/300",
+        "300+",
+        "-5",
+        "300:40/300",
+        "*",
+        "st=",
+        "300d",
+    ] {
+        assert!(
+            TimeControl::from_str(invalid_time_control).is_err(),
+            "{invalid_time_control:?} should not be parsed"
+        );
+    }
+}
+
+#[test]
+fn test_fischer_increment_and_flagging() {
+    let mut game_clock = GameClock::new("10+1".parse().unwrap()).unwrap();
+    assert!(game_clock.update(White, Duration::from_secs(3)));
+    assert_eq!(game_clock.get_remaining_time(White), Duration::from_secs(8));
+    assert_eq!(
+        game_clock.get_remaining_time(Black),
+        Duration::from_secs(10)
+    );
+    assert!(!game_clock.update(Black, Duration::from_millis(10_001)));
+    assert!(game_clock.is_flagged(Black));
+    assert_eq!(game_clock.get_remaining_time(Black), Duration::ZERO);
+    assert!(!game_clock.update(White, Duration::ZERO));
+}
+
+#[test]
+fn test_delays() {
+    let mut simple = GameClock::new("10d2".parse().unwrap()).unwrap();
+    assert_eq!(simple.get_available_time(White), Duration::from_secs(12));
+    assert!(simple.update(White, Duration::from_secs(1)));
+    assert_eq!(simple.get_remaining_time(White), Duration::from_secs(10));
+    assert!(simple.update(White, Duration::from_secs(5)));
+    assert_eq!(simple.get_remaining_time(White), Duration::from_secs(7));
+    assert!(simple.update(White, Duration::from_secs(9)));
+    assert!(!simple.update(White, Duration::from_millis(2_001)));
+
+    let mut bronstein = GameClock::new("10b2".parse().unwrap()).unwrap();
+    assert_eq!(bronstein.get_available_time(White), Duration::from_secs(10));
+    assert!(bronstein.update(White, Duration::from_secs(1)));
+    assert_eq!(bronstein.get_remaining_time(White), Duration::from_secs(10));
+    assert!(bronstein.update(White, Duration::from_secs(5)));
+    assert_eq!(bronstein.get_remaining_time(White), Duration::from_secs(7));
+}
+
+#[test]
+fn test_multi_period_time_control() {
+    let mut game_clock = GameClock::new("2/60+10:1/30:15".parse().unwrap()).unwrap();
+    assert_eq!(game_clock.get_moves_to_go(White), Some(2));
+    assert!(game_clock.update(White, Duration::from_secs(20)));
+    assert_eq!(game_clock.get_moves_to_go(White), Some(1));
+    assert_eq!(
+        game_clock.get_remaining_time(White),
+        Duration::from_secs(50)
+    );
+    assert!(game_clock.update(White, Duration::from_secs(20)));
+    assert_eq!(game_clock.get_moves_to_go(White), Some(1));
+    assert_eq!(
+        game_clock.get_remaining_time(White),
+        Duration::from_secs(70)
+    );
+    assert!(game_clock.update(White, Duration::from_secs(10)));
+    assert_eq!(game_clock.get_moves_to_go(White), None);
+    assert_eq!(
+        game_clock.get_remaining_time(White),
+        Duration::from_secs(75)
+    );
+    assert_eq!(game_clock.get_moves_to_go(Black), Some(2));
+}
+
+#[test]
+fn test_repeating_last_period() {
+    let mut game_clock = GameClock::new("2/10".parse().unwrap()).unwrap();
+    for _ in 0..4 {
+        assert!(game_clock.update(Black, Duration::from_secs(4)));
+    }
+    assert_eq!(
+        game_clock.get_remaining_time(Black),
+        Duration::from_secs(14)
+    );
+    assert_eq!(game_clock.get_moves_to_go(Black), Some(2));
+}
+
+#[test]
+fn test_hourglass() {
+    let mut game_clock = GameClock::new("*30".parse().unwrap()).unwrap();
+    assert!(game_clock.update(White, Duration::from_secs(10)));
+    assert_eq!(
+        game_clock.get_remaining_time(White),
+        Duration::from_secs(20)
+    );
+    assert_eq!(
+        game_clock.get_remaining_time(Black),
+        Duration::from_secs(40)
+    );
+    assert!(!game_clock.update(White, Duration::from_secs(21)));
+    assert_eq!(game_clock.get_flagged_color(), Some(White));
+}
+
+#[test]
+fn test_move_time_and_unlimited() {
+    let mut game_clock = GameClock::new("st=2".parse().unwrap()).unwrap();
+    assert_eq!(
+        game_clock.get_go_command(White),
+        Some(GoCommand::from_movetime(Duration::from_secs(2)))
+    );
+    assert!(game_clock.update(White, Duration::from_secs(2)));
+    assert!(!game_clock.update(Black, Duration::from_millis(2_001)));
+
+    let mut game_clock = GameClock::new(TimeControl::Unlimited).unwrap();
+    assert_eq!(game_clock.get_go_command(White), None);
+    assert!(game_clock.update(White, Duration::from_secs(1_000_000)));
+}
+
+#[test]
+fn test_go_command_from_game_clock() {
+    let mut game_clock = GameClock::new("40/5400+30:900d5".parse().unwrap()).unwrap();
+    assert!(game_clock.update(White, Duration::from_secs(60)));
+    assert_eq!(
+        game_clock.get_go_command(Black),
+        Some(GoCommand::from_time_clock(
+            Duration::from_secs(5370),
+            Duration::from_secs(5400),
+            Duration::from_secs(30),
+            Duration::from_secs(30),
+            Some(40),
+        ))
+    );
+    assert_eq!(
+        GameClock::new("300d5".parse().unwrap())
+            .unwrap()
+            .get_go_command(White),
+        Some(GoCommand::from_time_clock(
+            Duration::from_secs(305),
+            Duration::from_secs(305),
+            Duration::ZERO,
+            Duration::ZERO,
+            None,
+        ))
+    );
+}
+
+#[test]
+fn test_game_clock_rejects_empty_periods() {
+    assert!(GameClock::new(TimeControl::Periods(Vec::new())).is_err());
+}
+
+#[test]
+fn test_selfplay_time_control_command() {
+    assert_eq!(
+        Parser::parse_command("selfplay tc 40/5400+30:900").unwrap(),
+        vec![UserCommand::SelfPlayWithTimeControl(
+            "40/5400+30:900".parse().unwrap()
+        )]
+    );
+    assert!(Parser::parse_command("selfplay tc 40/").is_err());
+}
+
+#[test]
+fn test_self_play_with_time_control() {
+    let mut engine = Engine::from_fen("7k/8/5KQ1/8/8/8/8/8 w - - 0 1").unwrap();
+    self_play_with_time_control(&mut engine, &"1+0.1".parse().unwrap(), false, 4).unwrap();
+    assert_eq!(
+        engine.get_board().get_fen(),
+        "7k/8/5KQ1/8/8/8/8/8 w - - 0 1"
+    );
+}
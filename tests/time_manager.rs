@@ -0,0 +1,171 @@
+use timecat::*;
+
+fn get_time_clock(
+    wtime: u64,
+    btime: u64,
+    inc: u64,
+    moves_to_go: Option<NumMoves>,
+) -> TimedGoCommand {
+    TimedGoCommand {
+        wtime: Duration::from_millis(wtime),
+        btime: Duration::from_millis(btime),
+        winc: Duration::from_millis(inc),
+        binc: Duration::from_millis(inc),
+        moves_to_go,
+    }
+}
+
+fn get_time_manager(
+    time_clock: &TimedGoCommand,
+    turn: Color,
+    fullmove_number: NumMoves,
+) -> TimeManager {
+    let mut time_manager = TimeManager::new(Duration::from_millis(10));
+    time_manager.set_time_clock(time_clock, turn, fullmove_number);
+    time_manager
+}
+
+#[test]
+fn test_limits_never_exceed_remaining_time() {
+    for (wtime, inc, moves_to_go) in [
+        (60_000, 0, None),
+        (60_000, 1_000, None),
+        (1_000, 0, None),
+        (50, 0, None),
+        (5_400_000, 30_000, Some(40)),
+        (60_000, 0, Some(10)),
+        (60_000, 0, Some(1)),
+        (5, 0, Some(1)),
+    ] {
+        for fullmove_number in [1, 20, 60, 150] {
+            let time_clock = get_time_clock(wtime, wtime, inc, moves_to_go);
+            let time_manager = get_time_manager(&time_clock, White, fullmove_number);
+            let wtime = Duration::from_millis(wtime);
+            assert!(time_manager.get_optimum_time() <= time_manager.get_maximum_time());
+            assert!(time_manager.get_maximum_time() <= wtime);
+            assert!(time_manager.get_scaled_optimum_time() <= time_manager.get_maximum_time());
+        }
+    }
+}
+
+#[test]
+fn test_limits_use_side_to_move_clock() {
+    let time_clock = get_time_clock(60_000, 6_000, 0, None);
+    let white_time_manager = get_time_manager(&time_clock, White, 10);
+    let black_time_manager = get_time_manager(&time_clock, Black, 10);
+    assert!(white_time_manager.get_optimum_time() > black_time_manager.get_optimum_time());
+    assert!(black_time_manager.get_maximum_time() <= Duration::from_millis(6_000));
+}
+
+#[test]
+fn test_moves_to_go_handling() {
+    let last_move = get_time_manager(&get_time_clock(60_000, 60_000, 0, Some(1)), White, 40);
+    let many_moves = get_time_manager(&get_time_clock(60_000, 60_000, 0, Some(30)), White, 10);
+    assert!(last_move.get_optimum_time() > Duration::from_secs(50));
+    assert!(many_moves.get_optimum_time() < Duration::from_secs(3));
+    assert!(last_move.get_optimum_time() > many_moves.get_optimum_time());
+}
+
+#[test]
+fn test_zero_moves_to_go_is_sudden_death() {
+    let zero_moves_to_go = get_time_manager(&get_time_clock(60_000, 60_000, 0, Some(0)), White, 10);
+    let sudden_death = get_time_manager(&get_time_clock(60_000, 60_000, 0, None), White, 10);
+    assert_eq!(
+        zero_moves_to_go.get_optimum_time(),
+        sudden_death.get_optimum_time()
+    );
+    assert_eq!(
+        zero_moves_to_go.get_maximum_time(),
+        sudden_death.get_maximum_time()
+    );
+}
+
+#[test]
+fn test_move_overhead_is_counted_once() {
+    let time_clock = get_time_clock(60_000, 60_000, 0, Some(20));
+    let mut with_overhead = TimeManager::new(Duration::from_millis(100));
+    with_overhead.set_time_clock(&time_clock, White, 10);
+    let mut without_overhead = TimeManager::new(Duration::ZERO);
+    without_overhead.set_time_clock(&time_clock, White, 10);
+    assert_eq!(
+        with_overhead.get_maximum_time(),
+        without_overhead.get_maximum_time()
+    );
+    let time_elapsed = with_overhead.get_maximum_time() - Duration::from_millis(100);
+    assert!(with_overhead.is_hard_limit_reached(time_elapsed));
+    assert!(!with_overhead.is_hard_limit_reached(time_elapsed - Duration::from_millis(1)));
+}
+
+#[test]
+fn test_increment_increases_time() {
+    let without_increment = get_time_manager(&get_time_clock(60_000, 60_000, 0, None), White, 10);
+    let with_increment = get_time_manager(&get_time_clock(60_000, 60_000, 2_000, None), White, 10);
+    assert!(with_increment.get_optimum_time() > without_increment.get_optimum_time());
+}
+
+#[test]
+fn test_fixed_time_is_hard_limit() {
+    let mut time_manager = TimeManager::new(Duration::ZERO);
+    assert!(!time_manager.is_time_limited());
+    assert!(!time_manager.is_hard_limit_reached(Duration::from_secs(1_000_000)));
+    time_manager.set_fixed_time(Duration::from_millis(500));
+    assert!(!time_manager.is_hard_limit_reached(Duration::from_millis(499)));
+    assert!(time_manager.is_hard_limit_reached(Duration::from_millis(500)));
+}
+
+#[test]
+fn test_stable_best_move_stops_early() {
+    let time_clock = get_time_clock(60_000, 60_000, 0, None);
+    let mut stable = get_time_manager(&time_clock, White, 10);
+    let mut unstable = get_time_manager(&time_clock, White, 10);
+    let board = Board::default();
+    let moves = board.generate_legal_moves().take(2).collect_vec();
+    for iteration in 0..10 {
+        stable.update_on_iteration(Some(moves[0]), 20, 0.9);
+        unstable.update_on_iteration(Some(moves[iteration % 2]), 20, 0.3);
+    }
+    assert!(stable.get_scaled_optimum_time() < stable.get_optimum_time());
+    assert!(unstable.get_scaled_optimum_time() > unstable.get_optimum_time());
+    let time_elapsed = stable.get_optimum_time();
+    assert!(stable.is_soft_limit_reached(time_elapsed));
+    assert!(!unstable.is_soft_limit_reached(time_elapsed));
+}
+
+#[test]
+fn test_score_drop_extends_time() {
+    let time_clock = get_time_clock(60_000, 60_000, 0, None);
+    let mut steady = get_time_manager(&time_clock, White, 10);
+    let mut dropping = get_time_manager(&time_clock, White, 10);
+    let move_ = Board::default().generate_legal_moves().next();
+    for (steady_score, dropping_score) in [(50, 50), (50, 50), (50, -30)] {
+        steady.update_on_iteration(move_, steady_score, 0.5);
+        dropping.update_on_iteration(move_, dropping_score, 0.5);
+    }
+    assert!(dropping.get_scaled_optimum_time() > steady.get_scaled_optimum_time());
+}
+
+#[test]
+fn test_fixed_time_is_not_scaled() {
+    let mut time_manager = TimeManager::new(Duration::ZERO);
+    time_manager.set_fixed_time(Duration::from_millis(5_000));
+    assert!(time_manager.is_fixed_time());
+    let move_ = Board::default().generate_legal_moves().next();
+    for _ in 0..10 {
+        time_manager.update_on_iteration(move_, 20, 0.9);
+    }
+    assert!(time_manager.get_optimum_time_scale() < 1.0);
+    assert!(!time_manager.is_soft_limit_reached(Duration::from_millis(4_999)));
+    assert!(time_manager.is_soft_limit_reached(Duration::from_millis(5_000)));
+    time_manager.reset_variables();
+    assert!(!time_manager.is_fixed_time());
+}
+
+#[test]
+fn test_time_clock_hard_limit_leaves_move_overhead() {
+    let time_manager = get_time_manager(&get_time_clock(2_000, 2_000, 0, None), White, 1);
+    assert!(!time_manager.is_fixed_time());
+    assert!(time_manager.get_maximum_time() < Duration::from_millis(2_000));
+    let deadline = time_manager.get_maximum_time() - time_manager.get_move_overhead();
+    assert!(!time_manager.is_hard_limit_reached(deadline - Duration::from_millis(1)));
+    assert!(time_manager.is_hard_limit_reached(deadline));
+}
@@ -0,0 +1,104 @@
+use timecat::*;
+
+fn get_console_config() -> TimecatConfig {
+    let mut config = TimecatConfig::default();
+    config.set_console_mode(true);
+    #[cfg(feature = "colored")]
+    config.set_colored_output(false);
+    config
+}
+
+fn get_uci_config() -> TimecatConfig {
+    let mut config = get_console_config();
+    config.set_uci_mode(true);
+    config
+}
+
+#[test]
+fn test_stringify_score_with_config() {
+    let score: Score = 2 * PAWN_VALUE;
+    assert_eq!(
+        score.stringify_score_with_config(&get_console_config()),
+        "2"
+    );
+    assert_eq!(
+        score.stringify_score_with_config(&get_uci_config()),
+        "cp 200"
+    );
+    let mate_score = CHECKMATE_SCORE - 3;
+    assert_eq!(
+        mate_score.stringify_score_with_config(&get_console_config()),
+        "M2",
+    );
+    assert_eq!(
+        mate_score.stringify_score_with_config(&get_uci_config()),
+        "mate 2",
+    );
+}
+
+#[test]
+fn test_stringify_move_with_config() {
+    let board = Board::default();
+    let move_ = Move::from_san(&board, "Nf3").unwrap();
+    let mut config = get_console_config();
+    assert_eq!(
+        move_.stringify_move_with_config(&board, &config).unwrap(),
+        "Nf3",
+    );
+    config.set_long_algebraic_notation(true);
+    assert_eq!(
+        move_.stringify_move_with_config(&board, &config).unwrap(),
+        "Ng1-f3",
+    );
+    assert_eq!(
+        move_
+            .stringify_move_with_config(&board, &get_uci_config())
+            .unwrap(),
+        "g1f3",
+    );
+}
+
+#[test]
+fn test_config_apply_is_scoped() {
+    let default_config = TimecatConfig::get_current();
+    let console_config = get_console_config();
+    let uci_config = get_uci_config();
+    console_config.apply(|| {
+        assert_eq!(TimecatConfig::get_current(), console_config);
+        assert_eq!((PAWN_VALUE as Score).stringify(), "1");
+        uci_config.apply(|| {
+            assert_eq!(TimecatConfig::get_current(), uci_config);
+            assert_eq!((PAWN_VALUE as Score).stringify(), "cp 100");
+        });
+        assert_eq!(TimecatConfig::get_current(), console_config);
+    });
+    assert_eq!(TimecatConfig::get_current(), default_config);
+}
+
+#[test]
+fn test_engines_keep_separate_configs() {
+    let mut console_engine = Engine::default();
+    *console_engine.get_config_mut() = get_console_config();
+    let mut uci_engine = Engine::default();
+    *uci_engine.get_config_mut() = get_uci_config();
+    assert!(console_engine.get_config().is_in_console_mode());
+    assert!(uci_engine.get_config().is_in_uci_mode());
+    assert_eq!(
+        Engine::default().get_config(),
+        &GLOBAL_TIMECAT_STATE.get_config(),
+    );
+}
+
+#[test]
+fn test_mode_commands_only_change_instance_config() {
+    let global_config = GLOBAL_TIMECAT_STATE.get_config();
+    let mut timecat = TimecatBuilder::<Engine>::default()
+        .set_config(get_uci_config())
+        .build();
+    timecat.run_uci_command("console").unwrap();
+    assert!(timecat.get_config().is_in_console_mode());
+    assert!(timecat.get_config().is_in_debug_mode());
+    timecat.run_uci_command("ucimode").unwrap();
+    assert!(timecat.get_config().is_in_uci_mode());
+    assert_eq!(GLOBAL_TIMECAT_STATE.get_config(), global_config);
+}
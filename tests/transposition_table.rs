@@ -0,0 +1,262 @@
+use timecat::*;
+
+const NUM_CLUSTERS: u64 = 1 << 15;
+
+fn get_transposition_table() -> TranspositionTable {
+    let transposition_table = TranspositionTable::new(CacheTableSize::Exact(1));
+    assert_eq!(
+        transposition_table.len(),
+        NUM_CLUSTERS as usize * TRANSPOSITION_TABLE_CLUSTER_SIZE
+    );
+    transposition_table
+}
+
+fn get_colliding_key(index: u64, verification_key: u64) -> u64 {
+    (verification_key << 32) | index
+}
+
+fn get_move(s: &str) -> Option<Move> {
+    Some(Move::from_str(s).unwrap())
+}
+
+#[test]
+fn test_entry_is_packed() {
+    assert_eq!(size_of::<TranspositionTableEntry>(), 10);
+    assert_eq!(TranspositionTable::get_cluster_size(), 32);
+}
+
+#[test]
+fn test_read_and_write() {
+    let transposition_table = get_transposition_table();
+    let key = get_colliding_key(7, 1);
+    assert_eq!(transposition_table.read(key, 0, 0), (None, None));
+    transposition_table.write(key, 5, 0, 42, EntryFlagHash::Beta, get_move("e2e4"));
+    assert_eq!(
+        transposition_table.read(key, 5, 0),
+        (Some((42, EntryFlagHash::Beta)), get_move("e2e4"))
+    );
+    assert_eq!(
+        transposition_table.read(key, 6, 0),
+        (None, get_move("e2e4"))
+    );
+    assert_eq!(transposition_table.read_best_move(key), get_move("e2e4"));
+    assert_eq!(
+        transposition_table.read(get_colliding_key(7, 2), 0, 0),
+        (None, None)
+    );
+    transposition_table.write(key, 4, 0, 10, EntryFlagHash::Alpha, None);
+    assert_eq!(
+        transposition_table.read(key, 4, 0),
+        (Some((10, EntryFlagHash::Alpha)), get_move("e2e4"))
+    );
+    transposition_table.clear_best_moves();
+    assert_eq!(transposition_table.read_best_move(key), None);
+    transposition_table.clear();
+    assert!(transposition_table.is_empty());
+}
+
+#[test]
+fn test_deeper_entries_are_kept_within_a_search() {
+    let transposition_table = get_transposition_table();
+    let key = get_colliding_key(3, 1);
+    transposition_table.write(key, 12, 0, 100, EntryFlagHash::Beta, get_move("d2d4"));
+    transposition_table.write(key, 2, 0, -5, EntryFlagHash::Alpha, get_move("c2c4"));
+    assert_eq!(
+        transposition_table.read(key, 0, 0),
+        (Some((100, EntryFlagHash::Beta)), get_move("c2c4"))
+    );
+    transposition_table.increment_generation();
+    transposition_table.write(key, 2, 0, -5, EntryFlagHash::Alpha, None);
+    assert_eq!(
+        transposition_table.read(key, 0, 0),
+        (Some((-5, EntryFlagHash::Alpha)), get_move("c2c4"))
+    );
+}
+
+#[test]
+fn test_cluster_replacement_prefers_shallow_and_old_entries() {
+    let transposition_table = get_transposition_table();
+    let keys = (1..=4)
+        .map(|verification_key| get_colliding_key(11, verification_key))
+        .collect_vec();
+    transposition_table.write(keys[0], 10, 0, 1, EntryFlagHash::Exact, None);
+    transposition_table.write(keys[1], 2, 0, 2, EntryFlagHash::Exact, None);
+    transposition_table.write(keys[2], 8, 0, 3, EntryFlagHash::Exact, None);
+    for &key in &keys[..3] {
+        assert!(transposition_table.read(key, 0, 0).0.is_some());
+    }
+    transposition_table.write(keys[3], 5, 0, 4, EntryFlagHash::Exact, None);
+    assert!(transposition_table.read(keys[0], 0, 0).0.is_some());
+    assert!(transposition_table.read(keys[1], 0, 0).0.is_none());
+    assert!(transposition_table.read(keys[2], 0, 0).0.is_some());
+    assert!(transposition_table.read(keys[3], 0, 0).0.is_some());
+
+    for _ in 0..2 {
+        transposition_table.increment_generation();
+    }
+    transposition_table.write(keys[3], 5, 0, 4, EntryFlagHash::Exact, None);
+    transposition_table.write(keys[1], 1, 0, 5, EntryFlagHash::Exact, None);
+    assert!(transposition_table.read(keys[0], 0, 0).0.is_some());
+    assert!(transposition_table.read(keys[1], 0, 0).0.is_some());
+    assert!(transposition_table.read(keys[2], 0, 0).0.is_none());
+    assert!(transposition_table.read(keys[3], 0, 0).0.is_some());
+}
+
+#[test]
+fn test_hash_full_counts_current_generation() {
+    let transposition_table = get_transposition_table();
+    assert_eq!(transposition_table.get_hash_full(), 0.0);
+    for index in 0..HASH_FULL_SAMPLE_NUM_CLUSTERS as u64 {
+        transposition_table.write(index, 1, 0, 0, EntryFlagHash::Exact, None);
+    }
+    let hash_full = transposition_table.get_hash_full();
+    assert!((hash_full - 100.0 / TRANSPOSITION_TABLE_CLUSTER_SIZE as f64).abs() < 1e-9);
+    transposition_table.increment_generation();
+    assert_eq!(transposition_table.get_hash_full(), 0.0);
+    for index in 0..NUM_CLUSTERS {
+        transposition_table.write(index, 1, 0, 0, EntryFlagHash::Exact, None);
+    }
+    assert!((transposition_table.get_hash_full() - hash_full).abs() < 1e-9);
+}
+
+#[test]
+fn test_generation_wraps_around() {
+    let transposition_table = get_transposition_table();
+    for _ in 0..TRANSPOSITION_TABLE_NUM_GENERATIONS {
+        transposition_table.increment_generation();
+    }
+    assert_eq!(transposition_table.get_generation(), 0);
+}
+
+#[test]
+fn test_set_size_clears_table() {
+    let transposition_table = get_transposition_table();
+    transposition_table.write(1, 1, 0, 0, EntryFlagHash::Exact, get_move("e2e4"));
+    transposition_table.set_size(CacheTableSize::Exact(2));
+    assert_eq!(
+        transposition_table.len(),
+        2 * NUM_CLUSTERS as usize * TRANSPOSITION_TABLE_CLUSTER_SIZE
+    );
+    assert!(transposition_table.is_empty());
+}
+
+#[test]
+fn test_set_size_of_shared_table() {
+    let transposition_table = Arc::new(get_transposition_table());
+    let shared_transposition_table = transposition_table.clone();
+    shared_transposition_table.set_size(CacheTableSize::Exact(2));
+    shared_transposition_table.write(1, 1, 0, 0, EntryFlagHash::Exact, get_move("e2e4"));
+    assert_eq!(transposition_table.get_size(), CacheTableSize::Exact(2));
+    assert_eq!(transposition_table.read_best_move(1), get_move("e2e4"));
+}
+
+fn get_temporary_hash_file_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("timecat_{}_{name}.hash", std::process::id()))
+}
+
+#[test]
+fn test_save_and_load_hash() {
+    let path = get_temporary_hash_file_path("save_and_load");
+    let transposition_table = get_transposition_table();
+    transposition_table.increment_generation();
+    for index in 0..100 {
+        transposition_table.write(
+            get_colliding_key(index, index + 1),
+            (index % 20) as Depth,
+            0,
+            index as Score,
+            EntryFlagHash::Exact,
+            get_move("g1f3"),
+        );
+    }
+    transposition_table.save_hash(&path).unwrap();
+
+    let loaded_transposition_table = TranspositionTable::new(CacheTableSize::Exact(2));
+    loaded_transposition_table.load_hash(&path).unwrap();
+    assert_eq!(
+        loaded_transposition_table.get_size(),
+        CacheTableSize::Exact(1)
+    );
+    assert_eq!(loaded_transposition_table.len(), transposition_table.len());
+    assert_eq!(loaded_transposition_table.get_generation(), 1);
+    for index in 0..100 {
+        let key = get_colliding_key(index, index + 1);
+        assert_eq!(
+            loaded_transposition_table.read(key, 0, 0),
+            transposition_table.read(key, 0, 0)
+        );
+    }
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_load_corrupt_hash() {
+    let path = get_temporary_hash_file_path("corrupt");
+    let transposition_table = get_transposition_table();
+    transposition_table.write(1, 5, 0, 10, EntryFlagHash::Exact, get_move("e2e4"));
+    transposition_table.save_hash(&path).unwrap();
+    let bytes = fs::read(&path).unwrap();
+
+    let loaded_transposition_table = get_transposition_table();
+    let mut corrupt_bytes = bytes.clone();
+    corrupt_bytes[100] ^= 1;
+    fs::write(&path, &corrupt_bytes).unwrap();
+    assert_eq!(
+        loaded_transposition_table.load_hash(&path),
+        Err(TimecatError::BadHashFile)
+    );
+    fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+    assert_eq!(
+        loaded_transposition_table.load_hash(&path),
+        Err(TimecatError::BadHashFile)
+    );
+    let mut incompatible_bytes = bytes.clone();
+    incompatible_bytes[12] ^= 1;
+    fs::write(&path, &incompatible_bytes).unwrap();
+    assert_eq!(
+        loaded_transposition_table.load_hash(&path),
+        Err(TimecatError::IncompatibleHashFile)
+    );
+    assert!(loaded_transposition_table.is_empty());
+    fs::write(&path, &bytes).unwrap();
+    loaded_transposition_table.load_hash(&path).unwrap();
+    assert_eq!(
+        loaded_transposition_table.read(1, 0, 0),
+        (Some((10, EntryFlagHash::Exact)), get_move("e2e4"))
+    );
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_hash_commands() {
+    let path = get_temporary_hash_file_path("commands");
+    let path_string = path.to_str().unwrap().to_string();
+    assert_eq!(
+        Parser::parse_command(&format!("save_hash {path_string}")).unwrap(),
+        vec![UserCommand::SaveHash(path_string.clone())]
+    );
+    assert_eq!(
+        Parser::parse_command(&format!("load_hash {path_string}")).unwrap(),
+        vec![UserCommand::LoadHash(path_string.clone())]
+    );
+    assert!(Parser::parse_command("save_hash").is_err());
+
+    let mut engine = Engine::from_fen(STARTING_POSITION_FEN).unwrap();
+    let best_move = engine.go_quiet(&SearchConfig::new_depth(4)).get_best_move();
+    engine.save_hash(&path).unwrap();
+    engine.clear_hash();
+    assert_eq!(
+        engine
+            .get_transposition_table()
+            .read_best_move(engine.get_board().get_hash()),
+        None
+    );
+    engine.load_hash(&path).unwrap();
+    assert_eq!(
+        engine
+            .get_transposition_table()
+            .read_best_move(engine.get_board().get_hash()),
+        best_move
+    );
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,380 @@
+use timecat::*;
+
+fn get_variant_board(variant: Variant, fen: &str) -> Board {
+    let mut board = Board::new();
+    board.set_variant(variant).unwrap();
+    board.set_fen(fen).unwrap();
+    board
+}
+
+fn get_variant_engine(variant: Variant, fen: &str) -> Engine {
+    let mut engine = Engine::default();
+    engine.get_board_mut().set_variant(variant).unwrap();
+    engine.set_fen(fen).unwrap();
+    engine
+}
+
+#[test]
+fn test_variant_names() {
+    for variant in ALL_VARIANTS {
+        assert_eq!(variant.to_string().parse::<Variant>().unwrap(), variant);
+    }
+    assert_eq!(
+        "King-of-the-Hill".parse::<Variant>().unwrap(),
+        Variant::KingOfTheHill
+    );
+    assert!(matches!(
+        "crazyhorse".parse::<Variant>(),
+        Err(TimecatError::UnknownVariant { .. })
+    ));
+}
+
+#[test]
+fn test_three_check_fen() {
+    let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 2+3 0 1";
+    let position = BoardPosition::from_fen(fen).unwrap();
+    assert_eq!(position.get_variant(), Variant::ThreeCheck);
+    assert_eq!(position.get_remaining_checks(White), 2);
+    assert_eq!(position.get_remaining_checks(Black), 3);
+    assert_eq!(position.get_fen(), fen);
+    // The checks given can also be written at the end of the FEN.
+    let checks_given_fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 +1+0";
+    assert_eq!(BoardPosition::from_fen(checks_given_fen).unwrap(), position);
+    let fresh_position =
+        BoardPosition::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 3+3 0 1")
+            .unwrap();
+    assert_ne!(fresh_position.get_hash(), position.get_hash());
+    assert_ne!(fresh_position, position);
+    assert!(BoardPosition::from_fen(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 4+3 0 1"
+    )
+    .is_err());
+}
+
+#[test]
+fn test_three_check_win() {
+    let mut board = Board::from_fen("4k3/8/8/8/8/8/8/4K2R w - - 1+3 0 1").unwrap();
+    assert!(!board.is_insufficient_material());
+    board.push_san("Rh8+").unwrap();
+    assert_eq!(board.get_remaining_checks(White), 0);
+    assert_eq!(board.status(), BoardStatus::VariantWin(White));
+    assert_eq!(board.result(), GameResult::Win(White));
+    let mut engine = Engine::from_fen("4k3/8/8/8/8/8/8/4K2R w - - 1+3 0 1").unwrap();
+    let search_info = engine.go_quiet(&SearchConfig::new_depth(3));
+    let best_move = search_info.get_best_move().unwrap();
+    assert!(engine.get_board().gives_check(best_move));
+    assert!(is_checkmate(search_info.get_score().unwrap()));
+}
+
+#[test]
+fn test_king_of_the_hill() {
+    let fen = "4k3/8/8/8/8/3K4/8/8 w - - 0 1";
+    let mut board = get_variant_board(Variant::KingOfTheHill, fen);
+    assert_eq!(board.get_variant(), Variant::KingOfTheHill);
+    assert!(!board.is_game_over());
+    board.push_san("Kd4").unwrap();
+    assert_eq!(board.status(), BoardStatus::VariantWin(White));
+    let mut engine = get_variant_engine(Variant::KingOfTheHill, fen);
+    let search_info = engine.go_quiet(&SearchConfig::new_depth(3));
+    let best_move = search_info.get_best_move().unwrap();
+    assert!(BB_CENTER.contains(best_move.get_dest()));
+    assert!(is_checkmate(search_info.get_score().unwrap()));
+}
+
+#[test]
+fn test_racing_kings_move_generation() {
+    let mut board = Board::new();
+    board.set_variant(Variant::RacingKings).unwrap();
+    assert_eq!(board.get_fen(), RACING_KINGS_STARTING_FEN);
+    assert_eq!(board.generate_legal_moves().len(), 21);
+    assert!(board
+        .generate_legal_moves()
+        .all(|move_| !board.gives_check(move_)));
+    assert_eq!(MoveGenerator::perft_test(&board, 3), 11264);
+    assert_eq!(MoveGenerator::perft_cross_check(&board, 3).unwrap(), 11264);
+    // Positions with a king in check or with pawns are not Racing Kings positions.
+    assert!(board
+        .clone()
+        .set_fen("8/8/8/8/8/8/krbnNBRK/qrbnNBRQ b - - 0 1")
+        .is_ok());
+    assert!(board
+        .clone()
+        .set_fen("8/8/8/8/8/3k4/8/3RK3 b - - 0 1")
+        .is_err());
+    assert!(board
+        .clone()
+        .set_fen("8/8/8/8/8/4k3/P7/4K3 w - - 0 1")
+        .is_err());
+}
+
+#[test]
+fn test_racing_kings_goal() {
+    // Black can still reach the eighth rank, which would draw.
+    let mut board = get_variant_board(Variant::RacingKings, "K7/7k/8/8/8/8/8/8 b - - 0 1");
+    assert_eq!(board.status(), BoardStatus::Ongoing);
+    board.push_san("Kh8").unwrap();
+    assert_eq!(board.status(), BoardStatus::VariantDraw);
+    let board = get_variant_board(Variant::RacingKings, "K7/8/7k/8/8/8/8/8 b - - 0 1");
+    assert_eq!(board.status(), BoardStatus::VariantWin(White));
+    let board = get_variant_board(Variant::RacingKings, "7k/8/K7/8/8/8/8/8 w - - 0 1");
+    assert_eq!(board.status(), BoardStatus::VariantWin(Black));
+}
+
+#[test]
+fn test_uci_variant_option() {
+    let mut timecat = TimecatBuilder::<Engine>::default()
+        .set_output_sink(Arc::new(BufferSink::new()))
+        .build();
+    timecat
+        .run_uci_command("setoption name UCI_Variant value racingkings")
+        .unwrap();
+    timecat.run_uci_command("position startpos").unwrap();
+    assert_eq!(
+        timecat.get_engine().get_board().get_fen(),
+        RACING_KINGS_STARTING_FEN
+    );
+    assert!(timecat
+        .run_uci_command("setoption name UCI_Variant value crazyhorse")
+        .is_err());
+    timecat
+        .run_uci_command("setoption name UCI_Variant value chess")
+        .unwrap();
+    assert_eq!(
+        timecat.get_engine().get_board().get_fen(),
+        STARTING_POSITION_FEN
+    );
+}
+
+#[test]
+fn test_crazyhouse_fen() {
+    let fen = "r1bqkbnr/pppp1ppp/2n5/8/8/5N2/PPPP1PPP/RNBQKB1R[Pp] w KQkq - 0 1";
+    let position = BoardPosition::from_fen(fen).unwrap();
+    assert_eq!(position.get_variant(), Variant::Crazyhouse);
+    assert_eq!(position.get_pocket_count(White, Pawn), 1);
+    assert_eq!(position.get_pocket_count(Black, Pawn), 1);
+    assert_eq!(position.get_fen(), fen);
+    // The pockets can also be written as a ninth rank.
+    let ninth_rank_fen = "r1bqkbnr/pppp1ppp/2n5/8/8/5N2/PPPP1PPP/RNBQKB1R/pP w KQkq - 0 1";
+    assert_eq!(BoardPosition::from_fen(ninth_rank_fen).unwrap(), position);
+    let promoted_fen = "4k3/8/8/3q~4/4P3/8/8/4K3[] w - - 0 1";
+    let position = BoardPosition::from_fen(promoted_fen).unwrap();
+    assert_eq!(position.get_promoted_mask(), D5.to_bitboard());
+    assert_eq!(position.get_fen(), promoted_fen);
+    assert!(BoardPosition::from_fen("4k3/8/8/8/8/8/8/4K3[Kq] w - - 0 1").is_err());
+    assert!(BoardPosition::from_fen("4k3/8/8/3p~4/8/8/8/4K3[] w - - 0 1").is_err());
+    assert_eq!(
+        get_variant_board(Variant::Crazyhouse, STARTING_POSITION_FEN).get_fen(),
+        CRAZYHOUSE_STARTING_FEN
+    );
+}
+
+#[test]
+fn test_crazyhouse_drops() {
+    let mut board = Board::from_fen("4k3/8/8/8/8/8/8/4K3[Pn] w - - 0 1").unwrap();
+    // A pawn can be dropped on any empty square but the back ranks.
+    assert_eq!(board.generate_legal_moves().len(), 48 + 5);
+    assert!(board
+        .generate_legal_moves()
+        .contains(&Move::new_drop(Pawn, E4)));
+    assert!(!board
+        .generate_legal_moves()
+        .contains(&Move::new_drop(Pawn, E1)));
+    assert!(!board.is_legal(&Move::new_drop(Knight, E4)));
+    assert_eq!(Move::from_str("P@e4").unwrap(), Move::new_drop(Pawn, E4));
+    assert_eq!(
+        Move::new_drop(Pawn, E4).san(board.get_position()).unwrap(),
+        "P@e4"
+    );
+    board.push_san("@d7+").unwrap();
+    assert!(board.is_check());
+    assert_eq!(board.get_pocket_count(White, Pawn), 0);
+    assert_eq!(board.get_halfmove_clock(), 0);
+    assert_eq!(board.get_fen(), "4k3/3P4/8/8/8/8/8/4K3[n] b - - 0 1");
+    // A drop cannot block the check of a pawn.
+    assert!(!board.is_legal(&Move::new_drop(Knight, F6)));
+    assert_eq!(board.generate_legal_moves().len(), 5);
+    // Only the drops blocking the check are legal.
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/r3K3[NP] w - - 0 1").unwrap();
+    assert_eq!(board.generate_legal_moves().len(), 3 + 3);
+    assert_eq!(MoveGenerator::perft_cross_check(&board, 3).unwrap(), {
+        MoveGenerator::perft_test(&board, 3)
+    });
+}
+
+#[test]
+fn test_crazyhouse_captures() {
+    let mut board = Board::from_fen("4k3/8/8/3q4/4P3/8/8/4K3[] w - - 0 1").unwrap();
+    board.push_san("exd5").unwrap();
+    assert_eq!(board.get_pocket_count(White, Queen), 1);
+    assert_eq!(board.get_fen(), "4k3/8/8/3P4/8/8/8/4K3[Q] b - - 0 1");
+    assert_eq!(
+        board.get_hash(),
+        BoardPosition::from_fen("4k3/8/8/3P4/8/8/8/4K3[Q] b - - 0 1")
+            .unwrap()
+            .get_hash()
+    );
+    assert_ne!(
+        board.get_hash(),
+        BoardPosition::from_fen("4k3/8/8/3P4/8/8/8/4K3[] b - - 0 1")
+            .unwrap()
+            .get_hash()
+    );
+    assert!(!board.is_insufficient_material());
+    // A captured promoted piece goes back to the pocket as a pawn.
+    let mut board = Board::from_fen("4k3/8/8/3q~4/4P3/8/8/4K3[] w - - 0 1").unwrap();
+    board.push_san("exd5").unwrap();
+    assert_eq!(board.get_pocket_count(White, Queen), 0);
+    assert_eq!(board.get_pocket_count(White, Pawn), 1);
+    // The promoted piece is tracked until it is captured.
+    let mut board = Board::from_fen("4k3/1P6/8/8/8/8/8/4K3[] w - - 0 1").unwrap();
+    board.push_san("b8=Q+").unwrap();
+    board.push_san("Kd7").unwrap();
+    board.push_san("Qb5+").unwrap();
+    assert_eq!(board.get_promoted_mask(), B5.to_bitboard());
+    assert_eq!(board.get_fen(), "8/3k4/8/1Q~6/8/8/8/4K3[] b - - 2 2");
+}
+
+#[test]
+fn test_crazyhouse_search() {
+    let mut engine = Engine::from_fen("6k1/5ppp/8/8/8/8/8/6K1[R] w - - 0 1").unwrap();
+    let search_info = engine.go_quiet(&SearchConfig::new_depth(3));
+    let best_move = search_info.get_best_move().unwrap();
+    assert_eq!(best_move.get_drop(), Some(Rook));
+    assert!(BB_RANK_8.contains(best_move.get_dest()));
+    assert!(is_checkmate(search_info.get_score().unwrap()));
+}
+
+#[test]
+fn test_atomic_explosions() {
+    let mut board = get_variant_board(
+        Variant::Atomic,
+        "rnbqkbnr/pppp1ppp/8/4p3/8/5N2/PPPPPPPP/RNBQKB1R w KQkq - 0 2",
+    );
+    // The capture explodes the knight and the pieces around e5 but the pawns.
+    board.push_san("Nxe5").unwrap();
+    assert_eq!(
+        board.get_fen(),
+        "rnbqkbnr/pppp1ppp/8/8/8/8/PPPPPPPP/RNBQKB1R b KQkq - 0 2"
+    );
+    // Exploding the rook takes the castle rights with it.
+    let mut board = get_variant_board(Variant::Atomic, "4k2r/8/8/8/8/8/8/4K2R w Kk - 0 1");
+    board.push_san("Rxh8").unwrap();
+    assert_eq!(board.get_fen(), "4k3/8/8/8/8/8/8/4K3 b - - 0 1");
+    assert!(board.is_insufficient_material());
+    // Exploding the king of the other side wins.
+    let mut board = get_variant_board(Variant::Atomic, "4k3/3q4/8/8/8/8/8/3RK3 w - - 0 1");
+    assert!(!board.is_check());
+    board.push_san("Rxd7").unwrap();
+    assert!(!board.is_insufficient_material());
+    assert_eq!(board.status(), BoardStatus::VariantWin(White));
+    assert!(board.is_game_over());
+}
+
+#[test]
+fn test_atomic_legality() {
+    // The kings may touch, and then neither of them is in check.
+    let board = get_variant_board(Variant::Atomic, "8/8/8/8/3k4/3K4/8/7r w - - 0 1");
+    assert!(!board.is_check());
+    assert!(board
+        .generate_legal_moves()
+        .contains(&Move::from_str("d3e3").unwrap()));
+    // A king cannot capture, and a capture exploding the own king is illegal.
+    let board = get_variant_board(Variant::Atomic, "4k3/8/8/8/8/8/3q4/3QK3 w - - 0 1");
+    assert!(board.is_check());
+    assert!(!board.is_legal(&Move::from_str("e1d2").unwrap()));
+    assert!(!board.is_legal(&Move::from_str("d1d2").unwrap()));
+    assert_eq!(MoveGenerator::perft_cross_check(&board, 3).unwrap(), {
+        MoveGenerator::perft_test(&board, 3)
+    });
+    // Exploding the other king is legal even if the own king is left in check.
+    let board = get_variant_board(Variant::Atomic, "3rk3/8/8/8/8/8/8/3RK3 w - - 0 1");
+    assert!(board.is_legal(&Move::from_str("d1d8").unwrap()));
+    let mut engine = get_variant_engine(Variant::Atomic, "3rk3/8/8/8/8/8/8/3RK3 w - - 0 1");
+    let search_info = engine.go_quiet(&SearchConfig::new_depth(2));
+    assert_eq!(
+        search_info.get_best_move(),
+        Some(Move::from_str("d1d8").unwrap())
+    );
+    assert!(is_checkmate(search_info.get_score().unwrap()));
+}
+
+#[test]
+fn test_antichess_moves() {
+    let mut board = Board::new();
+    board.set_variant(Variant::Antichess).unwrap();
+    assert_eq!(board.get_fen(), ANTICHESS_STARTING_FEN);
+    board.push_san("e4").unwrap();
+    board.push_san("d5").unwrap();
+    // Capturing is compulsory.
+    assert_eq!(board.generate_legal_moves().len(), 1);
+    assert!(board.push_san("Nf3").is_err());
+    board.push_san("exd5").unwrap();
+    assert!(!board.is_check());
+    // The castle rights of standard chess are ignored, and the king can be captured.
+    let board = get_variant_board(Variant::Antichess, "4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1");
+    assert_eq!(board.get_fen(), "4k3/8/8/8/8/8/8/R3K2R w - - 0 1");
+    assert!(board.generate_legal_moves().all(
+        |move_| move_.get_source() != E1 || move_.get_source().distance(move_.get_dest()) == 1
+    ));
+    // A pawn can promote to a king.
+    let board = get_variant_board(Variant::Antichess, "8/P7/8/8/8/8/8/7k w - - 0 1");
+    assert_eq!(board.generate_legal_moves().len(), 5);
+    assert!(board.is_legal(&Move::from_str("a7a8k").unwrap()));
+    assert_eq!(MoveGenerator::perft_cross_check(&board, 3).unwrap(), {
+        MoveGenerator::perft_test(&board, 3)
+    });
+}
+
+#[test]
+fn test_antichess_goal() {
+    let mut board = get_variant_board(Variant::Antichess, "8/8/8/8/8/8/1p6/R7 b - - 0 1");
+    assert!(!board.is_insufficient_material());
+    board.push_san("bxa1=Q").unwrap();
+    assert_eq!(board.status(), BoardStatus::VariantWin(White));
+    // A side without moves wins too.
+    let board = get_variant_board(Variant::Antichess, "8/8/8/8/8/p7/P7/8 w - - 0 1");
+    assert_eq!(board.status(), BoardStatus::VariantWin(White));
+    let mut engine = get_variant_engine(Variant::Antichess, "8/8/8/8/8/8/1p6/R7 w - - 0 1");
+    let search_info = engine.go_quiet(&SearchConfig::new_depth(3));
+    assert_eq!(
+        search_info.get_best_move(),
+        Some(Move::from_str("a1c1").unwrap())
+    );
+    assert!(is_checkmate(search_info.get_score().unwrap()));
+}
+
+#[test]
+fn test_horde() {
+    let mut board = Board::new();
+    board.set_variant(Variant::Horde).unwrap();
+    assert_eq!(board.get_fen(), HORDE_STARTING_FEN);
+    assert_eq!(board.get_king_square(White), None);
+    assert_eq!(board.get_king_square(Black), Some(E8));
+    assert!(!board.has_insufficient_material(Black));
+    // The pawns on the first rank may move two squares, without allowing en passant.
+    let mut board = get_variant_board(Variant::Horde, "4k3/8/8/8/8/8/8/4PP2 w - - 0 1");
+    assert!(board
+        .generate_legal_moves()
+        .contains(&Move::from_str("e1e3").unwrap()));
+    board.push_san("e3").unwrap();
+    assert_eq!(board.ep_square(), None);
+    // Capturing the last piece of the horde wins.
+    let mut board = get_variant_board(Variant::Horde, "4k3/8/8/8/8/8/4q3/5P2 b - - 0 1");
+    board.push_san("Qxf1").unwrap();
+    assert_eq!(board.status(), BoardStatus::VariantWin(Black));
+    // Only the white pawns on the first rank and a black king make a horde position.
+    assert!(BoardPosition::from_fen("4k3/8/8/8/8/8/8/4P3 w - - 0 1").is_err());
+    let mut board = Board::new();
+    board.set_variant(Variant::Horde).unwrap();
+    assert!(board
+        .clone()
+        .set_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1")
+        .is_err());
+    assert!(board
+        .clone()
+        .set_fen("4k3/8/8/8/8/8/8/4p3 w - - 0 1")
+        .is_err());
+    let mut engine = get_variant_engine(Variant::Horde, "7k/8/8/8/8/8/8/6QP w - - 0 1");
+    let search_info = engine.go_quiet(&SearchConfig::new_depth(3));
+    assert!(search_info.get_best_move().is_some());
+}